@@ -0,0 +1,169 @@
+//! Base score calculation for CVSS v3 vectors.
+//!
+//! See: <https://www.first.org/cvss/v3.1/specification-document#7-4-Metric-Values>
+
+/// Compute the base score of a CVSS v3.0 or v3.1 vector, e.g.,
+/// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+///
+/// Returns `None` if the vector is malformed or isn't a CVSS v3 vector.
+pub(crate) fn base_score(vector: &str) -> Option<f64> {
+    let mut metrics = vector.split('/');
+    if !matches!(metrics.next()?, "CVSS:3.0" | "CVSS:3.1") {
+        return None;
+    }
+
+    let mut attack_vector = None;
+    let mut attack_complexity = None;
+    let mut privileges_required = None;
+    let mut user_interaction = None;
+    let mut scope_changed = None;
+    let mut confidentiality = None;
+    let mut integrity = None;
+    let mut availability = None;
+
+    // Temporal and environmental metrics may follow the base metrics, but don't affect the base
+    // score.
+    for metric in metrics {
+        let (name, value) = metric.split_once(':')?;
+        match name {
+            "AV" => {
+                attack_vector = Some(match value {
+                    "N" => 0.85,
+                    "A" => 0.62,
+                    "L" => 0.55,
+                    "P" => 0.2,
+                    _ => return None,
+                });
+            }
+            "AC" => {
+                attack_complexity = Some(match value {
+                    "L" => 0.77,
+                    "H" => 0.44,
+                    _ => return None,
+                });
+            }
+            "PR" => {
+                privileges_required = Some(match value {
+                    "N" | "L" | "H" => value,
+                    _ => return None,
+                });
+            }
+            "UI" => {
+                user_interaction = Some(match value {
+                    "N" => 0.85,
+                    "R" => 0.62,
+                    _ => return None,
+                });
+            }
+            "S" => {
+                scope_changed = Some(match value {
+                    "U" => false,
+                    "C" => true,
+                    _ => return None,
+                });
+            }
+            "C" => confidentiality = Some(impact(value)?),
+            "I" => integrity = Some(impact(value)?),
+            "A" => availability = Some(impact(value)?),
+            _ => {}
+        }
+    }
+
+    let scope_changed = scope_changed?;
+    // The weight of the required privileges depends on whether the scope is changed.
+    let privileges_required = match (privileges_required?, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => unreachable!(),
+    };
+
+    let impact_sub_score =
+        1.0 - ((1.0 - confidentiality?) * (1.0 - integrity?) * (1.0 - availability?));
+    let impact = if scope_changed {
+        7.52 * (impact_sub_score - 0.029) - 3.25 * (impact_sub_score - 0.02).powi(15)
+    } else {
+        6.42 * impact_sub_score
+    };
+    let exploitability =
+        8.22 * attack_vector? * attack_complexity? * privileges_required * user_interaction?;
+
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let score = if scope_changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+    Some(round_up(score.min(10.0)))
+}
+
+/// The weight of a confidentiality, integrity, or availability impact.
+fn impact(value: &str) -> Option<f64> {
+    match value {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    }
+}
+
+/// Round up to one decimal place, avoiding floating-point artifacts, as defined in Appendix A of
+/// the CVSS v3.1 specification.
+#[expect(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn round_up(value: f64) -> f64 {
+    let value = (value * 100_000.0).round() as i64;
+    if value % 10_000 == 0 {
+        value as f64 / 100_000.0
+    } else {
+        ((value / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base_score;
+
+    #[test]
+    fn test_base_score() {
+        assert_eq!(
+            base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            Some(9.8)
+        );
+        assert_eq!(
+            base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"),
+            Some(6.1)
+        );
+        assert_eq!(
+            base_score("CVSS:3.0/AV:L/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N"),
+            Some(5.5)
+        );
+        assert_eq!(
+            base_score("CVSS:3.1/AV:N/AC:H/PR:H/UI:R/S:C/C:H/I:H/A:H/E:P"),
+            Some(7.6)
+        );
+        assert_eq!(
+            base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N"),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_base_score_invalid() {
+        // CVSS v2 and v4 vectors aren't supported.
+        assert_eq!(base_score("AV:N/AC:L/Au:N/C:P/I:P/A:P"), None);
+        assert_eq!(
+            base_score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"),
+            None
+        );
+        // Missing and invalid metrics.
+        assert_eq!(base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H"), None);
+        assert_eq!(
+            base_score("CVSS:3.1/AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            None
+        );
+    }
+}
@@ -3,9 +3,11 @@
 pub use service::ProjectStatusAudit;
 pub use service::VulnerabilityServiceFormat;
 pub use service::osv;
+pub use service::osv_dump;
 pub use types::{
-    AdverseStatus, Dependency, Finding, ProjectStatus, Vulnerability, VulnerabilityID,
+    AdverseStatus, Dependency, Finding, ProjectStatus, Severity, Vulnerability, VulnerabilityID,
 };
 
+mod cvss;
 mod service;
 mod types;
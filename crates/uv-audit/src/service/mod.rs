@@ -3,11 +3,15 @@
 pub use project_status::ProjectStatusAudit;
 
 pub mod osv;
+pub mod osv_dump;
 mod project_status;
 
 /// The shape of the vulnerability service.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum VulnerabilityServiceFormat {
+    /// The OSV API.
     Osv,
+    /// A local directory with an unpacked OSV data dump.
+    OsvDump,
 }
@@ -13,6 +13,7 @@ use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::trace;
 
+use crate::cvss;
 use crate::types::{self, VulnerabilityID};
 use futures::{StreamExt as _, TryStreamExt as _};
 use jiff::Timestamp;
@@ -73,13 +74,13 @@ struct QueryRequest {
 #[serde(rename_all = "snake_case")]
 enum Event {
     /// A version that introduces the vulnerability.
-    Introduced(#[allow(dead_code)] String),
+    Introduced(String),
     /// A version that fixes the vulnerability.
     Fixed(String),
     /// The last known affected version.
-    LastAffected(#[allow(dead_code)] String),
+    LastAffected(String),
     /// An upper limit on the range.
-    Limit(#[allow(dead_code)] String),
+    Limit(String),
 }
 
 /// The type of a version range in an OSV vulnerability record.
@@ -110,10 +111,77 @@ struct Range {
     events: Vec<Event>,
 }
 
+impl Range {
+    /// Returns `true` if the given version is within this range.
+    ///
+    /// Following the OSV schema, the events are sorted by version, with an `introduced` event
+    /// starting an affected interval and a `fixed`, `last_affected` or `limit` event ending it.
+    /// Only `ECOSYSTEM` ranges, i.e., PEP 440 versions, are evaluated.
+    fn contains(&self, version: &Version) -> bool {
+        if !matches!(self.range_type, RangeType::Ecosystem) {
+            return false;
+        }
+
+        // `introduced: "0"` is the earliest possible version, which we represent as `None`.
+        let mut events = self
+            .events
+            .iter()
+            .filter_map(|event| {
+                let (Event::Introduced(event_version)
+                | Event::Fixed(event_version)
+                | Event::LastAffected(event_version)
+                | Event::Limit(event_version)) = event;
+                if event_version == "0" {
+                    return Some((None, event));
+                }
+                if let Ok(event_version) = Version::from_str(event_version) {
+                    Some((Some(event_version), event))
+                } else {
+                    trace!("Skipping invalid (non-PEP 440) version in OSV range: {event_version}");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        events.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut affected = false;
+        for (event_version, event) in events {
+            match event {
+                Event::Introduced(_) => {
+                    if event_version.is_none_or(|introduced| *version >= introduced) {
+                        affected = true;
+                    }
+                }
+                Event::Fixed(_) | Event::Limit(_) => {
+                    if event_version.is_some_and(|fixed| *version >= fixed) {
+                        affected = false;
+                    }
+                }
+                Event::LastAffected(_) => {
+                    if event_version.is_some_and(|last_affected| *version > last_affected) {
+                        affected = false;
+                    }
+                }
+            }
+        }
+        affected
+    }
+}
+
+/// The package an [`Affected`] entry applies to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AffectedPackage {
+    pub(crate) ecosystem: String,
+    pub(crate) name: String,
+}
+
 /// Package affected by a vulnerability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Affected {
+pub(crate) struct Affected {
+    pub(crate) package: Option<AffectedPackage>,
     ranges: Option<Vec<Range>>,
+    /// The explicitly enumerated affected versions.
+    versions: Option<Vec<String>>,
     // TODO: Enable these fields if/when they contain information that's
     // useful to us, e.g. metadata that constrains a vulnerability to specific
     // Python runtime versions, specific distributions of a version, etc.
@@ -121,6 +189,22 @@ struct Affected {
     // database_specific: Option<serde_json::Value>,
 }
 
+impl Affected {
+    /// Returns `true` if the given version is affected, either because it's enumerated in
+    /// `versions` or because it's within one of the `ranges`.
+    pub(crate) fn contains(&self, version: &Version) -> bool {
+        self.versions
+            .iter()
+            .flatten()
+            .any(|affected| Version::from_str(affected).is_ok_and(|affected| affected == *version))
+            || self
+                .ranges
+                .iter()
+                .flatten()
+                .any(|range| range.contains(version))
+    }
+}
+
 /// The type of a reference in an OSV vulnerability record.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -149,10 +233,32 @@ struct Reference {
     url: DisplaySafeUrl,
 }
 
+/// The type of a severity score in an OSV vulnerability record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SeverityType {
+    #[serde(rename = "CVSS_V2")]
+    CvssV2,
+    #[serde(rename = "CVSS_V3")]
+    CvssV3,
+    #[serde(rename = "CVSS_V4")]
+    CvssV4,
+    /// Some other severity type, e.g., `Ubuntu`.
+    #[serde(other)]
+    Other,
+}
+
+/// A severity score for a vulnerability, e.g., a CVSS vector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Severity {
+    #[serde(rename = "type")]
+    severity_type: SeverityType,
+    score: String,
+}
+
 /// A full vulnerability record from OSV.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Vulnerability {
-    id: String,
+pub(crate) struct Vulnerability {
+    pub(crate) id: String,
     modified: Timestamp,
     // Note: While the OSV spec says schema_version is required for versions >= 1.0.0,
     // some older records in the database don't have it, so we make it optional.
@@ -164,9 +270,10 @@ struct Vulnerability {
     summary: Option<String>,
     details: Option<String>,
     published: Option<Timestamp>,
-    affected: Option<Vec<Affected>>,
+    pub(crate) affected: Option<Vec<Affected>>,
     aliases: Option<Vec<String>>,
     references: Option<Vec<Reference>>,
+    severity: Option<Vec<Severity>>,
 }
 
 /// Request body for the batch query API.
@@ -207,7 +314,7 @@ pub enum Filter {
 
 impl Filter {
     /// Returns `true` if the given vulnerability ID matches this filter.
-    fn matches(self, id: &str) -> bool {
+    pub(crate) fn matches(self, id: &str) -> bool {
         match self {
             Self::All => true,
             Self::Malware => id.starts_with("MAL-"),
@@ -420,7 +527,7 @@ impl Osv {
     }
 
     /// Convert an OSV-specific [`Vulnerability`] record to a [`types::Finding`].
-    fn vulnerability_to_finding(
+    pub(crate) fn vulnerability_to_finding(
         dependency: &types::Dependency,
         vuln: Vulnerability,
     ) -> types::Finding {
@@ -482,6 +589,16 @@ impl Osv {
             .map(types::VulnerabilityID::new)
             .collect();
 
+        // Extract the highest CVSS v3 base score. Other severity types (e.g., CVSS v4) aren't
+        // scored.
+        let cvss_score = vuln
+            .severity
+            .iter()
+            .flatten()
+            .filter(|severity| matches!(severity.severity_type, SeverityType::CvssV3))
+            .filter_map(|severity| cvss::base_score(&severity.score))
+            .reduce(f64::max);
+
         types::Finding::Vulnerability(
             types::Vulnerability::new(
                 dependency.clone(),
@@ -493,6 +610,7 @@ impl Osv {
                 aliases,
                 vuln.published,
                 Some(vuln.modified),
+                cvss_score,
             )
             .into(),
         )
@@ -513,8 +631,8 @@ mod tests {
     use wiremock::matchers::{body_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
-    use crate::service::osv::{Filter, RangeType};
-    use crate::types::{Dependency, Finding};
+    use crate::service::osv::{Affected, Filter, RangeType, Vulnerability};
+    use crate::types::{Dependency, Finding, Severity};
 
     use super::Event;
     use super::Osv;
@@ -569,6 +687,105 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn test_affected_contains() {
+        let affected: Affected = serde_json::from_value(json!({
+            "package": { "name": "package-a", "ecosystem": "PyPI" },
+            "ranges": [
+                {
+                    "type": "ECOSYSTEM",
+                    "events": [
+                        { "introduced": "0" },
+                        { "fixed": "1.5" },
+                        { "introduced": "2.0" },
+                        { "last_affected": "2.3" }
+                    ]
+                },
+                {
+                    "type": "GIT",
+                    "events": [{ "introduced": "0" }]
+                }
+            ],
+            "versions": ["3.0"]
+        }))
+        .expect("Failed to deserialize affected package");
+
+        let contains = |version: &str| affected.contains(&Version::from_str(version).unwrap());
+        assert!(contains("0.1"));
+        assert!(contains("1.5rc1"));
+        assert!(!contains("1.5"));
+        assert!(!contains("1.9"));
+        assert!(contains("2.0"));
+        assert!(contains("2.3"));
+        assert!(!contains("2.3.1"));
+        assert!(contains("3.0"));
+        assert!(!contains("3.1"));
+    }
+
+    #[test]
+    fn test_nearest_fix_version() {
+        let vuln: Vulnerability = serde_json::from_value(json!({
+            "id": "PYSEC-2023-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "affected": [{
+                "package": { "name": "package-a", "ecosystem": "PyPI" },
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [
+                        { "introduced": "0" },
+                        { "fixed": "1.5" },
+                        { "introduced": "2.0" },
+                        { "fixed": "2.3.1" },
+                        { "introduced": "3.0" },
+                        { "fixed": "3.0.2" }
+                    ]
+                }]
+            }]
+        }))
+        .expect("Failed to deserialize vulnerability");
+
+        let nearest_fix_version = |version: &str| {
+            let dependency = Dependency::new(
+                PackageName::from_str("package-a").unwrap(),
+                Version::from_str(version).unwrap(),
+            );
+            match Osv::vulnerability_to_finding(&dependency, vuln.clone()) {
+                Finding::Vulnerability(vuln) => vuln.nearest_fix_version().map(ToString::to_string),
+                Finding::ProjectStatus(_) => unreachable!(),
+            }
+        };
+        assert_eq!(nearest_fix_version("1.0").as_deref(), Some("1.5"));
+        assert_eq!(nearest_fix_version("2.1").as_deref(), Some("2.3.1"));
+        assert_eq!(nearest_fix_version("3.0.1").as_deref(), Some("3.0.2"));
+        assert_eq!(nearest_fix_version("3.0.2").as_deref(), None);
+    }
+
+    #[test]
+    fn test_severity() {
+        let vuln: Vulnerability = serde_json::from_value(json!({
+            "id": "PYSEC-2023-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "severity": [
+                { "type": "CVSS_V4", "score": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N" },
+                { "type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N" },
+                { "type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:N" },
+                { "type": "Ubuntu", "score": "high" }
+            ]
+        }))
+        .expect("Failed to deserialize vulnerability");
+
+        let dependency = Dependency::new(
+            PackageName::from_str("package-a").unwrap(),
+            Version::from_str("1.0").unwrap(),
+        );
+        let Finding::Vulnerability(vuln) = Osv::vulnerability_to_finding(&dependency, vuln) else {
+            unreachable!()
+        };
+        // The highest CVSS v3 score is used.
+        assert_eq!(vuln.cvss_score, Some(9.1));
+        assert_eq!(vuln.severity(), Some(Severity::Critical));
+    }
+
     /// Ensure that `query_identifiers` returns the correct vulnerability ID mapping.
     #[tokio::test]
     async fn test_query_identifiers() {
@@ -760,6 +977,7 @@ mod tests {
                     modified: Some(
                         2026-01-01T00:00:00Z,
                     ),
+                    cvss_score: None,
                 },
             ),
             Vulnerability(
@@ -798,6 +1016,7 @@ mod tests {
                     modified: Some(
                         2026-01-02T00:00:00Z,
                     ),
+                    cvss_score: None,
                 },
             ),
         ]
@@ -0,0 +1,522 @@
+//! Types and interfaces for auditing against a local [OSV] data dump.
+//!
+//! OSV publishes a zip archive with all vulnerability records of an ecosystem, one JSON file per
+//! record. Unpacked into a directory, we match the records against the dependencies locally,
+//! using the same range evaluation as the [`osv`](super::osv) service module, so auditing
+//! doesn't require access to the OSV API.
+//!
+//! When downloading the data dump, we write an index of the records by package name next to the
+//! records, so an audit only needs to read the records of the audited packages.
+//!
+//! [OSV]: https://osv.dev/
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr as _;
+use std::sync::LazyLock;
+
+use futures::StreamExt as _;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::io::AsyncWriteExt as _;
+use tracing::{debug, trace};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::BaseClient;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
+
+use crate::service::osv::{Filter, Osv, Vulnerability};
+use crate::types;
+
+/// The URL of the data dump for the PyPI ecosystem.
+pub static DUMP_URL: LazyLock<DisplaySafeUrl> = LazyLock::new(|| {
+    DisplaySafeUrl::parse("https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip")
+        .expect("embedded OSV URL is a valid URL")
+});
+
+/// The name of the file in the data dump directory that indexes the records by package name.
+const INDEX_FILENAME: &str = ".index.json";
+
+/// The record filenames in the data dump directory, by the names of the packages they affect.
+type Index = BTreeMap<PackageName, Vec<String>>;
+
+/// Errors when auditing against or updating an OSV data dump.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(
+        "No OSV database found at `{}`, run `uv audit --update-db` to download it",
+        _0.user_display()
+    )]
+    MissingDatabase(PathBuf),
+    #[error("Failed to read the OSV database at `{}`", _0.user_display())]
+    Read(PathBuf, #[source] io::Error),
+    /// An error during an HTTP request, including middleware errors.
+    #[error(transparent)]
+    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    #[error("Failed to extract the OSV database downloaded from `{0}`")]
+    Extract(String, #[source] Box<uv_extract::Error>),
+    #[error("Failed to write the OSV database to `{}`", _0.user_display())]
+    Write(PathBuf, #[source] io::Error),
+}
+
+/// Represents an unpacked [OSV](https://osv.dev/) data dump in a local directory.
+pub struct OsvDump {
+    directory: PathBuf,
+}
+
+impl OsvDump {
+    /// Create a new OSV data dump service reading the records from the given directory.
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// The default location of the data dump, in the cache.
+    pub fn default_directory(cache: &Cache) -> PathBuf {
+        cache.bucket(CacheBucket::Osv).join("dump").join("PyPI")
+    }
+
+    /// Parse the location of a data dump given as a path or a `file://` URL.
+    pub fn parse_location(location: &str) -> PathBuf {
+        if let Ok(url) = DisplaySafeUrl::parse(location)
+            && url.scheme() == "file"
+            && let Ok(path) = url.to_file_path()
+        {
+            return path;
+        }
+        PathBuf::from(location)
+    }
+
+    /// Find the vulnerabilities affecting the given dependencies, returning full vulnerability
+    /// records.
+    pub async fn query_batch(
+        &self,
+        dependencies: &[types::Dependency],
+        filter: Filter,
+    ) -> Result<Vec<types::Finding>, Error> {
+        if dependencies.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let directory = self.directory.clone();
+        let names = dependencies
+            .iter()
+            .map(|dependency| dependency.name().clone())
+            .collect::<FxHashSet<_>>();
+        let records = tokio::task::spawn_blocking(move || read_records(&directory, &names))
+            .await
+            .expect("reading the OSV database panicked")?;
+
+        // Build findings in dependency order, and in record order within a dependency.
+        let findings = dependencies
+            .iter()
+            .flat_map(|dependency| {
+                records
+                    .get(dependency.name())
+                    .into_iter()
+                    .flatten()
+                    .filter(|vuln| filter.matches(&vuln.id))
+                    .filter(|vuln| affects(vuln, dependency))
+                    .map(|vuln| Osv::vulnerability_to_finding(dependency, vuln.clone()))
+            })
+            .collect();
+
+        Ok(findings)
+    }
+
+    /// Download the latest data dump from the given URL, replacing the current one.
+    ///
+    /// Returns the number of vulnerability records in the data dump.
+    pub async fn update(&self, client: &BaseClient, url: &DisplaySafeUrl) -> Result<usize, Error> {
+        debug!("Downloading the OSV database from: {url}");
+        let response = client
+            .for_host(url)
+            .raw_client()
+            .get(url.as_ref())
+            .send()
+            .await?
+            .error_for_status()
+            .map_err(reqwest_middleware::Error::Reqwest)?;
+
+        // Download and unpack next to the current data dump, so we can replace it with a rename.
+        let parent = self.directory.parent().unwrap_or(Path::new("."));
+        fs_err::tokio::create_dir_all(parent)
+            .await
+            .map_err(|err| Error::Write(self.directory.clone(), err))?;
+
+        // Stream the archive to a temporary file rather than buffering it in memory.
+        let archive = tempfile::NamedTempFile::new_in(parent)
+            .map_err(|err| Error::Write(self.directory.clone(), err))?
+            .into_temp_path();
+        let mut file = fs_err::tokio::File::create(&archive)
+            .await
+            .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(reqwest_middleware::Error::Reqwest)?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        }
+        file.flush()
+            .await
+            .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        drop(file);
+
+        let temp_dir = tempfile::tempdir_in(parent)
+            .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        let records = tokio::task::spawn_blocking({
+            let target = temp_dir.path().to_path_buf();
+            let url = url.to_string();
+            move || unpack(&archive, &target, &url)
+        })
+        .await
+        .expect("unpacking the OSV database panicked")?;
+
+        // Move the current data dump aside before moving the new one into place, so we can
+        // restore it if the rename fails.
+        let backup = tempfile::tempdir_in(parent)
+            .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        let previous = backup.path().join("previous");
+        let has_previous = self.directory.exists();
+        if has_previous {
+            uv_fs::rename_with_retry(&self.directory, &previous)
+                .await
+                .map_err(|err| Error::Write(self.directory.clone(), err))?;
+        }
+        if let Err(err) = uv_fs::rename_with_retry(temp_dir.path(), &self.directory).await {
+            if has_previous {
+                if let Err(err) = uv_fs::rename_with_retry(&previous, &self.directory).await {
+                    debug!("Failed to restore the previous OSV database: {err}");
+                }
+            }
+            return Err(Error::Write(self.directory.clone(), err));
+        }
+        if let Err(err) = fs_err::tokio::remove_dir_all(backup.path()).await {
+            debug!("Failed to remove the previous OSV database: {err}");
+        }
+
+        Ok(records)
+    }
+}
+
+/// Unpack the downloaded archive into the target directory and index its records.
+///
+/// Returns the number of vulnerability records in the archive.
+fn unpack(archive: &Path, target: &Path, url: &str) -> Result<usize, Error> {
+    let reader =
+        fs_err::File::open(archive).map_err(|err| Error::Read(archive.to_path_buf(), err))?;
+    uv_extract::unzip(reader, target)
+        .map_err(|err| Error::Extract(url.to_string(), Box::new(err)))?;
+
+    let (index, records) = index_records(target)?;
+    let path = target.join(INDEX_FILENAME);
+    let contents = serde_json::to_vec(&index).expect("index serialization can't fail");
+    fs_err::write(&path, contents).map_err(|err| Error::Write(path, err))?;
+    Ok(records)
+}
+
+/// Returns `true` if the vulnerability affects the version of the dependency.
+fn affects(vuln: &Vulnerability, dependency: &types::Dependency) -> bool {
+    vuln.affected.iter().flatten().any(|affected| {
+        affected.package.as_ref().is_some_and(|package| {
+            package.ecosystem == "PyPI"
+                && PackageName::from_str(&package.name).is_ok_and(|name| name == *dependency.name())
+        }) && affected.contains(dependency.version())
+    })
+}
+
+/// Read the vulnerability records for the given packages from the data dump.
+fn read_records(
+    directory: &Path,
+    names: &FxHashSet<PackageName>,
+) -> Result<FxHashMap<PackageName, Vec<Vulnerability>>, Error> {
+    let index = read_index(directory)?;
+
+    let mut records: FxHashMap<PackageName, Vec<Vulnerability>> = FxHashMap::default();
+    for name in names {
+        for filename in index.get(name).into_iter().flatten() {
+            let path = directory.join(filename);
+            if let Some(vuln) = read_record(&path)? {
+                records.entry(name.clone()).or_default().push(vuln);
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Read the index of the data dump.
+///
+/// Data dumps that weren't downloaded by uv, e.g., an archive unpacked manually, don't have an
+/// index, so we index them on the fly.
+fn read_index(directory: &Path) -> Result<Index, Error> {
+    let path = directory.join(INDEX_FILENAME);
+    match fs_err::read(&path) {
+        Ok(contents) => match serde_json::from_slice(&contents) {
+            Ok(index) => return Ok(index),
+            Err(err) => {
+                warn_user!(
+                    "Ignoring malformed index of the OSV database at `{}`: {err}",
+                    path.user_display()
+                );
+            }
+        },
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            if !directory.is_dir() {
+                return Err(Error::MissingDatabase(directory.to_path_buf()));
+            }
+            debug!(
+                "No index found for the OSV database at `{}`, reading all records",
+                directory.user_display()
+            );
+        }
+        Err(err) => return Err(Error::Read(path, err)),
+    }
+    let (index, _) = index_records(directory)?;
+    Ok(index)
+}
+
+/// Index the vulnerability records in the data dump by the names of the packages they affect.
+///
+/// Returns the index and the number of records.
+fn index_records(directory: &Path) -> Result<(Index, usize), Error> {
+    let entries = match fs_err::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::MissingDatabase(directory.to_path_buf()));
+        }
+        Err(err) => return Err(Error::Read(directory.to_path_buf(), err)),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| Error::Read(directory.to_path_buf(), err))?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "json")
+            && path
+                .file_name()
+                .is_some_and(|filename| filename != INDEX_FILENAME)
+    });
+    paths.sort();
+    trace!(
+        "Indexing {} OSV records in: {}",
+        paths.len(),
+        directory.user_display()
+    );
+
+    let mut index = Index::new();
+    let mut records = 0;
+    for path in paths {
+        let Some(vuln) = read_record(&path)? else {
+            continue;
+        };
+        records += 1;
+        let Some(filename) = path.file_name().and_then(|filename| filename.to_str()) else {
+            continue;
+        };
+        let affected_names = vuln
+            .affected
+            .iter()
+            .flatten()
+            .filter_map(|affected| affected.package.as_ref())
+            .filter_map(|package| PackageName::from_str(&package.name).ok())
+            .collect::<FxHashSet<_>>();
+        for name in affected_names {
+            index.entry(name).or_default().push(filename.to_string());
+        }
+    }
+    Ok((index, records))
+}
+
+/// Read a single vulnerability record, skipping it with a warning if it's malformed.
+fn read_record(path: &Path) -> Result<Option<Vulnerability>, Error> {
+    let contents = fs_err::read(path).map_err(|err| Error::Read(path.to_path_buf(), err))?;
+    match serde_json::from_slice(&contents) {
+        Ok(vuln) => Ok(Some(vuln)),
+        Err(err) => {
+            warn_user!(
+                "Skipping malformed vulnerability record in the OSV database at `{}`: {err}",
+                path.user_display()
+            );
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use crate::service::osv::Filter;
+    use crate::types::{Dependency, Finding};
+
+    use super::{Error, INDEX_FILENAME, OsvDump, index_records};
+
+    fn write_record(directory: &std::path::Path, record: &serde_json::Value) {
+        let id = record["id"].as_str().unwrap();
+        fs_err::write(
+            directory.join(format!("{id}.json")),
+            serde_json::to_vec(record).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_batch() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_record(
+            temp_dir.path(),
+            &json!({
+                "id": "PYSEC-2023-0001",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "Fixed in 2.1.0",
+                "affected": [{
+                    "package": { "name": "Package_A", "ecosystem": "PyPI" },
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{ "introduced": "0" }, { "fixed": "2.1.0" }]
+                    }]
+                }]
+            }),
+        );
+        write_record(
+            temp_dir.path(),
+            &json!({
+                "id": "PYSEC-2023-0002",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "Only affects 1.x",
+                "affected": [{
+                    "package": { "name": "package-a", "ecosystem": "PyPI" },
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{ "introduced": "1.0" }, { "fixed": "2.0" }]
+                    }]
+                }]
+            }),
+        );
+        write_record(
+            temp_dir.path(),
+            &json!({
+                "id": "MAL-2023-0003",
+                "modified": "2026-01-01T00:00:00Z",
+                "affected": [{
+                    "package": { "name": "package-b", "ecosystem": "PyPI" },
+                    "versions": ["1.0.0"]
+                }]
+            }),
+        );
+
+        let dependencies = vec![
+            Dependency::new(
+                PackageName::from_str("package-a").unwrap(),
+                Version::from_str("2.0.0").unwrap(),
+            ),
+            Dependency::new(
+                PackageName::from_str("package-b").unwrap(),
+                Version::from_str("1.0.0").unwrap(),
+            ),
+        ];
+
+        let service = OsvDump::new(temp_dir.path().to_path_buf());
+        let ids = |findings: Vec<Finding>| {
+            findings
+                .iter()
+                .map(|finding| match finding {
+                    Finding::Vulnerability(vuln) => {
+                        format!("{} {}", vuln.dependency.name(), vuln.id.as_str())
+                    }
+                    Finding::ProjectStatus(_) => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let findings = service
+            .query_batch(&dependencies, Filter::All)
+            .await
+            .unwrap();
+        insta::assert_debug_snapshot!(ids(findings), @r#"
+        [
+            "package-a PYSEC-2023-0001",
+            "package-b MAL-2023-0003",
+        ]
+        "#);
+
+        let findings = service
+            .query_batch(&dependencies, Filter::Malware)
+            .await
+            .unwrap();
+        insta::assert_debug_snapshot!(ids(findings), @r#"
+        [
+            "package-b MAL-2023-0003",
+        ]
+        "#);
+    }
+
+    #[tokio::test]
+    async fn test_index() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let record = json!({
+            "id": "PYSEC-2023-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "affected": [{
+                "package": { "name": "package-a", "ecosystem": "PyPI" },
+                "versions": ["1.0.0"]
+            }]
+        });
+        write_record(temp_dir.path(), &record);
+        // A malformed record is skipped rather than failing the audit.
+        fs_err::write(temp_dir.path().join("PYSEC-2023-0002.json"), "{").unwrap();
+
+        let dependencies = vec![Dependency::new(
+            PackageName::from_str("package-a").unwrap(),
+            Version::from_str("1.0.0").unwrap(),
+        )];
+        let service = OsvDump::new(temp_dir.path().to_path_buf());
+
+        // Without an index, we read all records.
+        let findings = service
+            .query_batch(&dependencies, Filter::All)
+            .await
+            .unwrap();
+        assert_eq!(findings.len(), 1);
+
+        // With an index, we only read the records it lists for the package.
+        let (index, records) = index_records(temp_dir.path()).unwrap();
+        assert_eq!(records, 1);
+        insta::assert_json_snapshot!(index, @r#"
+        {
+          "package-a": [
+            "PYSEC-2023-0001.json"
+          ]
+        }
+        "#);
+        fs_err::write(temp_dir.path().join(INDEX_FILENAME), r#"{"package-a": []}"#).unwrap();
+        let findings = service
+            .query_batch(&dependencies, Filter::All)
+            .await
+            .unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_missing_database() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let service = OsvDump::new(temp_dir.path().join("missing"));
+        let dependencies = vec![Dependency::new(
+            PackageName::from_str("package-a").unwrap(),
+            Version::from_str("1.0.0").unwrap(),
+        )];
+        let err = service
+            .query_batch(&dependencies, Filter::All)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::MissingDatabase(_)), "{err:?}");
+    }
+}
@@ -6,6 +6,8 @@ use uv_pep440::Version;
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
+pub use uv_configuration::{AdverseStatus, Severity};
+
 /// Represents a resolved dependency, with a normalized name and PEP 440 version.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Dependency {
@@ -51,33 +53,6 @@ impl VulnerabilityID {
     }
 }
 
-/// Represents an "adverse" project status, i.e. a status that indicates that
-/// a downstream user of the project should review their use of the project
-/// and consider removing it.
-///
-/// These are a subset of the possible project statuses defined in [PEP 792].
-///
-/// [PEP 792]: https://peps.python.org/pep-0792/
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum AdverseStatus {
-    /// The project is archived, meaning it is read-only and no longer maintained.
-    Archived,
-    /// The project is considered generally unsafe for use, e.g. due to malware.
-    Quarantined,
-    /// The project is considered obsolete, and may have been superseded by another project.
-    Deprecated,
-}
-
-impl std::fmt::Display for AdverseStatus {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        formatter.write_str(match self {
-            Self::Archived => "archived",
-            Self::Quarantined => "quarantined",
-            Self::Deprecated => "deprecated",
-        })
-    }
-}
-
 /// A vulnerability within a dependency.
 #[derive(Debug)]
 pub struct Vulnerability {
@@ -99,6 +74,8 @@ pub struct Vulnerability {
     pub published: Option<Timestamp>,
     /// The timestamp when this vulnerability was last modified, if available.
     pub modified: Option<Timestamp>,
+    /// The CVSS v3 base score of this vulnerability, if available.
+    pub cvss_score: Option<f64>,
 }
 
 impl Vulnerability {
@@ -112,6 +89,7 @@ impl Vulnerability {
         aliases: Vec<VulnerabilityID>,
         published: Option<Timestamp>,
         modified: Option<Timestamp>,
+        cvss_score: Option<f64>,
     ) -> Self {
         // Vulnerability summaries often contain excess whitespace, as well as newlines.
         // We normalize these out.
@@ -127,6 +105,7 @@ impl Vulnerability {
             aliases,
             published,
             modified,
+            cvss_score,
         }
     }
 
@@ -140,6 +119,22 @@ impl Vulnerability {
         self.ids().any(|own_id| own_id == id)
     }
 
+    /// Return the lowest fix version above the vulnerable version of the dependency, if any.
+    ///
+    /// A vulnerability can be fixed in multiple release lines, e.g., in both `1.2.4` and `2.0.1`,
+    /// so this is the nearest version the dependency can be upgraded to.
+    pub fn nearest_fix_version(&self) -> Option<&Version> {
+        self.fix_versions
+            .iter()
+            .filter(|version| *version > self.dependency.version())
+            .min()
+    }
+
+    /// Return the severity rating of this vulnerability, if it has a CVSS v3 base score.
+    pub fn severity(&self) -> Option<Severity> {
+        self.cvss_score.and_then(Severity::from_cvss_score)
+    }
+
     /// Pick the subjectively "best" identifier for this vulnerability.
     /// For our purposes we prefer PYSEC IDs, then GHSA, then CVE, then whatever
     /// primary ID the vulnerability came with.
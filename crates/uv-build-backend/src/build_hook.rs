@@ -0,0 +1,153 @@
+//! Support for building compiled artifacts with a Python build hook.
+//!
+//! The build hook is called by the `uv_build` Python shim, which passes the hook's output
+//! directory and the wheel tag of the build interpreter to the build backend through environment
+//! variables.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Serialize;
+use tracing::debug;
+
+use uv_distribution_filename::WheelFilename;
+use uv_fs::Simplified;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::metadata::PyProjectToml;
+use crate::settings::BuildBackendSettings;
+use crate::{Error, find_roots};
+
+/// The build hook configuration passed to the `uv_build` Python shim.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BuildHookInfo {
+    /// The module to import the function from.
+    pub module: String,
+    /// The attribute path of the function in the module.
+    pub function: String,
+    /// The additional build requirements of the hook.
+    pub requires: Vec<String>,
+    /// The module root, where the hook builds artifacts in place for editable installs.
+    pub module_root: PathBuf,
+}
+
+/// Read the build hook configuration of the project, if any.
+pub fn build_hook(source_tree: &Path, uv_version: &str) -> Result<Option<BuildHookInfo>, Error> {
+    let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
+    for warning in pyproject_toml.check_build_system(uv_version) {
+        warn_user_once!("{warning}");
+    }
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    let Some(build_hook) = &settings.build_hook else {
+        return Ok(None);
+    };
+
+    let Some((module, function)) = build_hook
+        .function
+        .split_once(':')
+        .filter(|(module, function)| !module.trim().is_empty() && !function.trim().is_empty())
+    else {
+        return Err(Error::InvalidBuildHook(build_hook.function.clone()));
+    };
+
+    let (module_root, _module_relative) = find_roots(
+        source_tree,
+        &pyproject_toml,
+        &settings.module_root,
+        settings.module_name.as_ref(),
+        settings.namespace,
+        false,
+    )?;
+
+    Ok(Some(BuildHookInfo {
+        module: module.trim().to_string(),
+        function: function.trim().to_string(),
+        requires: build_hook.requires.clone(),
+        module_root,
+    }))
+}
+
+/// The wheel filename for the project.
+///
+/// Projects without a build hook are pure Python and use `py3-none-any`, projects with a build hook
+/// use the tag of the interpreter that ran the hook.
+pub(crate) fn wheel_filename(
+    pyproject_toml: &PyProjectToml,
+    settings: &BuildBackendSettings,
+) -> Result<WheelFilename, Error> {
+    let (language_tag, abi_tag, platform_tag) = if settings.build_hook.is_some() {
+        let Some(tag) = env::var_os(EnvVars::UV_INTERNAL__BUILD_HOOK_WHEEL_TAG) else {
+            return Err(Error::BuildHookNotRun);
+        };
+        let tag = tag.to_string_lossy();
+        debug!("Using the wheel tag of the build interpreter: {tag}");
+        parse_wheel_tag(&tag).ok_or_else(|| Error::InvalidWheelTag(tag.to_string()))?
+    } else {
+        (
+            LanguageTag::Python {
+                major: 3,
+                minor: None,
+            },
+            AbiTag::None,
+            PlatformTag::Any,
+        )
+    };
+
+    Ok(WheelFilename::new(
+        pyproject_toml.name().clone(),
+        pyproject_toml.version()?.clone(),
+        language_tag,
+        abi_tag,
+        platform_tag,
+    ))
+}
+
+/// Parse a single wheel tag, such as `cp312-cp312-linux_x86_64`.
+fn parse_wheel_tag(tag: &str) -> Option<(LanguageTag, AbiTag, PlatformTag)> {
+    let mut parts = tag.split('-');
+    let language_tag = LanguageTag::from_str(parts.next()?).ok()?;
+    let abi_tag = AbiTag::from_str(parts.next()?).ok()?;
+    let platform_tag = PlatformTag::from_str(parts.next()?).ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((language_tag, abi_tag, platform_tag))
+}
+
+/// The directory with the artifacts the build hook built for the wheel, if the project has a build
+/// hook.
+pub(crate) fn build_hook_dir(settings: &BuildBackendSettings) -> Result<Option<PathBuf>, Error> {
+    if settings.build_hook.is_none() {
+        return Ok(None);
+    }
+    let Some(dir) = env::var_os(EnvVars::UV_INTERNAL__BUILD_HOOK_DIR) else {
+        return Err(Error::BuildHookNotRun);
+    };
+    let dir = PathBuf::from(dir);
+    debug!("Using build hook artifacts from: {}", dir.user_display());
+    Ok(Some(dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wheel_tag() {
+        let (language_tag, abi_tag, platform_tag) =
+            parse_wheel_tag("cp313-cp313t-macosx_11_0_arm64").unwrap();
+        assert_eq!(language_tag.to_string(), "cp313");
+        assert_eq!(abi_tag.to_string(), "cp313t");
+        assert_eq!(platform_tag.to_string(), "macosx_11_0_arm64");
+
+        assert!(parse_wheel_tag("cp312-cp312").is_none());
+        assert!(parse_wheel_tag("cp312-cp312-linux_x86_64-extra").is_none());
+    }
+}
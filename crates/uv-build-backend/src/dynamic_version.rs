@@ -0,0 +1,413 @@
+//! Determine the version of a project with `dynamic = ["version"]`.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use regex::Regex;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+use uv_fs::Simplified;
+use uv_pep440::{BumpCommand, Version, VersionParseError};
+use uv_pypi_types::{Metadata10, MetadataError};
+
+use crate::settings::{LocalScheme, VersionScheme, VersionSource};
+
+/// The default pattern for extracting the version from a git tag, allowing an optional `v` prefix.
+const DEFAULT_TAG_PATTERN: &str = r"^v?(?P<version>.+)$";
+
+#[derive(Debug, Error)]
+pub enum DynamicVersionError {
+    #[error("Invalid `tool.uv.build-backend.version.tag-pattern`: `{0}`")]
+    TagPattern(String, #[source] regex::Error),
+    #[error(
+        "`tool.uv.build-backend.version.tag-pattern` must contain a named group `version`: `{0}`"
+    )]
+    TagPatternGroup(String),
+    #[error("Failed to run `git {0}`, is git installed?")]
+    GitCommand(String, #[source] io::Error),
+    #[error("`git {command}` failed: {stderr}")]
+    GitFailed { command: String, stderr: String },
+    #[error(
+        "Failed to determine the version from git in `{}`: not a git repository and there is no `PKG-INFO`",
+        _0.user_display()
+    )]
+    NotARepository(PathBuf),
+    #[error("Failed to read the version from `PKG-INFO` at `{}`", _0.user_display())]
+    PkgInfo(PathBuf, #[source] MetadataError),
+    #[error("Failed to read `{}`", _0.user_display())]
+    Read(PathBuf, #[source] io::Error),
+    #[error("No `__version__` assignment found in `{}`", _0.user_display())]
+    MissingVersionAttribute(PathBuf),
+    #[error("Invalid version `{0}`")]
+    InvalidVersion(String, #[source] VersionParseError),
+}
+
+impl VersionSource {
+    /// Determine the version of the project at `root`.
+    pub(crate) fn resolve(&self, root: &Path) -> Result<Version, DynamicVersionError> {
+        match self {
+            Self::Git {
+                tag_pattern,
+                version_scheme,
+                local_scheme,
+            } => {
+                // A source distribution doesn't contain the git repository, but the version we
+                // determined when building it.
+                let pkg_info = root.join("PKG-INFO");
+                if pkg_info.is_file() {
+                    let version = version_from_pkg_info(&pkg_info)?;
+                    debug!("Using version from `PKG-INFO`: {version}");
+                    return Ok(version);
+                }
+                let version = version_from_git(
+                    root,
+                    tag_pattern.as_deref().unwrap_or(DEFAULT_TAG_PATTERN),
+                    *version_scheme,
+                    *local_scheme,
+                )?;
+                debug!("Using version from git: {version}");
+                Ok(version)
+            }
+            Self::File { path } => {
+                let path = root.join(path);
+                let version = version_from_file(&path)?;
+                debug!("Using version from `{}`: {version}", path.user_display());
+                Ok(version)
+            }
+        }
+    }
+}
+
+fn parse_version(version: &str) -> Result<Version, DynamicVersionError> {
+    Version::from_str(version)
+        .map_err(|err| DynamicVersionError::InvalidVersion(version.to_string(), err))
+}
+
+fn version_from_pkg_info(path: &Path) -> Result<Version, DynamicVersionError> {
+    let contents =
+        fs_err::read(path).map_err(|err| DynamicVersionError::Read(path.to_path_buf(), err))?;
+    let metadata = Metadata10::parse_pkg_info(&contents)
+        .map_err(|err| DynamicVersionError::PkgInfo(path.to_path_buf(), err))?;
+    parse_version(&metadata.version)
+}
+
+/// Read the version from a `__version__ = "..."` assignment.
+fn version_from_file(path: &Path) -> Result<Version, DynamicVersionError> {
+    let contents = fs_err::read_to_string(path)
+        .map_err(|err| DynamicVersionError::Read(path.to_path_buf(), err))?;
+    let pattern = Regex::new(r#"(?m)^__version__\s*(?::\s*str\s*)?=\s*["']([^"']+)["']"#)
+        .expect("valid regex");
+    let version = pattern
+        .captures(&contents)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| DynamicVersionError::MissingVersionAttribute(path.to_path_buf()))?;
+    parse_version(version.as_str())
+}
+
+/// Run a git command in the project root, returning its trimmed stdout.
+fn git(root: &Path, args: &[&str]) -> Result<String, DynamicVersionError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|err| DynamicVersionError::GitCommand(args.join(" "), err))?;
+    if !output.status.success() {
+        return Err(DynamicVersionError::GitFailed {
+            command: args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Derive the version from the nearest tag reachable from `HEAD` that matches the tag pattern.
+fn version_from_git(
+    root: &Path,
+    tag_pattern: &str,
+    version_scheme: VersionScheme,
+    local_scheme: LocalScheme,
+) -> Result<Version, DynamicVersionError> {
+    let pattern = Regex::new(tag_pattern)
+        .map_err(|err| DynamicVersionError::TagPattern(tag_pattern.to_string(), err))?;
+    if !pattern.capture_names().any(|name| name == Some("version")) {
+        return Err(DynamicVersionError::TagPatternGroup(
+            tag_pattern.to_string(),
+        ));
+    }
+
+    let Ok(node) = git(root, &["rev-parse", "--short", "HEAD"]) else {
+        return Err(DynamicVersionError::NotARepository(root.to_path_buf()));
+    };
+    let dirty = !git(root, &["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+
+    let (version, distance) = if let Some((tag, version, distance)) = nearest_tag(root, &pattern)? {
+        debug!("Found git tag `{tag}` with {distance} commits since");
+        (version, distance)
+    } else {
+        // Like other tools, start counting from `0.0` when there are no tags yet.
+        debug!("No git tag matches `{tag_pattern}`");
+        let distance = git(root, &["rev-list", "--count", "HEAD"])?;
+        (Version::new([0, 0]), distance)
+    };
+    let distance = distance
+        .parse::<u64>()
+        .map_err(|_| DynamicVersionError::GitFailed {
+            command: "rev-list --count".to_string(),
+            stderr: format!("Unexpected output: `{distance}`"),
+        })?;
+
+    if distance == 0 && !dirty {
+        return Ok(version);
+    }
+
+    let mut version = version.without_local();
+    match version_scheme {
+        VersionScheme::GuessNextDev => {
+            if let Some(pre) = version.pre() {
+                version.bump(BumpCommand::BumpPrerelease {
+                    kind: pre.kind,
+                    value: None,
+                });
+            } else {
+                version.bump(BumpCommand::BumpRelease {
+                    index: version.release().len() - 1,
+                    value: None,
+                });
+            }
+            version.bump(BumpCommand::BumpDev {
+                value: Some(distance),
+            });
+        }
+        VersionScheme::PostRelease => {
+            let post = version.post().unwrap_or(0) + distance;
+            version.bump(BumpCommand::BumpPost { value: Some(post) });
+        }
+    }
+
+    match local_scheme {
+        LocalScheme::NodeAndDirty => {
+            let local = if dirty {
+                format!("g{node}.dirty")
+            } else {
+                format!("g{node}")
+            };
+            parse_version(&format!("{version}+{local}"))
+        }
+        LocalScheme::NoLocalVersion => Ok(version),
+    }
+}
+
+/// Find the nearest tag reachable from `HEAD` that matches the tag pattern, returning the tag, its
+/// version and the number of commits since.
+///
+/// `git describe` only filters tags by glob, so we exclude the tags that don't match the pattern
+/// and ask again.
+fn nearest_tag(
+    root: &Path,
+    pattern: &Regex,
+) -> Result<Option<(String, Version, String)>, DynamicVersionError> {
+    let mut excluded: Vec<String> = Vec::new();
+    loop {
+        let mut args = vec!["describe", "--tags", "--long", "--match", "*"];
+        for tag in &excluded {
+            args.extend(["--exclude", tag.as_str()]);
+        }
+        // `git describe` fails if there is no (remaining) tag.
+        let Ok(description) = git(root, &args) else {
+            return Ok(None);
+        };
+        // The description has the form `<tag>-<distance>-g<node>`, where the tag may contain
+        // dashes.
+        let mut parts = description.rsplitn(3, '-');
+        let (Some(_node), Some(distance), Some(tag)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(DynamicVersionError::GitFailed {
+                command: args.join(" "),
+                stderr: format!("Unexpected output: `{description}`"),
+            });
+        };
+        if let Some(version) = pattern
+            .captures(tag)
+            .and_then(|captures| captures.name("version"))
+            .and_then(|version| Version::from_str(version.as_str()).ok())
+        {
+            return Ok(Some((tag.to_string(), version, distance.to_string())));
+        }
+        trace!("Skipping git tag `{tag}`, it doesn't match the tag pattern");
+        excluded.push(escape_glob(tag));
+    }
+}
+
+/// Escape the glob metacharacters in a tag name for `git describe --exclude`.
+fn escape_glob(tag: &str) -> String {
+    let mut escaped = String::with_capacity(tag.len());
+    for char in tag.chars() {
+        if matches!(char, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn version_attribute() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("__init__.py");
+        fs_err::write(
+            &path,
+            indoc! {r#"
+                """A module."""
+
+                __version__: str = '1.2.3rc1'
+            "#},
+        )
+        .unwrap();
+        assert_eq!(
+            version_from_file(&path).unwrap(),
+            Version::from_str("1.2.3rc1").unwrap()
+        );
+
+        fs_err::write(&path, "VERSION = '1.2.3'\n").unwrap();
+        assert!(matches!(
+            version_from_file(&path),
+            Err(DynamicVersionError::MissingVersionAttribute(_))
+        ));
+    }
+
+    fn run_git(root: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args([
+                "-c",
+                "user.name=uv",
+                "-c",
+                "user.email=uv@example.com",
+                "-c",
+                "commit.gpgsign=false",
+                "-c",
+                "tag.gpgsign=false",
+            ])
+            .args(args)
+            .current_dir(root)
+            .status()
+            .unwrap();
+        assert!(status.success(), "`git {}` failed", args.join(" "));
+    }
+
+    fn commit(root: &Path, contents: &str) {
+        fs_err::write(root.join("file.txt"), contents).unwrap();
+        run_git(root, &["add", "file.txt"]);
+        run_git(root, &["commit", "--quiet", "-m", contents]);
+    }
+
+    #[test]
+    fn git_tags() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        run_git(root, &["init", "--quiet"]);
+        commit(root, "first");
+
+        let version = |tag_pattern: &str, version_scheme, local_scheme| {
+            version_from_git(root, tag_pattern, version_scheme, local_scheme)
+                .unwrap()
+                .to_string()
+        };
+
+        // Without a tag, count from `0.0`.
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NoLocalVersion
+            ),
+            "0.1.dev1"
+        );
+
+        // On a tag, use the tag version.
+        run_git(root, &["tag", "v1.2.3"]);
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NodeAndDirty
+            ),
+            "1.2.3"
+        );
+
+        // After the tag, use the version scheme.
+        commit(root, "second");
+        commit(root, "third");
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NoLocalVersion
+            ),
+            "1.2.4.dev2"
+        );
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::PostRelease,
+                LocalScheme::NoLocalVersion
+            ),
+            "1.2.3.post2"
+        );
+        let node = git(root, &["rev-parse", "--short", "HEAD"]).unwrap();
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NodeAndDirty
+            ),
+            format!("1.2.4.dev2+g{node}")
+        );
+
+        // Uncommitted changes mark the version as dirty.
+        fs_err::write(root.join("file.txt"), "changed").unwrap();
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NodeAndDirty
+            ),
+            format!("1.2.4.dev2+g{node}.dirty")
+        );
+
+        // Tags that don't match the pattern are ignored.
+        run_git(root, &["tag", "release-2.0.0rc1"]);
+        assert_eq!(
+            version(
+                r"^release-(?P<version>.+)$",
+                VersionScheme::GuessNextDev,
+                LocalScheme::NoLocalVersion
+            ),
+            "2.0.0rc2.dev0"
+        );
+
+        // Use the nearest tag, skipping tags whose version doesn't parse.
+        run_git(root, &["tag", "v1.3.0", "HEAD~1"]);
+        assert_eq!(
+            version(
+                DEFAULT_TAG_PATTERN,
+                VersionScheme::GuessNextDev,
+                LocalScheme::NoLocalVersion
+            ),
+            "1.3.1.dev1"
+        );
+    }
+
+    #[test]
+    fn glob_escape() {
+        assert_eq!(escape_glob("v1.0"), "v1.0");
+        assert_eq!(escape_glob("v[1]*?"), r"v\[1\]\*\?");
+    }
+}
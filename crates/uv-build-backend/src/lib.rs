@@ -1,18 +1,25 @@
 use itertools::Itertools;
+mod build_hook;
+mod dynamic_version;
 mod metadata;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
 mod wheel;
 
+pub use build_hook::{BuildHookInfo, build_hook};
+pub use dynamic_version::DynamicVersionError;
 pub use metadata::{PyProjectToml, check_direct_build};
-pub use settings::{BuildBackendSettings, WheelDataIncludes};
+pub use settings::{
+    BuildBackendSettings, BuildHook, LocalScheme, VersionScheme, VersionSource, WheelDataIncludes,
+};
 pub use source_dist::{build_source_dist, list_source_dist};
 use uv_warnings::warn_user_once;
 pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
 
 use rustc_hash::FxHashSet;
 use std::collections::HashSet;
+use std::env;
 use std::ffi::OsStr;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -24,6 +31,7 @@ use uv_fs::{Simplified, normalize_path};
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
 use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_static::EnvVars;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -40,6 +48,8 @@ pub enum Error {
     TomlSerialize(#[source] toml::ser::Error),
     #[error("Invalid project metadata")]
     Validation(#[from] ValidationError),
+    #[error("Failed to determine the dynamic project version")]
+    DynamicVersion(#[from] DynamicVersionError),
     #[error("Invalid module name: {0}")]
     InvalidModuleName(String, #[source] IdentifierParseError),
     #[error("Unsupported glob expression in: {field}")]
@@ -85,6 +95,18 @@ pub enum Error {
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
     TarWrite(PathBuf, #[source] io::Error),
+    #[error("Invalid `SOURCE_DATE_EPOCH`, expected a Unix timestamp: `{0}`")]
+    InvalidSourceDateEpoch(String),
+    #[error(
+        "Invalid `tool.uv.build-backend.build-hook.function`, expected `module:function`: `{0}`"
+    )]
+    InvalidBuildHook(String),
+    #[error(
+        "The project has a build hook (`tool.uv.build-backend.build-hook`), which is only supported when building through the PEP 517 interface of `uv_build`"
+    )]
+    BuildHookNotRun,
+    #[error("Invalid wheel tag of the build interpreter: `{0}`")]
+    InvalidWheelTag(String),
 }
 
 impl uv_errors::Hint for Error {
@@ -230,6 +252,24 @@ fn check_metadata_directory(
     Ok(())
 }
 
+/// Read the modification time for archive entries from `SOURCE_DATE_EPOCH`, if set.
+///
+/// See <https://reproducible-builds.org/docs/source-date-epoch/>.
+fn source_date_epoch() -> Result<Option<u64>, Error> {
+    let Some(value) = env::var_os(EnvVars::SOURCE_DATE_EPOCH) else {
+        return Ok(None);
+    };
+    let value = value.to_string_lossy();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let epoch = value
+        .parse::<u64>()
+        .map_err(|_| Error::InvalidSourceDateEpoch(value.to_string()))?;
+    debug!("Using `SOURCE_DATE_EPOCH` for archive timestamps: {epoch}");
+    Ok(Some(epoch))
+}
+
 /// Returns the list of module names without names which would be included twice
 ///
 /// In normal cases it should do nothing:
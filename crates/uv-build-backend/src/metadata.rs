@@ -8,6 +8,7 @@ use std::fmt::Display;
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::str::{self, FromStr};
+use std::sync::OnceLock;
 use tracing::{debug, trace, warn};
 use uv_warnings::warn_user_once;
 use version_ranges::Ranges;
@@ -66,8 +67,16 @@ pub enum ValidationError {
     UnsupportedContentType(String),
     #[error("`project.description` must be a single line")]
     DescriptionNewlines,
-    #[error("Dynamic metadata is not supported")]
+    #[error("Dynamic metadata is only supported for `version`")]
     Dynamic,
+    #[error("`project.version` is missing, set it or declare it as dynamic")]
+    MissingVersion,
+    #[error("`project.version` must not be set when `version` is declared as dynamic")]
+    StaticAndDynamicVersion,
+    #[error(
+        "`version` is declared as dynamic, but there is no `tool.uv.build-backend.version` to read it from"
+    )]
+    MissingVersionSource,
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -133,6 +142,8 @@ pub enum DirectBuildIncompatibility {
     UrlRequirement,
     #[error("`uv_build{0}` is not a known compatible range")]
     IncompatibleRange(VersionSpecifiers),
+    #[error("it has a build hook (`tool.uv.build-backend.build-hook`)")]
+    BuildHook,
 }
 
 #[derive(Debug, Clone)]
@@ -255,6 +266,24 @@ pub fn check_direct_build(
     #[serde(rename_all = "kebab-case")]
     struct PyProjectToml {
         build_system: BuildSystem,
+        tool: Option<Tool>,
+    }
+
+    #[derive(Deserialize)]
+    struct Tool {
+        uv: Option<ToolUv>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUv {
+        build_backend: Option<ToolUvBuildBackend>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUvBuildBackend {
+        build_hook: Option<serde::de::IgnoredAny>,
     }
 
     let path = source_tree.join("pyproject.toml");
@@ -326,6 +355,16 @@ pub fn check_direct_build(
         Some(VersionOrUrl::VersionSpecifier(_)) => {}
     }
 
+    // Build hooks are called from the Python shim of the PEP 517 interface.
+    if pyproject_toml
+        .tool
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.build_backend)
+        .is_some_and(|build_backend| build_backend.build_hook.is_some())
+    {
+        return Err(DirectBuildIncompatibility::BuildHook);
+    }
+
     Ok(())
 }
 
@@ -366,6 +405,12 @@ pub struct PyProjectToml {
     tool: Option<Tool>,
     /// Build-related data
     build_system: BuildSystem,
+    /// The directory containing the `pyproject.toml`.
+    #[serde(skip)]
+    root: PathBuf,
+    /// The version of a project with `dynamic = ["version"]`, determined on first use.
+    #[serde(skip)]
+    dynamic_version: OnceLock<Version>,
 }
 
 impl PyProjectToml {
@@ -373,19 +418,62 @@ impl PyProjectToml {
         &self.project.name.normalized
     }
 
-    pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+    /// The project version.
+    ///
+    /// For a project with `dynamic = ["version"]`, the version is determined from the configured
+    /// version source on first use, as this may require running git.
+    pub(crate) fn version(&self) -> Result<&Version, Error> {
+        if let Some(version) = &self.project.version {
+            return Ok(version);
+        }
+        if let Some(version) = self.dynamic_version.get() {
+            return Ok(version);
+        }
+        let Some(version_source) = self
+            .settings()
+            .and_then(|settings| settings.version.as_ref())
+        else {
+            return Err(ValidationError::MissingVersionSource.into());
+        };
+        let version = version_source.resolve(&self.root)?;
+        Ok(self.dynamic_version.get_or_init(|| version))
     }
 
     pub(crate) fn parse(path: &Path) -> Result<Self, Error> {
         let contents = fs_err::read_to_string(path)?;
-        let pyproject_toml =
+        let mut pyproject_toml: Self =
             tracing::info_span!("toml::from_str uv build backend", path = %path.display())
                 .in_scope(|| toml::from_str(&contents))
                 .map_err(|err| Error::Toml(path.to_path_buf(), err))?;
+        pyproject_toml.root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        pyproject_toml.validate_version()?;
         Ok(pyproject_toml)
     }
 
+    /// Check that the project either has a static version or a source for its dynamic version.
+    fn validate_version(&self) -> Result<(), Error> {
+        let dynamic = self
+            .project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version");
+        match (&self.project.version, dynamic) {
+            (Some(_), false) => Ok(()),
+            (Some(_), true) => Err(ValidationError::StaticAndDynamicVersion.into()),
+            (None, false) => Err(ValidationError::MissingVersion.into()),
+            (None, true) => {
+                if self
+                    .settings()
+                    .is_none_or(|settings| settings.version.is_none())
+                {
+                    return Err(ValidationError::MissingVersionSource.into());
+                }
+                Ok(())
+            }
+        }
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -494,11 +582,13 @@ impl PyProjectToml {
         if self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .any(|field| field != "version")
         {
             return Err(ValidationError::Dynamic.into());
         }
+        let version = self.version()?;
 
         let import_names = parse_import_names(self.project.import_names.as_deref())?;
         let import_namespaces = parse_import_namespaces(self.project.import_namespaces.as_deref())?;
@@ -651,7 +741,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.given.clone(),
-            version: self.project.version.to_string(),
+            version: version.to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -952,7 +1042,9 @@ struct Project {
     /// The name of the project.
     name: VerbatimPackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// Unset if the version is dynamic.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -1010,7 +1102,7 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Only `version` is supported, read from `tool.uv.build-backend.version`.
     dynamic: Option<Vec<String>>,
 }
 
@@ -1999,10 +2091,87 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(format_err(err), @"
         Invalid project metadata
-          Caused by: Dynamic metadata is not supported
+          Caused by: Dynamic metadata is only supported for `version`
         ");
     }
 
+    fn dynamic_version_error(contents: &str) -> String {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_toml = temp_dir.path().join("pyproject.toml");
+        fs_err::write(&pyproject_toml, contents).unwrap();
+        format_err(PyProjectToml::parse(&pyproject_toml).unwrap_err())
+    }
+
+    #[test]
+    fn dynamic_version_validation() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            dynamic = ["version"]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#
+        };
+        assert_snapshot!(dynamic_version_error(contents), @"
+        Invalid project metadata
+          Caused by: `version` is declared as dynamic, but there is no `tool.uv.build-backend.version` to read it from
+        ");
+
+        let contents = extend_project(indoc! {r#"
+            dynamic = ["version"]
+        "#
+        });
+        assert_snapshot!(dynamic_version_error(&contents), @"
+        Invalid project metadata
+          Caused by: `project.version` must not be set when `version` is declared as dynamic
+        ");
+
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#
+        };
+        assert_snapshot!(dynamic_version_error(contents), @"
+        Invalid project metadata
+          Caused by: `project.version` is missing, set it or declare it as dynamic
+        ");
+    }
+
+    #[test]
+    fn dynamic_version_from_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            dynamic = ["version"]
+
+            [tool.uv.build-backend]
+            version = { source = "file", path = "src/hello_world/__init__.py" }
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#
+        };
+        fs_err::write(temp_dir.path().join("pyproject.toml"), contents).unwrap();
+        fs_err::create_dir_all(temp_dir.path().join("src/hello_world")).unwrap();
+        fs_err::write(
+            temp_dir.path().join("src/hello_world/__init__.py"),
+            "__version__ = \"1.2.3\"\n",
+        )
+        .unwrap();
+
+        let pyproject_toml = PyProjectToml::parse(&temp_dir.path().join("pyproject.toml")).unwrap();
+        let metadata = pyproject_toml.to_metadata(temp_dir.path()).unwrap();
+        assert_eq!(metadata.version, "1.2.3");
+    }
+
     fn script_error(contents: &str) -> String {
         let err = toml::from_str::<PyProjectToml>(contents)
             .unwrap()
@@ -2060,6 +2229,31 @@ mod tests {
         check_direct_build(temp_dir.path(), "0.10.0").unwrap();
     }
 
+    #[test]
+    fn check_direct_build_build_hook() {
+        let temp_dir = TempDir::new().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            indoc! {r#"
+                [project]
+                name = "hello-world"
+                version = "0.1.0"
+
+                [build-system]
+                requires = ["uv_build>=0.10.0,<0.11"]
+                build-backend = "uv_build"
+
+                [tool.uv.build-backend]
+                build-hook = { function = "build_extensions:build" }
+            "#},
+        )
+        .unwrap();
+        assert_snapshot!(
+            check_direct_build(temp_dir.path(), "0.10.0").unwrap_err(),
+            @"it has a build hook (`tool.uv.build-backend.build-hook`)"
+        );
+    }
+
     #[test]
     fn check_direct_build_parse_error() {
         let temp_dir = TempDir::new().unwrap();
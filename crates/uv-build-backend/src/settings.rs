@@ -171,6 +171,54 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub(crate) data: WheelDataIncludes,
+
+    /// Where to read the project version from, for projects that declare
+    /// `dynamic = ["version"]`.
+    ///
+    /// - `source = "git"`: Derive the version from the nearest tag reachable from `HEAD`.
+    ///   `tag-pattern` is a regular expression with a named group `version` that extracts the
+    ///   version from the tag, it defaults to `^v?(?P<version>.+)$`. When there are commits since the
+    ///   tag, `version-scheme` determines the version: `guess-next-dev` (the default) increments
+    ///   the tag version and adds a dev segment with the number of commits since the tag, e.g.,
+    ///   `1.2.4.dev3`, while `post-release` adds a post segment, e.g., `1.2.3.post3`.
+    ///   `local-scheme` determines the local version segment: `node-and-dirty` (the default) adds the
+    ///   commit hash and a `dirty` marker for uncommitted changes, e.g., `1.2.4.dev3+gabc1234.dirty`,
+    ///   while `no-local-version` omits the local segment.
+    /// - `source = "file"`: Read the version from a `__version__ = "..."` assignment in the file
+    ///   at `path`, relative to the project root.
+    ///
+    /// The version is written to the `PKG-INFO` of the source distribution, which is used as the
+    /// version source when building a wheel from the source distribution.
+    #[option(
+        default = r#"None"#,
+        value_type = "dict",
+        example = r#"version = { source = "git", tag-pattern = "^release-(?P<version>.+)$" }"#
+    )]
+    pub(crate) version: Option<VersionSource>,
+
+    /// A Python function that builds compiled artifacts, such as C or Cython extension modules,
+    /// for the wheel.
+    ///
+    /// `function` is an entrypoint in the form `module:function`, imported from the project root.
+    /// `requires` are additional build requirements that are installed before calling the
+    /// function.
+    ///
+    /// When building a wheel, the function is called as `function(output_directory,
+    /// config_settings)` and writes its artifacts to the output directory, using the same layout
+    /// as the wheel, e.g., `foo/_speedups.cpython-312-x86_64-linux-gnu.so`. The artifacts are
+    /// added to the wheel next to the module files, and the wheel is tagged for the building
+    /// interpreter (e.g., `cp312-cp312-linux_x86_64`) instead of `py3-none-any`. For editable
+    /// installs, the output directory is the module root, so the artifacts are built in place.
+    ///
+    /// The build hook runs through the PEP 517 interface of `uv_build`, so uv doesn't use its
+    /// integrated build backend for projects with a build hook. When building from a source
+    /// distribution, the module containing the function must be included with `source-include`.
+    #[option(
+        default = r#"None"#,
+        value_type = "dict",
+        example = r#"build-hook = { function = "build_extensions:build", requires = ["cython>=3"] }"#
+    )]
+    pub(crate) build_hook: Option<BuildHook>,
 }
 
 impl Default for BuildBackendSettings {
@@ -184,6 +232,8 @@ impl Default for BuildBackendSettings {
             wheel_exclude: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            version: None,
+            build_hook: None,
         }
     }
 }
@@ -228,3 +278,76 @@ impl WheelDataIncludes {
         .filter_map(|(name, value)| Some((name, value?)))
     }
 }
+
+/// A Python function that builds compiled artifacts for the wheel.
+///
+/// See `BuildBackendSettings::build_hook`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BuildHook {
+    /// The function to call, in the form `module:function`.
+    pub function: String,
+    /// Additional requirements for building the wheel, such as `cython`.
+    #[serde(default)]
+    pub requires: Vec<String>,
+}
+
+/// Where to read the version of a project with a dynamic version from.
+///
+/// See `BuildBackendSettings::version`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(
+    tag = "source",
+    rename_all = "kebab-case",
+    rename_all_fields = "kebab-case",
+    deny_unknown_fields
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionSource {
+    /// Derive the version from the nearest git tag.
+    Git {
+        /// A regular expression with a named group `version` that extracts the version from a tag.
+        #[serde(default)]
+        tag_pattern: Option<String>,
+        /// How to derive the version when there are commits since the nearest tag.
+        #[serde(default)]
+        version_scheme: VersionScheme,
+        /// Which local version segment to add when there are commits since the nearest tag or
+        /// uncommitted changes.
+        #[serde(default)]
+        local_scheme: LocalScheme,
+    },
+    /// Read the version from a `__version__` assignment in a file.
+    File {
+        /// The path to the file, relative to the project root.
+        path: PathBuf,
+    },
+}
+
+/// How to derive the version when there are commits since the nearest tag.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionScheme {
+    /// Increment the tag version and add a dev segment with the number of commits since the tag,
+    /// e.g., `1.2.4.dev3` for three commits after `v1.2.3`.
+    #[default]
+    GuessNextDev,
+    /// Add a post segment with the number of commits since the tag, e.g., `1.2.3.post3` for three
+    /// commits after `v1.2.3`.
+    PostRelease,
+}
+
+/// Which local version segment to add to versions derived from git.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LocalScheme {
+    /// Add the abbreviated commit hash, and `dirty` if there are uncommitted changes, e.g.,
+    /// `+gabc1234.dirty`.
+    #[default]
+    NodeAndDirty,
+    /// Don't add a local version segment.
+    NoLocalVersion,
+}
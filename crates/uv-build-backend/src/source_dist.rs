@@ -2,7 +2,8 @@ use crate::metadata::DEFAULT_EXCLUDES;
 use crate::wheel::build_exclude_matcher;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots, write_directory_once, write_file_with_directories,
+    error_on_venv, find_roots, source_date_epoch, write_directory_once,
+    write_file_with_directories,
 };
 use flate2::Compression;
 use flate2::write::GzEncoder;
@@ -36,7 +37,7 @@ pub fn build_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let source_dist_path = source_dist_directory.join(filename.to_string());
@@ -46,7 +47,8 @@ pub fn build_source_dist(
     }
 
     let temp_file = uv_fs::tempfile_in(source_dist_directory)?;
-    let writer = TarGzWriter::new(temp_file.as_file(), &source_dist_path);
+    let mtime = source_date_epoch()?.unwrap_or_default();
+    let writer = TarGzWriter::new(temp_file.as_file(), &source_dist_path, mtime);
     write_source_dist(source_tree, writer, uv_version, show_warnings)?;
     temp_file
         .persist(&source_dist_path)
@@ -64,7 +66,7 @@ pub fn list_source_dist(
     let pyproject_toml = PyProjectToml::parse(&source_tree.join("pyproject.toml"))?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
     let mut files = FileList::new();
@@ -211,14 +213,14 @@ fn write_source_dist(
 
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
-        version: pyproject_toml.version().clone(),
+        version: pyproject_toml.version()?.clone(),
         extension: SourceDistExtension::TarGz,
     };
 
     let top_level = format!(
         "{}-{}",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     let metadata = pyproject_toml.to_metadata(source_tree)?;
@@ -426,14 +428,16 @@ impl<W: Write + Unpin> AsyncWrite for SyncWriter<W> {
 struct TarGzWriter<W: Write + Unpin + Send> {
     path: PathBuf,
     tar: tokio_tar::Builder<SyncWriter<GzEncoder<W>>>,
+    /// The modification time of all entries, as a Unix timestamp.
+    mtime: u64,
 }
 
 impl<W: Write + Unpin + Send> TarGzWriter<W> {
-    fn new(writer: W, path: impl Into<PathBuf>) -> Self {
+    fn new(writer: W, path: impl Into<PathBuf>, mtime: u64) -> Self {
         let path = path.into();
         let enc = GzEncoder::new(writer, Compression::default());
         let tar = tokio_tar::Builder::new_non_terminated(SyncWriter::new(enc));
-        Self { path, tar }
+        Self { path, tar, mtime }
     }
 }
 
@@ -474,6 +478,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TarGzWriter<W> {
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
+        header.set_mtime(self.mtime);
         block_on(
             self.tar
                 .append_data(&mut header, path, SyncReader::new(Cursor::new(bytes))),
@@ -508,6 +513,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TarGzWriter<W> {
             header.set_mode(0o644);
         }
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         block_on(
             self.tar
@@ -523,6 +529,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TarGzWriter<W> {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         block_on(
             self.tar
                 .append_data(&mut header, directory, SyncReader::new(io::empty())),
@@ -1,10 +1,12 @@
 use async_zip::base::write::{EntrySeekableWriter, ZipFileWriter};
-use async_zip::{Compression, ZipEntryBuilder};
+use async_zip::{Compression, ZipDateTime, ZipDateTimeBuilder, ZipEntryBuilder};
 use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD as base64};
 use fs_err::File;
 use futures_lite::future::block_on;
 use futures_lite::io::{AsyncSeek, AsyncWrite, AsyncWriteExt};
 use globset::{GlobSet, GlobSetBuilder};
+use jiff::Timestamp;
+use jiff::tz::TimeZone;
 use rustc_hash::FxHashSet;
 use sha2::{Digest, Sha256};
 use std::fmt::{Display, Formatter};
@@ -23,10 +25,12 @@ use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_preview::PreviewFeature;
 use uv_warnings::warn_user_once;
 
+use crate::build_hook::{build_hook_dir, wheel_filename};
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots, write_directory_once, write_file_with_directories,
+    error_on_venv, find_roots, source_date_epoch, write_directory_once,
+    write_file_with_directories,
 };
 
 // Files at or below this size are buffered and written with `write_entry_whole`,
@@ -53,16 +57,11 @@ pub fn build_wheel(
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
@@ -72,7 +71,7 @@ pub fn build_wheel(
     }
 
     let temp_file = uv_fs::tempfile_in(wheel_dir)?;
-    let wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
+    let wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file(), zip_modification_date()?);
 
     write_wheel(
         source_tree,
@@ -103,7 +102,7 @@ pub fn list_wheel(
 
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
+        pyproject_toml.version()?.clone(),
         LanguageTag::Python {
             major: 3,
             minor: None,
@@ -137,6 +136,7 @@ fn write_wheel(
         .settings()
         .cloned()
         .unwrap_or_else(BuildBackendSettings::default);
+    let build_hook_artifacts = build_hook_artifacts(&settings)?;
 
     // Wheel excludes
     let mut excludes: Vec<String> = Vec::new();
@@ -212,6 +212,18 @@ fn write_wheel(
                 continue;
             }
 
+            // Prefer the artifacts from the build hook over stale in-place builds.
+            if build_hook_artifacts
+                .iter()
+                .any(|(artifact_path, _)| artifact_path == entry_path)
+            {
+                trace!(
+                    "Skipping module file built by the build hook: {}",
+                    entry_path.user_display()
+                );
+                continue;
+            }
+
             debug!("Adding to wheel: {}", entry_path.user_display());
             write_file_with_directories(
                 &mut wheel_writer,
@@ -224,13 +236,27 @@ fn write_wheel(
     }
     debug!("Visited {files_visited} files for wheel build");
 
+    if !build_hook_artifacts.is_empty() {
+        debug!("Adding build hook artifacts to wheel");
+    }
+    for (entry_path, path) in &build_hook_artifacts {
+        debug!("Adding to wheel: {}", entry_path.user_display());
+        write_file_with_directories(
+            &mut wheel_writer,
+            &mut written_directories,
+            Path::new(""),
+            entry_path,
+            path,
+        )?;
+    }
+
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
         debug!("Adding license files");
         let license_dir = format!(
             "{}-{}.dist-info/licenses/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version()
+            pyproject_toml.version()?
         );
 
         wheel_subdir_from_globs(
@@ -262,6 +288,31 @@ fn write_wheel(
     Ok(())
 }
 
+/// Collect the files the build hook built for the wheel, as pairs of the path in the wheel and the
+/// path on disk.
+fn build_hook_artifacts(settings: &BuildBackendSettings) -> Result<Vec<(PathBuf, PathBuf)>, Error> {
+    let Some(build_hook_dir) = build_hook_dir(settings)? else {
+        return Ok(Vec::new());
+    };
+    let mut artifacts = Vec::new();
+    for entry in WalkDir::new(&build_hook_dir).sort_by_file_name() {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: build_hook_dir.clone(),
+            err,
+        })?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let entry_path = entry
+            .path()
+            .strip_prefix(&build_hook_dir)
+            .expect("walkdir starts with root")
+            .to_path_buf();
+        artifacts.push((entry_path, entry.into_path()));
+    }
+    Ok(artifacts)
+}
+
 /// Build a wheel from the source tree and place it in the output directory.
 pub fn build_editable(
     source_tree: &Path,
@@ -281,16 +332,7 @@ pub fn build_editable(
 
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
@@ -300,7 +342,8 @@ pub fn build_editable(
     }
 
     let temp_file = uv_fs::tempfile_in(wheel_dir)?;
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(temp_file.as_file(), zip_modification_date()?);
 
     debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
@@ -367,7 +410,7 @@ fn write_data_files<'data>(
         let data_dir = format!(
             "{}-{}.data/{}/",
             pyproject_toml.name().as_dist_info_name(),
-            pyproject_toml.version(),
+            pyproject_toml.version()?,
             name
         );
 
@@ -394,16 +437,11 @@ pub fn metadata(
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     debug!(
         "Writing metadata files to {}",
@@ -631,7 +669,7 @@ fn write_dist_info(
     let dist_info_dir = format!(
         "{}-{}.dist-info",
         pyproject_toml.name().as_dist_info_name(),
-        pyproject_toml.version()
+        pyproject_toml.version()?
     );
 
     writer.write_directory(&dist_info_dir)?;
@@ -698,7 +736,8 @@ impl WheelInfo {
         Self {
             wheel_version: "1.0".to_string(),
             generator: format!("uv {uv_version}"),
-            root_is_purelib: true,
+            // Wheels with compiled artifacts from a build hook are platform-specific.
+            root_is_purelib: filename.platform_tags() == [PlatformTag::Any],
             tags,
         }
     }
@@ -717,10 +756,42 @@ impl Display for WheelInfo {
     }
 }
 
+/// The modification date for wheel entries, from `SOURCE_DATE_EPOCH` if set.
+///
+/// ZIP timestamps can't represent dates before 1980, so earlier timestamps are clamped to
+/// 1980-01-01, which is also the default.
+fn zip_modification_date() -> Result<ZipDateTime, Error> {
+    let Some(epoch) = source_date_epoch()? else {
+        return Ok(ZipDateTime::default());
+    };
+    let datetime = i64::try_from(epoch)
+        .ok()
+        .and_then(|epoch| Timestamp::from_second(epoch).ok())
+        .ok_or_else(|| Error::InvalidSourceDateEpoch(epoch.to_string()))?
+        .to_zoned(TimeZone::UTC)
+        .datetime();
+    if datetime.year() < 1980 {
+        return Ok(ZipDateTime::default());
+    }
+    if datetime.year() > 2107 {
+        return Err(Error::InvalidSourceDateEpoch(epoch.to_string()));
+    }
+    Ok(ZipDateTimeBuilder::new()
+        .year(i32::from(datetime.year()))
+        .month(u32::from(datetime.month().unsigned_abs()))
+        .day(u32::from(datetime.day().unsigned_abs()))
+        .hour(u32::from(datetime.hour().unsigned_abs()))
+        .minute(u32::from(datetime.minute().unsigned_abs()))
+        .second(u32::from(datetime.second().unsigned_abs()))
+        .build())
+}
+
 /// ZIP archive (wheel) writer.
 struct ZipDirectoryWriter<W: AsyncWrite + AsyncSeek + Unpin> {
     writer: ZipFileWriter<W>,
     compression: Compression,
+    /// The modification date of all entries.
+    modification_date: ZipDateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
@@ -733,8 +804,10 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> ZipDirectoryWriter<W> {
     const EXECUTABLE_FILE_MODE: u16 = 0o100_755;
     const DIRECTORY_MODE: u16 = 0o040_755;
 
-    fn entry(path: &str, compression: Compression, mode: u16) -> ZipEntryBuilder {
-        ZipEntryBuilder::new(path.to_string().into(), compression).unix_permissions(mode)
+    fn entry(&self, path: &str, compression: Compression, mode: u16) -> ZipEntryBuilder {
+        ZipEntryBuilder::new(path.to_string().into(), compression)
+            .unix_permissions(mode)
+            .last_modification_date(self.modification_date)
     }
 
     /// Add a file with the given name and return a writer for it.
@@ -749,7 +822,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> ZipDirectoryWriter<W> {
         } else {
             Self::REGULAR_FILE_MODE
         };
-        let entry = Self::entry(path, self.compression, mode);
+        let entry = self.entry(path, self.compression, mode);
         let writer = block_on(self.writer.write_entry_seekable(entry))?;
         Ok(EntryWriter::new(writer))
     }
@@ -795,10 +868,11 @@ impl<W: Seek + Unpin> AsyncSeek for SyncWriter<W> {
 
 impl<W: Write + Seek + Unpin> ZipDirectoryWriter<SyncWriter<W>> {
     /// A wheel writer with deflate compression.
-    fn new_wheel(writer: W) -> Self {
+    fn new_wheel(writer: W, modification_date: ZipDateTime) -> Self {
         Self {
             writer: ZipFileWriter::new(SyncWriter::new(writer)),
             compression: Compression::Deflate,
+            modification_date,
             record: Vec::new(),
         }
     }
@@ -811,6 +885,7 @@ impl<W: Write + Seek + Unpin> ZipDirectoryWriter<SyncWriter<W>> {
         Self {
             writer: ZipFileWriter::new(SyncWriter::new(writer)),
             compression: Compression::Stored,
+            modification_date: ZipDateTime::default(),
             record: Vec::new(),
         }
     }
@@ -858,7 +933,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         trace!("Adding {}", path);
         // Set appropriate permissions for metadata files (644 = rw-r--r--)
-        let entry = Self::entry(path, self.compression, Self::REGULAR_FILE_MODE);
+        let entry = self.entry(path, self.compression, Self::REGULAR_FILE_MODE);
         block_on(self.writer.write_entry_whole(entry, bytes))?;
 
         let hash = base64.encode(Sha256::new().chain_update(bytes).finalize());
@@ -891,7 +966,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
 
         if metadata.len() <= WHOLE_FILE_ZIP_ENTRY_LIMIT {
             let bytes = fs_err::read(file)?;
-            let entry = Self::entry(path, self.compression, mode);
+            let entry = self.entry(path, self.compression, mode);
             block_on(self.writer.write_entry_whole(entry, &bytes))?;
 
             let hash = base64.encode(Sha256::new().chain_update(&bytes).finalize());
@@ -917,7 +992,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
         } else {
             format!("{directory}/")
         };
-        let entry = Self::entry(&directory, Compression::Stored, Self::DIRECTORY_MODE);
+        let entry = self.entry(&directory, Compression::Stored, Self::DIRECTORY_MODE);
         block_on(self.writer.write_entry_whole(entry, &[]))?;
         Ok(())
     }
@@ -929,7 +1004,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
         let record = mem::take(&mut self.record);
         let mut record_bytes = Vec::new();
         write_record(&mut record_bytes, dist_info_dir, record)?;
-        let entry = Self::entry(&record_path, self.compression, Self::REGULAR_FILE_MODE);
+        let entry = self.entry(&record_path, self.compression, Self::REGULAR_FILE_MODE);
         block_on(self.writer.write_entry_whole(entry, &record_bytes))?;
 
         trace!("Adding central directory");
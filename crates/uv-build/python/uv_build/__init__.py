@@ -26,6 +26,10 @@ if TYPE_CHECKING:
 # overlapping `uv-build` executable.
 USE_UV_EXECUTABLE = False
 
+# The build hook configuration, resolved on first use. Frontends may call several hooks in the
+# same process, and each lookup spawns a uv subprocess.
+_BUILD_HOOK: "dict[str, Any] | None | bool" = False
+
 
 def warn_config_settings(config_settings: "Mapping[Any, Any] | None" = None) -> None:
     import sys
@@ -35,9 +39,12 @@ def warn_config_settings(config_settings: "Mapping[Any, Any] | None" = None) ->
 
 
 def call(
-    args: "Sequence[str]", config_settings: "Mapping[Any, Any] | None" = None
+    args: "Sequence[str]",
+    config_settings: "Mapping[Any, Any] | None" = None,
+    env: "Mapping[str, str] | None" = None,
 ) -> str:
     """Invoke a uv subprocess and return the filename from stdout."""
+    import os
     import shutil
     import subprocess
     import sys
@@ -52,7 +59,9 @@ def call(
     build_backend_args = ["build-backend"] if USE_UV_EXECUTABLE else []
     # Forward stderr, capture stdout for the filename
     result = subprocess.run(
-        [uv_bin, *build_backend_args, *args], stdout=subprocess.PIPE
+        [uv_bin, *build_backend_args, *args],
+        stdout=subprocess.PIPE,
+        env={**os.environ, **env} if env else None,
     )
     if result.returncode != 0:
         sys.exit(result.returncode)
@@ -69,6 +78,71 @@ def call(
     return stdout[-1].strip()
 
 
+def get_build_hook() -> "dict[str, Any] | None":
+    """Read the build hook configuration from `tool.uv.build-backend.build-hook`.
+
+    The configuration is read once per process and cached.
+    """
+    global _BUILD_HOOK
+
+    if _BUILD_HOOK is False:
+        import json
+
+        _BUILD_HOOK = json.loads(call(["build-hook"]))
+    return _BUILD_HOOK  # type: ignore[return-value]
+
+
+def wheel_tag() -> str:
+    """The wheel tag of the running interpreter, e.g., `cp312-cp312-linux_x86_64`."""
+    import sys
+    import sysconfig
+
+    version = f"{sys.version_info[0]}{sys.version_info[1]}"
+    if sys.implementation.name == "cpython":
+        interpreter = f"cp{version}"
+        abi = interpreter
+        if sysconfig.get_config_var("Py_GIL_DISABLED"):
+            abi += "t"
+    elif sys.implementation.name == "pypy":
+        interpreter = f"pp{version}"
+        # For example, `pypy310-pp73-x86_64-linux-gnu` -> `pypy310_pp73`
+        soabi = sysconfig.get_config_var("SOABI") or ""
+        abi = "_".join(soabi.split("-")[:2]) or "none"
+    else:
+        interpreter = f"py{version}"
+        abi = "none"
+    platform = sysconfig.get_platform().replace("-", "_").replace(".", "_")
+    return f"{interpreter}-{abi}-{platform}"
+
+
+def run_build_hook(
+    build_hook: "dict[str, Any]",
+    output_directory: str,
+    config_settings: "Mapping[Any, Any] | None",
+) -> None:
+    """Import the build hook function from the project root and call it."""
+    import importlib
+    import os
+    import sys
+
+    sys.path.insert(0, os.getcwd())
+    try:
+        function = importlib.import_module(build_hook["module"])
+    finally:
+        sys.path.pop(0)
+    for attribute in build_hook["function"].split("."):
+        function = getattr(function, attribute)
+    function(output_directory, dict(config_settings or {}))
+
+
+def build_hook_env(build_hook_dir: "str | None" = None) -> "dict[str, str]":
+    """The environment variables passing the build hook results to uv."""
+    env = {"UV_INTERNAL__BUILD_HOOK_WHEEL_TAG": wheel_tag()}
+    if build_hook_dir is not None:
+        env["UV_INTERNAL__BUILD_HOOK_DIR"] = build_hook_dir
+    return env
+
+
 def build_sdist(
     sdist_directory: str, config_settings: "Mapping[Any, Any] | None" = None
 ) -> str:
@@ -86,7 +160,15 @@ def build_wheel(
     args = ["build-wheel", wheel_directory]
     if metadata_directory:
         args.extend([metadata_directory])
-    return call(args, config_settings)
+    build_hook = get_build_hook()
+    if build_hook is None:
+        return call(args, config_settings)
+
+    import tempfile
+
+    with tempfile.TemporaryDirectory() as build_hook_dir:
+        run_build_hook(build_hook, build_hook_dir, config_settings)
+        return call(args, env=build_hook_env(build_hook_dir))
 
 
 def get_requires_for_build_sdist(
@@ -101,8 +183,11 @@ def get_requires_for_build_wheel(
     config_settings: "Mapping[Any, Any] | None" = None,
 ) -> "Sequence[str]":
     """PEP 517 hook `get_requires_for_build_wheel`."""
-    warn_config_settings(config_settings)
-    return []
+    build_hook = get_build_hook()
+    if build_hook is None:
+        warn_config_settings(config_settings)
+        return []
+    return build_hook["requires"]
 
 
 def prepare_metadata_for_build_wheel(
@@ -110,7 +195,8 @@ def prepare_metadata_for_build_wheel(
 ) -> str:
     """PEP 517 hook `prepare_metadata_for_build_wheel`."""
     args = ["prepare-metadata-for-build-wheel", metadata_directory]
-    return call(args, config_settings)
+    # The wheel tag is only read if the project has a build hook, so we can skip the lookup.
+    return call(args, config_settings, env=build_hook_env())
 
 
 def build_editable(
@@ -122,15 +208,23 @@ def build_editable(
     args = ["build-editable", wheel_directory]
     if metadata_directory:
         args.extend([metadata_directory])
-    return call(args, config_settings)
+    build_hook = get_build_hook()
+    if build_hook is None:
+        return call(args, config_settings)
+    # Build the artifacts in place, next to the modules the editable install points to.
+    run_build_hook(build_hook, build_hook["module-root"], config_settings)
+    return call(args, env=build_hook_env())
 
 
 def get_requires_for_build_editable(
     config_settings: "Mapping[Any, Any] | None" = None,
 ) -> "Sequence[str]":
     """PEP 660 hook `get_requires_for_build_editable`."""
-    warn_config_settings(config_settings)
-    return []
+    build_hook = get_build_hook()
+    if build_hook is None:
+        warn_config_settings(config_settings)
+        return []
+    return build_hook["requires"]
 
 
 def prepare_metadata_for_build_editable(
@@ -138,4 +232,5 @@ def prepare_metadata_for_build_editable(
 ) -> str:
     """PEP 660 hook `prepare_metadata_for_build_editable`."""
     args = ["prepare-metadata-for-build-editable", metadata_directory]
-    return call(args, config_settings)
+    # The wheel tag is only read if the project has a build hook, so we can skip the lookup.
+    return call(args, config_settings, env=build_hook_env())
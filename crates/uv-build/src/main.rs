@@ -126,6 +126,17 @@ fn main() -> Result<()> {
             // Tell the build frontend about the name of the artifact we built
             writeln!(&mut std::io::stdout(), "{filename}").context("stdout is closed")?;
         }
+        "build-hook" => {
+            let build_hook =
+                uv_build_backend::build_hook(&env::current_dir()?, uv_version::version())?;
+            // Tell the Python shim about the build hook, or `null` if there is none
+            writeln!(
+                &mut std::io::stdout(),
+                "{}",
+                serde_json::to_string(&build_hook)?
+            )
+            .context("stdout is closed")?;
+        }
         "--help" => {
             // This works both as redirect to use the proper uv package and as smoke test.
             writeln!(
@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{Cache, RemoteCache};
 use clap::{Parser, ValueHint};
 use tracing::{debug, warn};
 
@@ -29,6 +29,21 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR, value_hint = ValueHint::DirPath)]
     pub cache_dir: Option<PathBuf>,
+
+    /// A shared cache for built wheels, as a path to a directory or an `http://` or `https://`
+    /// URL.
+    ///
+    /// Wheels built from source distributions are uploaded to the remote cache, and fetched from
+    /// it on a local cache miss, allowing other machines to skip the build.
+    // Hidden while in preview.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_REMOTE_CACHE,
+        value_hint = ValueHint::Url,
+        hide = true
+    )]
+    pub remote_cache: Option<RemoteCache>,
 }
 
 impl Cache {
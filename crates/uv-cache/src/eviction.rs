@@ -0,0 +1,533 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use crate::removal::{Removal, rm_rf};
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket};
+
+/// A policy for evicting the least-recently-used entries from the cache.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EvictionPolicy {
+    /// Evict entries that have not been used within the given duration.
+    pub max_age: Option<CacheAge>,
+    /// Evict the least-recently-used entries until the cache is no larger than the given size.
+    pub max_size: Option<CacheSize>,
+}
+
+impl EvictionPolicy {
+    /// Returns `true` if the policy does not evict any entries.
+    pub fn is_empty(&self) -> bool {
+        self.max_age.is_none() && self.max_size.is_none()
+    }
+}
+
+/// A maximum age for cache entries, parsed from a duration like `30d`, `2 weeks`, or `P30D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheAge(Duration);
+
+impl CacheAge {
+    /// Return the age as a [`Duration`].
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for CacheAge {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let span = input
+            .parse::<jiff::Span>()
+            .map_err(|err| format!("`{input}` could not be parsed as a duration: {err}"))?;
+        // Durations are used relative to the current time, so days are treated as 24 hours.
+        // Calendar units (months and years) have no fixed length, and are rejected.
+        let duration = span
+            .to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+            .map_err(|_| {
+                format!(
+                    "`{input}` could not be converted to a fixed duration (use weeks, days, or smaller units)"
+                )
+            })?;
+        let duration = Duration::try_from(duration)
+            .map_err(|_| format!("`{input}` must be a positive duration"))?;
+        Ok(Self(duration))
+    }
+}
+
+/// A maximum size for the cache, parsed from a byte count like `20GiB`, `500MB`, or `1024`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheSize(u64);
+
+impl CacheSize {
+    /// Return the size in bytes.
+    pub fn as_bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for CacheSize {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| format!("`{input}` could not be parsed as a size (e.g., `20GiB`)"))?;
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1000,
+            "mb" => 1000_u64.pow(2),
+            "gb" => 1000_u64.pow(3),
+            "tb" => 1000_u64.pow(4),
+            "k" | "kib" => 1024,
+            "m" | "mib" => 1024_u64.pow(2),
+            "g" | "gib" => 1024_u64.pow(3),
+            "t" | "tib" => 1024_u64.pow(4),
+            _ => {
+                return Err(format!(
+                    "`{input}` has an unknown unit `{unit}` (expected one of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)"
+                ));
+            }
+        };
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::cast_sign_loss
+        )]
+        let bytes = (number * multiplier as f64) as u64;
+        Ok(Self(bytes))
+    }
+}
+
+impl Display for CacheSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+/// An independently removable entry in the cache, such as the wheels for a package from a given
+/// index.
+#[derive(Debug)]
+struct Candidate {
+    path: PathBuf,
+    /// The most recent time at which the entry, or any archive it references, was accessed or
+    /// modified.
+    last_used: SystemTime,
+    /// The size of the entry, excluding any referenced archives.
+    size: u64,
+    /// The archives referenced by the entry.
+    archives: Vec<PathBuf>,
+}
+
+/// The usage of an entry in the archive bucket.
+#[derive(Debug)]
+struct ArchiveUsage {
+    last_used: SystemTime,
+    size: u64,
+    /// The number of candidates that reference the archive.
+    references: usize,
+}
+
+impl Cache {
+    /// Evict the least-recently-used entries from the cache, according to the given policy.
+    ///
+    /// Entries are evicted at the granularity of a package within a given index (or a single URL,
+    /// path, or Git revision) across the wheel, source distribution, and index buckets, along with
+    /// cached flat index responses and interpreter metadata. An entry's last use is the most recent
+    /// access or modification time of any of its files, including any unzipped archives it
+    /// references. Archives are removed once no remaining entry references them.
+    ///
+    /// The caller is expected to hold an exclusive lock on the cache.
+    pub fn evict(&self, policy: &EvictionPolicy) -> Result<Removal, io::Error> {
+        self.evict_at(policy, SystemTime::now())
+    }
+
+    fn evict_at(&self, policy: &EvictionPolicy, now: SystemTime) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if policy.is_empty() || !self.root.exists() {
+            return Ok(summary);
+        }
+
+        let (mut candidates, mut archives) = self.eviction_candidates()?;
+
+        // Compute each candidate's last use, accounting for the archives it references.
+        for candidate in &mut candidates {
+            for archive in &candidate.archives {
+                if let Some(usage) = archives.get(archive) {
+                    candidate.last_used = candidate.last_used.max(usage.last_used);
+                }
+            }
+        }
+        candidates.sort_by_key(|candidate| candidate.last_used);
+
+        // Determine the size of the evictable entries, along with the archives they reference.
+        // Other buckets (e.g., environments and build artifacts) are never evicted, and so don't
+        // count towards the maximum size.
+        let mut remaining = candidates
+            .iter()
+            .map(|candidate| candidate.size)
+            .chain(archives.values().map(|usage| usage.size))
+            .sum::<u64>();
+
+        let cutoff = policy
+            .max_age
+            .and_then(|max_age| now.checked_sub(max_age.as_duration()));
+
+        let mut evicted = Vec::new();
+        for candidate in candidates {
+            let expired = cutoff.is_some_and(|cutoff| candidate.last_used < cutoff);
+            let oversized = policy
+                .max_size
+                .is_some_and(|max_size| remaining > max_size.as_bytes());
+            if !expired && !oversized {
+                // Candidates are sorted by last use, so all remaining candidates are retained.
+                break;
+            }
+
+            remaining = remaining.saturating_sub(candidate.size);
+            for archive in &candidate.archives {
+                if let Some(usage) = archives.get_mut(archive) {
+                    usage.references = usage.references.saturating_sub(1);
+                    if usage.references == 0 {
+                        remaining = remaining.saturating_sub(usage.size);
+                    }
+                }
+            }
+            evicted.push(candidate);
+        }
+
+        for candidate in &evicted {
+            debug!("Evicting cache entry: {}", candidate.path.display());
+            summary += rm_rf(&candidate.path)?;
+        }
+
+        // Only remove targets in the archive bucket. Cache entries may contain unexpected links
+        // to paths outside the cache.
+        let archive_root = fs_err::canonicalize(&self.root)?.join(CacheBucket::Archive.to_str());
+        for (archive, usage) in archives {
+            if usage.references == 0 && archive.starts_with(&archive_root) {
+                debug!("Removing unreferenced cache archive: {}", archive.display());
+                summary += rm_rf(archive)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Collect the entries in the cache that can be evicted, along with the archives that they
+    /// reference.
+    fn eviction_candidates(
+        &self,
+    ) -> Result<(Vec<Candidate>, FxHashMap<PathBuf, ArchiveUsage>), io::Error> {
+        // The depth (relative to the root for each kind) at which entries are independent.
+        let kinds = [
+            (WheelCacheKind::Pypi, 1),
+            (WheelCacheKind::Index, 2),
+            (WheelCacheKind::Url, 1),
+            (WheelCacheKind::Path, 1),
+            (WheelCacheKind::Editable, 1),
+            (WheelCacheKind::Git, 2),
+        ];
+
+        let mut roots = Vec::new();
+        for bucket in [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+        ] {
+            for (kind, depth) in kinds {
+                roots.push((self.bucket(bucket).join(kind.to_str()), depth));
+            }
+        }
+
+        let mut entries = Vec::new();
+        for (root, depth) in roots {
+            collect_entries(&root, depth, &mut entries)?;
+        }
+
+        // Flat index responses and interpreter metadata are stored as independent files.
+        for bucket in [CacheBucket::FlatIndex, CacheBucket::Interpreter] {
+            let root = self.bucket(bucket);
+            if !root.is_dir() {
+                continue;
+            }
+            for entry in walkdir::WalkDir::new(root) {
+                let entry = entry?;
+                if entry.file_type().is_file() && !is_marker(entry.path()) {
+                    entries.push(entry.into_path());
+                }
+            }
+        }
+
+        let mut candidates = Vec::with_capacity(entries.len());
+        let mut archives = FxHashMap::<PathBuf, ArchiveUsage>::default();
+        for path in entries {
+            let (last_used, size, links) = self.usage(&path)?;
+
+            let mut referenced = Vec::new();
+            for target in links {
+                if let Some(usage) = archives.get_mut(&target) {
+                    usage.references += 1;
+                } else {
+                    let (last_used, size) = directory_usage(&target)?;
+                    archives.insert(
+                        target.clone(),
+                        ArchiveUsage {
+                            last_used,
+                            size,
+                            references: 1,
+                        },
+                    );
+                }
+                referenced.push(target);
+            }
+
+            candidates.push(Candidate {
+                path,
+                last_used,
+                size,
+                archives: referenced,
+            });
+        }
+
+        Ok((candidates, archives))
+    }
+
+    /// Return the last use and size of a cache entry, along with the archives it links to.
+    ///
+    /// Like [`directory_usage`], directory timestamps are ignored, as are those of symlinks, which
+    /// reflect when the link was created rather than when the archive was used.
+    pub(crate) fn usage(&self, path: &Path) -> Result<(SystemTime, u64, Vec<PathBuf>), io::Error> {
+        let mut last_used = SystemTime::UNIX_EPOCH;
+        let mut size = 0;
+        let mut links = Vec::new();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if !entry.file_type().is_symlink() {
+                last_used = last_used.max(last_use(&metadata));
+            }
+            size += metadata.len();
+
+            // On Unix, archive references use symlinks. On Windows, archive references are files
+            // containing structured data.
+            let is_link = if cfg!(unix) {
+                entry.file_type().is_symlink()
+            } else {
+                entry.file_type().is_file()
+                    && !entry.path().extension().is_some_and(|ext| {
+                        ext.eq_ignore_ascii_case("whl")
+                            || ext.eq_ignore_ascii_case("http")
+                            || ext.eq_ignore_ascii_case("rev")
+                            || ext.eq_ignore_ascii_case("msgpack")
+                            || ext.eq_ignore_ascii_case("rkyv")
+                    })
+            };
+            if is_link && !is_marker(entry.path()) {
+                if let Ok(target) = self.resolve_link(entry.path()) {
+                    links.push(target);
+                }
+            }
+        }
+        Ok((last_used, size, links))
+    }
+}
+
+/// Collect the paths at exactly the given depth below `root`.
+fn collect_entries(root: &Path, depth: usize, entries: &mut Vec<PathBuf>) -> io::Result<()> {
+    if !root.is_dir() {
+        return Ok(());
+    }
+    for entry in walkdir::WalkDir::new(root)
+        .min_depth(depth)
+        .max_depth(depth)
+    {
+        let entry = entry?;
+        if !is_marker(entry.path()) {
+            entries.push(entry.into_path());
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the path is a lock or ignore file, which is never evicted on its own.
+pub(crate) fn is_marker(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == ".lock" || name == ".gitignore")
+        || path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("lock"))
+}
+
+/// Return the most recent access or modification time in the given metadata.
+fn last_use(metadata: &std::fs::Metadata) -> SystemTime {
+    let accessed = metadata.accessed().unwrap_or(SystemTime::UNIX_EPOCH);
+    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    accessed.max(modified)
+}
+
+/// Return the most recent use and total size of the files in a directory.
+///
+/// Directory timestamps are ignored, as they change whenever entries are added or listed.
+pub(crate) fn directory_usage(path: &Path) -> io::Result<(SystemTime, u64)> {
+    let mut last_used = SystemTime::UNIX_EPOCH;
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        last_used = last_used.max(last_use(&metadata));
+        size += metadata.len();
+    }
+    Ok((last_used, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::FileTimes;
+    use std::path::Path;
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Cache, CacheBucket};
+
+    use super::{CacheAge, CacheSize, EvictionPolicy};
+
+    /// Write a file with the given size, last used at the given number of days before `now`.
+    fn write(path: &Path, size: usize, now: SystemTime, days: u64) {
+        fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+        fs_err::write(path, vec![0; size]).unwrap();
+        let time = now - Duration::from_hours(days * 24);
+        fs_err::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_times(FileTimes::new().set_accessed(time).set_modified(time))
+            .unwrap();
+    }
+
+    #[test]
+    fn parse_age() {
+        assert_eq!(
+            "30d".parse::<CacheAge>().unwrap().as_duration(),
+            Duration::from_hours(30 * 24)
+        );
+        assert_eq!(
+            "2 weeks".parse::<CacheAge>().unwrap().as_duration(),
+            Duration::from_hours(14 * 24)
+        );
+        assert_eq!(
+            "PT12H".parse::<CacheAge>().unwrap().as_duration(),
+            Duration::from_hours(12)
+        );
+        assert!("1 month".parse::<CacheAge>().is_err());
+        assert!("soon".parse::<CacheAge>().is_err());
+    }
+
+    #[test]
+    fn parse_size() {
+        assert_eq!("1024".parse::<CacheSize>().unwrap().as_bytes(), 1024);
+        assert_eq!("20GiB".parse::<CacheSize>().unwrap().as_bytes(), 20 << 30);
+        assert_eq!(
+            "500MB".parse::<CacheSize>().unwrap().as_bytes(),
+            500_000_000
+        );
+        assert_eq!("1.5k".parse::<CacheSize>().unwrap().as_bytes(), 1536);
+        assert!("20 parsecs".parse::<CacheSize>().is_err());
+        assert!("GiB".parse::<CacheSize>().is_err());
+    }
+
+    #[test]
+    fn evict_max_age() {
+        let now = SystemTime::now();
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+        let simple = cache.bucket(CacheBucket::Simple).join("pypi");
+        write(&simple.join("old.rkyv"), 10, now, 40);
+        write(&simple.join("new.rkyv"), 10, now, 1);
+
+        let policy = EvictionPolicy {
+            max_age: Some("30d".parse().unwrap()),
+            max_size: None,
+        };
+        let summary = cache.evict_at(&policy, now).unwrap();
+
+        assert_eq!(summary.num_files, 1);
+        assert!(!simple.join("old.rkyv").exists());
+        assert!(simple.join("new.rkyv").exists());
+    }
+
+    #[test]
+    fn evict_max_size() {
+        let now = SystemTime::now();
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+        let wheels = cache.bucket(CacheBucket::Wheels).join("pypi");
+        write(&wheels.join("a").join("a.http"), 100, now, 3);
+        write(&wheels.join("b").join("b.http"), 100, now, 2);
+        write(&wheels.join("c").join("c.http"), 100, now, 1);
+
+        let policy = EvictionPolicy {
+            max_age: None,
+            max_size: Some("150".parse().unwrap()),
+        };
+        cache.evict_at(&policy, now).unwrap();
+
+        assert!(!wheels.join("a").exists());
+        assert!(!wheels.join("b").exists());
+        assert!(wheels.join("c").exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn evict_unreferenced_archives() {
+        use crate::ArchiveId;
+
+        let now = SystemTime::now();
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+
+        // An archive referenced by an old entry, and an archive shared with a recent entry.
+        let unique = ArchiveId::new();
+        let shared = ArchiveId::new();
+        write(&cache.archive(&unique).join("RECORD"), 10, now, 40);
+        write(&cache.archive(&shared).join("RECORD"), 10, now, 40);
+
+        let wheels = cache.bucket(CacheBucket::Wheels);
+        for (entry, days, ids) in [
+            ("old", 40, vec![&unique, &shared]),
+            ("new", 1, vec![&shared]),
+        ] {
+            let dir = wheels.join("index").join(entry).join("pkg");
+            write(&dir.join("pkg.http"), 10, now, days);
+            for id in ids {
+                cache.create_link(id, dir.join(id.to_string())).unwrap();
+            }
+        }
+
+        let policy = EvictionPolicy {
+            max_age: Some("30d".parse().unwrap()),
+            max_size: None,
+        };
+        cache.evict_at(&policy, now).unwrap();
+
+        assert!(!wheels.join("index").join("old").join("pkg").exists());
+        assert!(wheels.join("index").join("new").join("pkg").exists());
+        assert!(!cache.archive(&unique).exists());
+        assert!(cache.archive(&shared).exists());
+    }
+}
@@ -17,8 +17,11 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::eviction::{CacheAge, CacheSize, EvictionPolicy};
+pub use crate::remote::{RemoteCache, RemoteCacheEntry};
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::stats::{BucketStats, CacheSource, CacheStats, CachedVersion};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -27,7 +30,10 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod eviction;
+mod remote;
 mod removal;
+mod stats;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -174,6 +180,8 @@ pub struct Cache {
     /// Ensure that `uv cache` operations don't remove items from the cache that are used by another
     /// uv process.
     lock_file: Option<Arc<LockedFile>>,
+    /// A shared, secondary cache tier for built wheels.
+    remote: Option<Arc<RemoteCache>>,
 }
 
 impl Cache {
@@ -184,6 +192,7 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
             lock_file: None,
+            remote: None,
         }
     }
 
@@ -195,6 +204,7 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
             lock_file: None,
+            remote: None,
         })
     }
 
@@ -204,6 +214,20 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`RemoteCache`] to use as a secondary tier for built wheels.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<RemoteCache>) -> Self {
+        Self {
+            remote: remote.map(Arc::new),
+            ..self
+        }
+    }
+
+    /// Return the [`RemoteCache`], if configured.
+    pub fn remote(&self) -> Option<&RemoteCache> {
+        self.remote.as_deref()
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub async fn with_exclusive_lock(self) -> Result<Self, LockedFileError> {
         let Self {
@@ -211,6 +235,7 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
         } = self;
 
         // Release the existing lock, avoid deadlocks from a cloned cache.
@@ -233,6 +258,7 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file: Some(Arc::new(lock_file)),
+            remote,
         })
     }
 
@@ -245,6 +271,7 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
         } = self;
 
         match LockedFile::acquire_no_wait(
@@ -257,12 +284,14 @@ impl Cache {
                 refresh,
                 temp_dir,
                 lock_file: Some(Arc::new(lock_file)),
+                remote,
             }),
             None => Err(Self {
                 root,
                 refresh,
                 temp_dir,
                 lock_file,
+                remote,
             }),
         }
     }
@@ -0,0 +1,174 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::CacheBucket;
+
+/// A shared, secondary cache tier, backed by a directory (e.g., on a network file system) or an
+/// HTTP server that supports `GET` and `PUT` requests.
+///
+/// Entries are stored at `{bucket}/{key}/{file}`, relative to the root of the remote cache, where
+/// the key is a digest of the entry's source and build settings. Both backends share the same
+/// layout, so a directory populated by one machine can be served over HTTP to others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteCache {
+    /// A directory on the local (or a network) file system.
+    Directory(PathBuf),
+    /// An HTTP server.
+    Url(DisplaySafeUrl),
+}
+
+impl RemoteCache {
+    /// Return the location of a file within an entry in the remote cache.
+    pub fn entry(&self, bucket: CacheBucket, key: &str, file: &str) -> RemoteCacheEntry {
+        match self {
+            Self::Directory(root) => {
+                RemoteCacheEntry::Path(root.join(bucket.to_str()).join(key).join(file))
+            }
+            Self::Url(root) => {
+                let mut url = root.clone();
+                url.path_segments_mut()
+                    .expect("HTTP URLs can be a base")
+                    .pop_if_empty()
+                    .extend([bucket.to_str(), key, file]);
+                RemoteCacheEntry::Url(url)
+            }
+        }
+    }
+}
+
+impl FromStr for RemoteCache {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.starts_with("http://") || input.starts_with("https://") {
+            let url = DisplaySafeUrl::parse(input)
+                .map_err(|err| format!("`{input}` is not a valid URL: {err}"))?;
+            Ok(Self::Url(url))
+        } else if input.starts_with("file://") {
+            let url = DisplaySafeUrl::parse(input)
+                .map_err(|err| format!("`{input}` is not a valid URL: {err}"))?;
+            let path = url
+                .to_file_path()
+                .map_err(|()| format!("`{input}` is not a valid file URL"))?;
+            Ok(Self::Directory(path))
+        } else if input.contains("://") {
+            Err(format!(
+                "`{input}` uses an unsupported scheme (expected a path, or an `http://`, `https://`, or `file://` URL)"
+            ))
+        } else {
+            Ok(Self::Directory(PathBuf::from(input)))
+        }
+    }
+}
+
+impl Display for RemoteCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RemoteCache {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RemoteCache {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("RemoteCache")
+    }
+
+    fn json_schema(_gen: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A path to a directory, or an `http://` or `https://` URL."
+        })
+    }
+}
+
+/// The location of a file in the [`RemoteCache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteCacheEntry {
+    Path(PathBuf),
+    Url(DisplaySafeUrl),
+}
+
+impl Display for RemoteCacheEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::CacheBucket;
+
+    use super::{RemoteCache, RemoteCacheEntry};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "/mnt/uv-cache".parse::<RemoteCache>().unwrap(),
+            RemoteCache::Directory(PathBuf::from("/mnt/uv-cache"))
+        );
+        assert!(matches!(
+            "https://cache.example.com/uv"
+                .parse::<RemoteCache>()
+                .unwrap(),
+            RemoteCache::Url(_)
+        ));
+        assert!("s3://bucket".parse::<RemoteCache>().is_err());
+    }
+
+    #[test]
+    fn entry() {
+        let remote = "https://cache.example.com/uv/"
+            .parse::<RemoteCache>()
+            .unwrap();
+        let RemoteCacheEntry::Url(url) = remote.entry(
+            CacheBucket::SourceDistributions,
+            "0123",
+            "py3-none-any.json",
+        ) else {
+            panic!("expected a URL");
+        };
+        assert_eq!(
+            url.as_str(),
+            format!(
+                "https://cache.example.com/uv/{}/0123/py3-none-any.json",
+                CacheBucket::SourceDistributions
+            )
+        );
+
+        let remote = "/mnt/uv-cache".parse::<RemoteCache>().unwrap();
+        assert_eq!(
+            remote.entry(
+                CacheBucket::SourceDistributions,
+                "0123",
+                "py3-none-any.json"
+            ),
+            RemoteCacheEntry::Path(
+                PathBuf::from("/mnt/uv-cache")
+                    .join(CacheBucket::SourceDistributions.to_string())
+                    .join("0123")
+                    .join("py3-none-any.json")
+            )
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
+
+use uv_fs::{directories, entries};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::eviction::{directory_usage, is_marker};
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket};
+
+/// The disk usage of the cache, broken down by bucket.
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    /// The usage of each cache bucket.
+    pub buckets: Vec<BucketStats>,
+    /// The size of any entries outside the current cache buckets (e.g., buckets from older
+    /// versions of uv).
+    pub other: u64,
+    /// The number of unzipped archives that are no longer referenced by any cache entry.
+    pub unreferenced_archives: usize,
+    /// The total size of the unreferenced archives.
+    pub unreferenced_archives_size: u64,
+}
+
+impl CacheStats {
+    /// Return the total size of the cache.
+    pub fn total(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.size).sum::<u64>() + self.other
+    }
+}
+
+/// The disk usage of a single cache bucket.
+#[derive(Debug, Clone)]
+pub struct BucketStats {
+    pub bucket: CacheBucket,
+    /// The total size of the bucket.
+    pub size: u64,
+    /// The size of each package in the bucket, from largest to smallest.
+    ///
+    /// Only buckets that store data per package (wheels, source distributions, index responses,
+    /// and unzipped archives) are broken down by package.
+    pub packages: Vec<(PackageName, u64)>,
+}
+
+/// The source from which a cache entry was populated.
+///
+/// Sources other than PyPI are identified by the digest of their URL, as used in the cache
+/// structure.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CacheSource {
+    /// The Python Package Index.
+    Pypi,
+    /// An alternative index.
+    Index(String),
+    /// A direct URL.
+    Url(String),
+    /// A local path.
+    Path(String),
+    /// An editable path.
+    Editable(String),
+    /// A Git repository.
+    Git(String),
+}
+
+/// A version of a package in the cache, along with the buckets that hold it.
+#[derive(Debug, Clone)]
+pub struct CachedVersion {
+    /// The version of the package, or `None` for index responses, which cover all versions.
+    pub version: Option<Version>,
+    pub source: CacheSource,
+    pub buckets: Vec<CacheBucket>,
+    /// The total size of the version across all buckets.
+    pub size: u64,
+}
+
+/// A file or directory in a cache bucket that holds the data for a single package from a single
+/// source.
+#[derive(Debug)]
+struct PackageEntry {
+    name: PackageName,
+    source: CacheSource,
+    path: PathBuf,
+}
+
+impl Cache {
+    /// Compute the disk usage of each cache bucket, along with the largest packages in each
+    /// bucket and any archives that are no longer referenced.
+    ///
+    /// Sizes are computed from the length of each file, and so may differ from the space used on
+    /// disk.
+    pub fn stats(&self) -> Result<CacheStats, io::Error> {
+        let mut stats = CacheStats::default();
+
+        for bucket in CacheBucket::iter() {
+            let root = self.bucket(bucket);
+            let size = if root.exists() {
+                directory_usage(&root)?.1
+            } else {
+                0
+            };
+
+            let mut packages = FxHashMap::<PackageName, u64>::default();
+            match bucket {
+                CacheBucket::Wheels | CacheBucket::SourceDistributions | CacheBucket::Simple => {
+                    for entry in self.package_entries(bucket)? {
+                        *packages.entry(entry.name).or_default() += directory_usage(&entry.path)?.1;
+                    }
+                }
+                CacheBucket::Archive => {
+                    for archive in directories(&root)? {
+                        if let Some((name, _)) = archive_distribution(&archive) {
+                            *packages.entry(name).or_default() += directory_usage(&archive)?.1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+            let mut packages = packages.into_iter().collect::<Vec<_>>();
+            packages.sort_by(|(a_name, a_size), (b_name, b_size)| {
+                b_size.cmp(a_size).then_with(|| a_name.cmp(b_name))
+            });
+
+            stats.buckets.push(BucketStats {
+                bucket,
+                size,
+                packages,
+            });
+        }
+
+        if !self.root.exists() {
+            return Ok(stats);
+        }
+
+        // Account for anything outside the current buckets, like outdated bucket versions.
+        for entry in fs_err::read_dir(&self.root)? {
+            let entry = entry?;
+            if CacheBucket::iter().any(|bucket| entry.file_name() == bucket.to_str()) {
+                continue;
+            }
+            stats.other += directory_usage(&entry.path())?.1;
+        }
+
+        // Count the archives that aren't referenced by any other bucket.
+        let references = self.find_archive_references()?;
+        for archive in directories(self.bucket(CacheBucket::Archive))? {
+            let target = fs_err::canonicalize(&archive)?;
+            if !references.contains_key(&target) {
+                stats.unreferenced_archives += 1;
+                stats.unreferenced_archives_size += directory_usage(&archive)?.1;
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// List the cached versions of a package, along with their sources and the buckets that hold
+    /// them.
+    pub fn inspect(&self, name: &PackageName) -> Result<Vec<CachedVersion>, io::Error> {
+        let mut versions =
+            BTreeMap::<(Option<Version>, CacheSource), (Vec<CacheBucket>, u64)>::new();
+        let mut record =
+            |version: Option<Version>, source: &CacheSource, bucket: CacheBucket, size: u64| {
+                let (buckets, total) = versions.entry((version, source.clone())).or_default();
+                if !buckets.contains(&bucket) {
+                    buckets.push(bucket);
+                }
+                *total += size;
+            };
+
+        for bucket in [
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Simple,
+        ] {
+            for entry in self.package_entries(bucket)? {
+                if entry.name != *name {
+                    continue;
+                }
+                match bucket {
+                    CacheBucket::Wheels => {
+                        // Each wheel is stored as a set of files that share a `{version}-{tags}`
+                        // stem, alongside a link to the unzipped archive.
+                        let mut wheels = BTreeMap::<String, Vec<PathBuf>>::new();
+                        for path in entries(&entry.path)? {
+                            if is_marker(&path) {
+                                continue;
+                            }
+                            let Some(stem) = wheel_stem(&path) else {
+                                continue;
+                            };
+                            wheels.entry(stem).or_default().push(path);
+                        }
+                        for (stem, paths) in wheels {
+                            let mut size = 0;
+                            let mut links = Vec::new();
+                            for path in paths {
+                                let (_, entry_size, entry_links) = self.usage(&path)?;
+                                size += entry_size;
+                                links.extend(entry_links);
+                            }
+                            let version = links
+                                .iter()
+                                .find_map(|link| archive_distribution(link))
+                                .map(|(_, version)| version)
+                                .or_else(|| {
+                                    let (version, _) = stem.split_once('-')?;
+                                    Version::from_str(version).ok()
+                                });
+                            record(version.clone(), &entry.source, bucket, size);
+                            for link in links {
+                                let size = directory_usage(&link)?.1;
+                                record(version.clone(), &entry.source, CacheBucket::Archive, size);
+                            }
+                        }
+                    }
+                    CacheBucket::SourceDistributions => {
+                        for (version, path) in sdist_versions(&entry)? {
+                            let (_, size, links) = self.usage(&path)?;
+                            record(version.clone(), &entry.source, bucket, size);
+                            for link in links {
+                                let size = directory_usage(&link)?.1;
+                                record(version.clone(), &entry.source, CacheBucket::Archive, size);
+                            }
+                        }
+                    }
+                    _ => {
+                        let size = directory_usage(&entry.path)?.1;
+                        record(None, &entry.source, bucket, size);
+                    }
+                }
+            }
+        }
+
+        Ok(versions
+            .into_iter()
+            .map(|((version, source), (buckets, size))| CachedVersion {
+                version,
+                source,
+                buckets,
+                size,
+            })
+            .collect())
+    }
+
+    /// Collect the per-package entries in the wheel, source distribution, or index bucket.
+    fn package_entries(&self, bucket: CacheBucket) -> Result<Vec<PackageEntry>, io::Error> {
+        let root = self.bucket(bucket);
+        let mut packages = Vec::new();
+
+        // For registries, we expect an entry per package (indexed by name). Index responses are
+        // stored as files; wheels and source distributions as directories.
+        let registry_entry = |path: PathBuf, source: &CacheSource| {
+            let name = if bucket == CacheBucket::Simple {
+                path.file_stem()
+                    .filter(|_| {
+                        path.extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("rkyv"))
+                    })?
+                    .to_str()?
+                    .to_string()
+            } else {
+                if !path.is_dir() {
+                    return None;
+                }
+                path.file_name()?.to_str()?.to_string()
+            };
+            let name = PackageName::from_str(&name).ok()?;
+            Some(PackageEntry {
+                name,
+                source: source.clone(),
+                path,
+            })
+        };
+        for path in entries(root.join(WheelCacheKind::Pypi))? {
+            packages.extend(registry_entry(path, &CacheSource::Pypi));
+        }
+        for index in directories(root.join(WheelCacheKind::Index))? {
+            let source = CacheSource::Index(digest(&index));
+            for path in entries(&index)? {
+                packages.extend(registry_entry(path, &source));
+            }
+        }
+
+        match bucket {
+            CacheBucket::Wheels => {
+                // For direct URLs and paths, we expect a directory for every URL, followed by a
+                // directory per package (indexed by name).
+                for (kind, source) in [
+                    (
+                        WheelCacheKind::Url,
+                        CacheSource::Url as fn(String) -> CacheSource,
+                    ),
+                    (WheelCacheKind::Path, CacheSource::Path),
+                    (WheelCacheKind::Editable, CacheSource::Editable),
+                ] {
+                    for url in directories(root.join(kind))? {
+                        let source = source(digest(&url));
+                        for path in directories(&url)? {
+                            packages.extend(registry_entry(path, &source));
+                        }
+                    }
+                }
+            }
+            CacheBucket::SourceDistributions => {
+                // For direct URLs and paths, we expect a directory for every URL, followed by a
+                // directory per revision. The package name is only available in the metadata.
+                for (kind, source) in [
+                    (
+                        WheelCacheKind::Url,
+                        CacheSource::Url as fn(String) -> CacheSource,
+                    ),
+                    (WheelCacheKind::Path, CacheSource::Path),
+                    (WheelCacheKind::Editable, CacheSource::Editable),
+                ] {
+                    for url in directories(root.join(kind))? {
+                        let Some(metadata) =
+                            directories(&url)?.find_map(|revision| read_metadata(&revision))
+                        else {
+                            continue;
+                        };
+                        packages.push(PackageEntry {
+                            name: metadata.name,
+                            source: source(digest(&url)),
+                            path: url,
+                        });
+                    }
+                }
+
+                // For Git dependencies, we expect a directory for every repository, followed by a
+                // directory for every SHA.
+                for repository in directories(root.join(WheelCacheKind::Git))? {
+                    let source = CacheSource::Git(digest(&repository));
+                    for sha in directories(&repository)? {
+                        let Some(metadata) = read_metadata(&sha) else {
+                            continue;
+                        };
+                        packages.push(PackageEntry {
+                            name: metadata.name,
+                            source: source.clone(),
+                            path: sha,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(packages)
+    }
+}
+
+/// Return the versions held by a source distribution entry, along with their directories.
+fn sdist_versions(entry: &PackageEntry) -> Result<Vec<(Option<Version>, PathBuf)>, io::Error> {
+    match entry.source {
+        // For registries, we expect a directory per version.
+        CacheSource::Pypi | CacheSource::Index(_) => Ok(directories(&entry.path)?
+            .map(|path| {
+                let version = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| Version::from_str(name).ok());
+                (version, path)
+            })
+            .collect()),
+        // For direct URLs and paths, we expect a directory per revision.
+        CacheSource::Url(_) | CacheSource::Path(_) | CacheSource::Editable(_) => {
+            Ok(directories(&entry.path)?
+                .filter_map(|path| {
+                    let metadata = read_metadata(&path)?;
+                    Some((Some(metadata.version), path))
+                })
+                .collect())
+        }
+        // For Git dependencies, the entry is the directory for a single SHA.
+        CacheSource::Git(_) => Ok(read_metadata(&entry.path)
+            .map(|metadata| (Some(metadata.version), entry.path.clone()))
+            .into_iter()
+            .collect()),
+    }
+}
+
+/// Read the resolved metadata for a built source distribution.
+fn read_metadata(path: &Path) -> Option<ResolutionMetadata> {
+    let metadata = fs_err::read(path.join("metadata.msgpack")).ok()?;
+    rmp_serde::from_slice::<ResolutionMetadata>(&metadata).ok()
+}
+
+/// Return the `{version}-{tags}` stem shared by the files for a cached wheel.
+fn wheel_stem(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?;
+    let stem = [".whl", ".rev", ".msgpack", ".http"]
+        .iter()
+        .find_map(|ext| name.strip_suffix(ext))
+        .unwrap_or(name);
+    Some(stem.to_string())
+}
+
+/// Return the name and version of the distribution in an unzipped archive, based on its
+/// `.dist-info` directory.
+fn archive_distribution(archive: &Path) -> Option<(PackageName, Version)> {
+    directories(archive).ok()?.find_map(|path| {
+        let name = path.file_name()?.to_str()?;
+        let (name, version) = name.strip_suffix(".dist-info")?.rsplit_once('-')?;
+        Some((
+            PackageName::from_str(name).ok()?,
+            Version::from_str(version).ok()?,
+        ))
+    })
+}
+
+/// Return the digest that names a cache directory.
+fn digest(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use crate::{Cache, CacheBucket};
+
+    use super::CacheSource;
+
+    #[test]
+    fn stats_and_inspect() {
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+
+        // A wheel from PyPI, along with its unzipped archive.
+        let wheels = cache.bucket(CacheBucket::Wheels).join("pypi").join("foo");
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("1.0-py3-none-any.rev"), vec![0; 10]).unwrap();
+        let archive = cache.bucket(CacheBucket::Archive).join("abc");
+        fs_err::create_dir_all(archive.join("foo-1.0.dist-info")).unwrap();
+        fs_err::write(
+            archive.join("foo-1.0.dist-info").join("METADATA"),
+            vec![0; 100],
+        )
+        .unwrap();
+
+        // An index response from an alternative index.
+        let simple = cache
+            .bucket(CacheBucket::Simple)
+            .join("index")
+            .join("0123456789abcdef");
+        fs_err::create_dir_all(&simple).unwrap();
+        fs_err::write(simple.join("foo.rkyv"), vec![0; 20]).unwrap();
+        fs_err::write(simple.join("bar.rkyv"), vec![0; 30]).unwrap();
+
+        // An archive that nothing references.
+        let dangling = cache.bucket(CacheBucket::Archive).join("def");
+        fs_err::create_dir_all(dangling.join("bar-2.0.dist-info")).unwrap();
+        fs_err::write(
+            dangling.join("bar-2.0.dist-info").join("METADATA"),
+            vec![0; 5],
+        )
+        .unwrap();
+
+        let stats = cache.stats().unwrap();
+        let simple = stats
+            .buckets
+            .iter()
+            .find(|bucket| bucket.bucket == CacheBucket::Simple)
+            .unwrap();
+        assert_eq!(simple.size, 50);
+        assert_eq!(
+            simple.packages,
+            [
+                (PackageName::from_str("bar").unwrap(), 30),
+                (PackageName::from_str("foo").unwrap(), 20)
+            ]
+        );
+        assert_eq!(stats.unreferenced_archives, 2);
+        assert_eq!(stats.unreferenced_archives_size, 105);
+        assert_eq!(stats.total(), 165);
+
+        let versions = cache
+            .inspect(&PackageName::from_str("foo").unwrap())
+            .unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].version, None);
+        assert_eq!(
+            versions[0].source,
+            CacheSource::Index("0123456789abcdef".to_string())
+        );
+        assert_eq!(versions[0].buckets, [CacheBucket::Simple]);
+        assert_eq!(versions[1].version, Some(Version::from_str("1.0").unwrap()));
+        assert_eq!(versions[1].source, CacheSource::Pypi);
+        assert_eq!(versions[1].buckets, [CacheBucket::Wheels]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn inspect_archive() {
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+
+        let wheels = cache.bucket(CacheBucket::Wheels).join("pypi").join("foo");
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("1.0-py3-none-any.rev"), vec![0; 10]).unwrap();
+        let archive = cache.bucket(CacheBucket::Archive).join("abc");
+        fs_err::create_dir_all(archive.join("foo-1.0.dist-info")).unwrap();
+        fs_err::write(
+            archive.join("foo-1.0.dist-info").join("METADATA"),
+            vec![0; 100],
+        )
+        .unwrap();
+        fs_err::os::unix::fs::symlink(&archive, wheels.join("1.0-py3-none-any")).unwrap();
+
+        let stats = cache.stats().unwrap();
+        assert_eq!(stats.unreferenced_archives, 0);
+
+        let versions = cache
+            .inspect(&PackageName::from_str("foo").unwrap())
+            .unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(
+            versions[0].buckets,
+            [CacheBucket::Wheels, CacheBucket::Archive]
+        );
+    }
+}
@@ -69,7 +69,7 @@ pub(crate) enum WheelCacheKind {
 }
 
 impl WheelCacheKind {
-    fn to_str(self) -> &'static str {
+    pub(crate) fn to_str(self) -> &'static str {
         match self {
             Self::Pypi => "pypi",
             Self::Index => "index",
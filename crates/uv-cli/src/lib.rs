@@ -1,5 +1,6 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::net::IpAddr;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -13,7 +14,7 @@ use clap::{ValueEnum, ValueHint};
 
 use uv_audit::VulnerabilityServiceFormat;
 use uv_auth::Service;
-use uv_cache::CacheArgs;
+use uv_cache::{CacheAge, CacheArgs, CacheSize};
 use uv_configuration::{
     ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
     ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
@@ -50,6 +51,15 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum PythonListFormat {
     /// Plain text (for humans).
@@ -68,6 +78,48 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum WhyFormat {
+    /// Display the dependency paths in a human-readable format.
+    #[default]
+    Text,
+    /// Display the dependency paths in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum OutdatedFormat {
+    /// Display the outdated packages in a human-readable table.
+    #[default]
+    Text,
+    /// Display the outdated packages in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the dependency tree in a human-readable format.
+    #[default]
+    Text,
+    /// Display the dependency graph in JSON format.
+    Json,
+    /// Display the dependency graph in the Graphviz DOT format.
+    Dot,
+    /// Display the dependency graph as a Mermaid flowchart.
+    Mermaid,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockDiffFormat {
+    /// Display the lockfile changes in a human-readable format.
+    #[default]
+    Text,
+    /// Display the lockfile changes as a Markdown table, e.g., for a pull request comment.
+    Markdown,
+    /// Display the lockfile changes in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum AuditOutputFormat {
     /// Display the result in a human-readable format.
@@ -77,6 +129,12 @@ pub enum AuditOutputFormat {
     Json,
     /// Display the result in SARIF format.
     Sarif,
+    /// Display the result as a `CycloneDX` v1.5 vulnerability exploitability (VEX) document.
+    #[clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")]
+    CycloneDX1_5,
+    /// Display the result as an `OpenVEX` document.
+    #[clap(name = "openvex")]
+    OpenVex,
 }
 
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
@@ -539,6 +597,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Serve a local package index.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
     /// Inspect uv workspaces.
     #[command(
         after_help = "Use `uv help workspace` for more details.",
@@ -878,6 +942,55 @@ pub struct SelfUpdateArgs {
     pub dry_run: bool,
 }
 
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Serve a directory of distributions, or the wheels in the uv cache, as a package index.
+    ///
+    /// The index implements the Simple Repository API, with both HTML (PEP 503) and JSON (PEP 691)
+    /// responses, and exposes the core metadata of each wheel as a `.metadata` file (PEP 658). It
+    /// can be consumed by uv, pip, or any other client that supports the Simple API, via
+    /// `--index-url http://<host>:<port>/simple/`.
+    ///
+    /// The set of distributions is read once, at startup.
+    Serve(IndexServeArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexServeArgs {
+    /// The directory of wheels and source distributions to serve.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(value_name = "DIRECTORY", value_hint = ValueHint::DirPath, conflicts_with = "from_cache")]
+    pub path: Option<PathBuf>,
+
+    /// Serve the wheels in the uv cache, rather than a directory.
+    ///
+    /// Each unzipped wheel in the cache is repacked into an archive when it's first requested.
+    /// The repacked archives aren't byte-for-byte identical to the original wheels, so the index
+    /// omits their hashes, and installs that verify hashes against the original wheels (e.g., from
+    /// a `uv.lock` or with `--require-hashes`) will fail.
+    #[arg(long)]
+    pub from_cache: bool,
+
+    /// The address on which to listen.
+    ///
+    /// Use `0.0.0.0` to expose the index to other machines on the network.
+    #[arg(long, default_value = "127.0.0.1", value_hint = ValueHint::Hostname)]
+    pub host: IpAddr,
+
+    /// The port on which to listen.
+    ///
+    /// Use `0` to select an available port.
+    #[arg(long, short, default_value_t = 8000)]
+    pub port: u16,
+}
+
 #[derive(Args)]
 pub struct CacheNamespace {
     #[command(subcommand)]
@@ -910,7 +1023,15 @@ pub enum CacheCommand {
     /// Displays the total size of the cache directory. This includes all downloaded and built
     /// wheels, source distributions, and other cached data. By default, outputs the size in raw
     /// bytes; use `--human` for human-readable output.
+    ///
+    /// Use `--buckets` to break down the size by cache bucket, along with the largest packages in
+    /// each bucket.
     Size(SizeArgs),
+    /// Show the cached versions of a package.
+    ///
+    /// Lists every version of the package in the cache, along with the index (or URL, path, or Git
+    /// repository) it was retrieved from and the cache buckets that hold it.
+    Inspect(InspectArgs),
 }
 
 #[derive(Args, Debug)]
@@ -951,6 +1072,26 @@ pub struct PruneArgs {
     /// `--force` is used, `uv cache prune` will proceed without taking a lock.
     #[arg(long)]
     pub force: bool,
+
+    /// Remove cache entries that have not been used within the given duration.
+    ///
+    /// Accepts a duration like `30d`, `2 weeks`, or `P30D`. An entry is considered used when any
+    /// of its files, or of the unzipped wheels it references, was last accessed or modified.
+    ///
+    /// Entries are removed per package and index across the wheel, source distribution, and index
+    /// response buckets.
+    #[arg(long, value_name = "DURATION")]
+    pub max_age: Option<CacheAge>,
+
+    /// Remove the least-recently-used cache entries until they take up no more than the given
+    /// size.
+    ///
+    /// Accepts a size like `20GiB`, `500MB`, or a number of bytes. Only evictable entries (i.e.,
+    /// wheels, source distributions, index responses, and the unzipped wheels they reference) count
+    /// towards the size; other buckets, like cached environments and build artifacts, are excluded,
+    /// so the cache directory as a whole may remain larger than the given size.
+    #[arg(long, value_name = "SIZE")]
+    pub max_size: Option<CacheSize>,
 }
 
 #[derive(Args, Debug)]
@@ -958,6 +1099,35 @@ pub struct SizeArgs {
     /// Display the cache size in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
     #[arg(long = "human", short = 'H', alias = "human-readable")]
     pub human: bool,
+
+    /// Break down the cache size by bucket (e.g., wheels, source distributions, and Git
+    /// repositories).
+    ///
+    /// Includes the largest packages in each bucket and the number of unzipped archives that are no
+    /// longer referenced by any cache entry, which can be removed with `uv cache prune`.
+    #[arg(long)]
+    pub buckets: bool,
+
+    /// Select the output format.
+    ///
+    /// The JSON output always includes the breakdown by bucket.
+    #[arg(long, value_enum, default_value_t = CacheFormat::default())]
+    pub output_format: CacheFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct InspectArgs {
+    /// The package to inspect.
+    #[arg(value_hint = ValueHint::Other)]
+    pub package: PackageName,
+
+    /// Display sizes in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
+    #[arg(long = "human", short = 'H', alias = "human-readable")]
+    pub human: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheFormat::default())]
+    pub output_format: CacheFormat,
 }
 
 #[derive(Args)]
@@ -995,6 +1165,18 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Install(PipInstallArgs),
+    /// Download packages and their dependencies into a directory, without installing them.
+    ///
+    /// The original wheels and source distributions are written to the target directory, which
+    /// can later be used as a `--find-links` location (e.g., on a machine without network access).
+    ///
+    /// Use `--python-version` and `--python-platform` to download packages for an environment
+    /// other than the current one.
+    #[command(
+        after_help = "Use `uv help pip download` for more details.",
+        after_long_help = ""
+    )]
+    Download(PipDownloadArgs),
     /// Uninstall packages from an environment.
     #[command(
         after_help = "Use `uv help pip uninstall` for more details.",
@@ -1032,6 +1214,16 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Audit the packages in an environment or a requirements file.
+    ///
+    /// Packages are audited for known vulnerabilities. By default, the packages installed in the
+    /// current environment are audited. Use `--requirements` to audit the packages pinned in a
+    /// `requirements.txt` or `pylock.toml` file instead.
+    #[command(
+        after_help = "Use `uv help pip audit` for more details.",
+        after_long_help = ""
+    )]
+    Audit(PipAuditArgs),
     /// Display debug information (unsupported)
     #[command(hide = true)]
     Debug(PipDebugArgs),
@@ -1192,6 +1384,43 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Explain why a package is included in the project's lockfile.
+    ///
+    /// Displays every dependency path from the workspace members to the given package, grouped by
+    /// the resolution fork (i.e., the set of environments) under which each path applies.
+    ///
+    /// The project is re-locked before displaying the paths unless the `--locked` or `--frozen`
+    /// flag is provided.
+    ///
+    /// Each step is annotated with the version specifier of the requirement that led to it. The
+    /// lockfile only records the requirements of workspace members and packages from mutable
+    /// sources (e.g., local directories), so the requirements of registry packages are read from
+    /// their metadata. Steps into the dependencies of other packages (e.g., Git dependencies) are
+    /// displayed without a specifier.
+    #[command(
+        after_help = "Use `uv help why` for more details.",
+        after_long_help = ""
+    )]
+    Why(WhyArgs),
+    /// Display the latest versions of the packages in the project's lockfile.
+    ///
+    /// For each locked package, displays the locked version, the latest version that satisfies the
+    /// requirements of the packages that depend on it, and the latest version overall, along with
+    /// the kind of update (i.e., major, minor, patch, or pre-release) required to reach the latest
+    /// version, if the package is outdated.
+    ///
+    /// Only packages from a registry are considered. Packages that are required directly by a
+    /// workspace member are marked as direct; all others are marked as transitive. If a package
+    /// is locked at different versions for different platforms or Python versions, each version is
+    /// displayed separately, constrained only by the requirements that apply to it.
+    ///
+    /// The project is re-locked before checking for updates unless the `--locked` or `--frozen`
+    /// flag is provided.
+    #[command(
+        after_help = "Use `uv help outdated` for more details.",
+        after_long_help = ""
+    )]
+    Outdated(OutdatedArgs),
     /// Format Python code in the project.
     ///
     /// Formats Python code using the Ruff formatter. By default, all Python files in the project
@@ -2471,23 +2700,269 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
-    /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
+    /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
+    ///
+    /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
+    /// and will instead use the defined backend.
+    ///
+    /// For example, when set to `cpu`, uv will use the CPU-only PyTorch index; when set to `cu126`,
+    /// uv will use the PyTorch index for CUDA 12.6.
+    ///
+    /// The `auto` mode will attempt to detect the appropriate PyTorch index based on the currently
+    /// installed CUDA drivers.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
+    pub torch_backend: Option<TorchMode>,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipInstallCompatArgs,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
+    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    pub package: Vec<String>,
+
+    /// Download the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(
+        long,
+        short,
+        alias = "requirement",
+        group = "sources",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's downloaded. However, including a package in a constraints file will
+    /// _not_ trigger the download of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(
+        long,
+        short,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be downloaded, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(
+        long,
+        alias = "override",
+        env = EnvVars::UV_OVERRIDE,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    #[arg(
+        long,
+        short,
+        alias = "build-constraint",
+        env = EnvVars::UV_BUILD_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
+
+    /// The directory into which the packages should be downloaded.
+    ///
+    /// The directory will be created if it doesn't exist. Defaults to the current working
+    /// directory.
+    #[arg(
+        long,
+        short = 'd',
+        alias = "destination-dir",
+        value_hint = ValueHint::DirPath
+    )]
+    pub dest: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed on
+    /// the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
+    ///
+    /// The interpreter is also used to determine the target Python version and platform, unless
+    /// `--python-version` or `--python-platform` are provided.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python to resolve requirements.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--system`
+    /// option instructs uv to avoid using a virtual environment Python and restrict its search to
+    /// the system path.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// When enabled, resolving will not run arbitrary Python code, and only pre-built wheels will
+    /// be selected from package indexes. Source distributions that are requested directly (e.g.,
+    /// by URL or path) are still downloaded, since downloading them doesn't require a build.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't download pre-built wheels.
+    ///
+    /// The source distributions for the given packages will be downloaded instead. The resolver
+    /// will still use pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only download pre-built wheels; don't download source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The minimum Python version for which packages should be downloaded.
+    ///
+    /// For example, `3.8` or `3.8.17`.
+    ///
+    /// Defaults to the version of the Python interpreter used for resolution.
+    ///
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.8` is
+    /// mapped to `3.8.0`.
+    #[arg(long, help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which packages should be downloaded.
     ///
-    /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
-    /// and will instead use the defined backend.
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
     ///
-    /// For example, when set to `cpu`, uv will use the CPU-only PyTorch index; when set to `cu126`,
-    /// uv will use the PyTorch index for CUDA 12.6.
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
     ///
-    /// The `auto` mode will attempt to detect the appropriate PyTorch index based on the currently
-    /// installed CUDA drivers.
+    /// When targeting iOS, the default minimum version is `13.0`. Use
+    /// `IPHONEOS_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
     ///
-    /// This option is in preview and may change in any future release.
-    #[arg(long, value_enum, env = EnvVars::UV_TORCH_BACKEND)]
-    pub torch_backend: Option<TorchMode>,
-
-    #[command(flatten)]
-    pub compat_args: compat::PipInstallCompatArgs,
+    /// When targeting Android, the default minimum Android API level is `24`. Use
+    /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
 }
 
 #[derive(Args)]
@@ -2797,6 +3272,98 @@ pub struct PipCheckArgs {
     pub python_platform: Option<TargetTriple>,
 }
 
+#[derive(Args)]
+pub struct PipAuditArgs {
+    /// Audit the packages pinned in the given files, rather than the packages installed in an
+    /// environment.
+    ///
+    /// The following formats are supported: `requirements.txt` and `pylock.toml`.
+    ///
+    /// Requirements in a `requirements.txt` file must be pinned to an exact version (e.g.,
+    /// `flask==3.0.0`), as in the output of `uv pip compile` or `uv export`; other requirements
+    /// are skipped.
+    #[arg(long, short, alias = "requirement", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    pub requirements: Vec<PathBuf>,
+
+    /// The Python interpreter for which packages should be audited.
+    ///
+    /// By default, uv audits packages in a virtual environment but will audit packages in a system
+    /// Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+        conflicts_with = "requirements",
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Audit packages in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = AuditOutputFormat::default())]
+    pub output_format: AuditOutputFormat,
+
+    /// Ignore a vulnerability by ID.
+    ///
+    /// Vulnerabilities matching any of the provided IDs (including aliases) will be excluded from
+    /// the audit results.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub ignore: Vec<String>,
+
+    /// Ignore a vulnerability by ID, but only while no fix is available.
+    ///
+    /// Vulnerabilities matching any of the provided IDs (including aliases) will be excluded from
+    /// the audit results as long as they have no known fix versions. Once a fix version becomes
+    /// available, the vulnerability will be reported again.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub ignore_until_fixed: Vec<String>,
+
+    /// The service format to use for vulnerability lookups.
+    ///
+    /// Each service format has a default URL, which can be
+    /// changed with `--service-url`. The defaults are:
+    ///
+    /// * OSV: <https://api.osv.dev/>
+    /// * OSV dump: A local copy of the OSV database in the uv cache, see `uv audit --update-db`.
+    ///   Use `--service-url` to provide the path to a directory with an unpacked OSV data dump
+    ///   instead.
+    #[arg(long, value_enum, default_value = "osv")]
+    pub service_format: VulnerabilityServiceFormat,
+
+    /// The URL to vulnerability service API endpoint.
+    ///
+    /// If not provided, the default URL for the selected service will be used.
+    ///
+    /// The service needs to use the OSV protocol, unless a different
+    /// format was requested by `--service-format`.
+    #[arg(long, value_hint = ValueHint::Url)]
+    pub service_url: Option<String>,
+}
+
 #[derive(Args)]
 pub struct PipShowArgs {
     /// The package(s) to display.
@@ -3001,6 +3568,15 @@ pub struct BuildArgs {
     #[arg(long, conflicts_with = "list")]
     pub force_pep517: bool,
 
+    /// Build each distribution twice and check that the builds are identical.
+    ///
+    /// The distributions are built in separate temporary directories with a fixed
+    /// `SOURCE_DATE_EPOCH`, which defaults to 1980-01-01 unless set in the environment. If the
+    /// archives differ, the files with differing contents, timestamps or permissions are reported
+    /// and the build fails.
+    #[arg(long, conflicts_with = "list")]
+    pub verify_reproducible: bool,
+
     /// Clear the output directory before the build, removing stale artifacts.
     #[arg(long)]
     pub clear: bool,
@@ -3770,6 +4346,28 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Run a task defined in the `tool.uv.tasks` table of the project.
+    ///
+    /// Any tasks listed in the task's `depends-on` are run first. Additional arguments are
+    /// appended to the task's command.
+    // Hidden while in preview.
+    #[arg(long, conflicts_with_all = ["script", "module", "gui_script"], hide = true)]
+    pub task: bool,
+
+    /// Restart the command when files in the given path change.
+    ///
+    /// May be provided multiple times. Directories are watched recursively, respecting
+    /// `.gitignore` files.
+    ///
+    /// If the project's `pyproject.toml` or `uv.lock` changes, the environment is synced before
+    /// the command is restarted.
+    ///
+    /// On Unix, the command is restarted by sending `SIGTERM` to its process group, and any
+    /// processes that remain after five seconds are killed.
+    // Hidden while in preview.
+    #[arg(long, value_hint = ValueHint::AnyPath, hide = true)]
+    pub watch: Vec<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3785,9 +4383,20 @@ pub struct RunArgs {
     ///
     /// Any extras or groups specified via `--extra`, `--group`, or related options will be applied
     /// to all workspace members.
+    ///
+    /// In preview, the command is then run in each workspace member, using the member's directory
+    /// as the working directory.
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
 
+    /// When running the command in each workspace member, stop at the first failure.
+    ///
+    /// Members that have not started yet are skipped, and any commands that are still running
+    /// are terminated.
+    // Hidden while in preview.
+    #[arg(long, requires = "all_packages", hide = true)]
+    pub fail_fast: bool,
+
     /// Run the command in a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
@@ -4243,6 +4852,21 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Report the changes to the lockfile relative to a Git revision or another lockfile.
+    ///
+    /// If the value refers to an existing file, it is read as a lockfile; if it refers to a
+    /// directory, the lockfile is read from within that directory. Otherwise, it is treated as a
+    /// Git revision (e.g., `HEAD` or `origin/main`), and the lockfile is read from that revision.
+    ///
+    /// Packages are compared by name, and reported as added, removed, upgraded, downgraded, or as
+    /// having changed sources, hashes, or resolution markers.
+    #[arg(long, value_name = "REVISION|PATH", value_hint = ValueHint::Other)]
+    pub diff: Option<String>,
+
+    /// The format in which to display the lockfile changes requested by `--diff`.
+    #[arg(long, value_enum, default_value_t = LockDiffFormat::default(), requires = "diff")]
+    pub diff_format: LockDiffFormat,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4278,13 +4902,62 @@ pub struct LockArgs {
         value_hint = ValueHint::Other,
     )]
     pub python: Option<Maybe<String>>,
+
+    #[command(subcommand)]
+    pub command: Option<LockCommand>,
+}
+
+#[derive(Subcommand)]
+pub enum LockCommand {
+    /// Merge two versions of the lockfile, for use as a Git merge driver.
+    ///
+    /// Performs a three-way merge of the requirements and package versions recorded in the
+    /// lockfiles, then re-resolves the merged requirements using the merged package versions as
+    /// preferences. The merged lockfile is written to `<CURRENT>`.
+    ///
+    /// The requirements are read from the lockfiles, rather than the `pyproject.toml` files in the
+    /// working tree, which may not be merged yet. If both branches changed the requirement on the
+    /// same package, or the two versions can't be reconciled, uv will exit with a non-zero status,
+    /// and Git will report a merge conflict.
+    ///
+    /// To use as a Git merge driver, add `uv.lock merge=uv` to `.gitattributes`, and configure
+    /// the driver with `git config merge.uv.driver "uv lock merge-driver %O %A %B"`.
+    MergeDriver(LockMergeDriverArgs),
+}
+
+#[derive(Args)]
+pub struct LockMergeDriverArgs {
+    /// The lockfile from the common ancestor (`%O`).
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub base: PathBuf,
+
+    /// The lockfile from the current branch (`%A`).
+    ///
+    /// The merged lockfile is written to this path.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub current: PathBuf,
+
+    /// The lockfile from the other branch (`%B`).
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub other: PathBuf,
 }
 
 #[derive(Args)]
 pub struct UpgradeArgs {
     /// The package to upgrade.
-    #[arg(value_hint = ValueHint::Other)]
-    pub package: PackageName,
+    #[arg(value_hint = ValueHint::Other, required_unless_present = "interactive")]
+    pub package: Option<PackageName>,
+
+    /// Select the dependencies to upgrade interactively.
+    ///
+    /// Lists every outdated dependency in `project.dependencies`, along with the kind of version
+    /// change (i.e., major, minor, patch, or pre-release) required to reach its latest version.
+    /// The selected dependencies are upgraded together, with a single update to the lockfile.
+    ///
+    /// Optionally, the lower bound of each selected requirement can be raised to its new version
+    /// in the `pyproject.toml`.
+    #[arg(long, short, conflicts_with = "package")]
+    pub interactive: bool,
 }
 
 #[derive(Args)]
@@ -4406,6 +5079,37 @@ pub struct AddArgs {
     #[arg(long, value_enum)]
     pub bounds: Option<AddBoundsKind>,
 
+    /// Rewrite the bounds of existing dependencies to match the locked versions.
+    ///
+    /// Updates the version specifiers of every registry dependency across all workspace members,
+    /// including optional dependencies and dependency groups, based on the version in the lockfile
+    /// and the `--bounds` policy. Dependencies with URL, path, Git, or workspace sources are left
+    /// unchanged.
+    #[arg(
+        long,
+        group = "sources",
+        conflicts_with_all = [
+            "packages",
+            "requirements",
+            "constraints",
+            "marker",
+            "dev",
+            "optional",
+            "group",
+            "editable",
+            "raw",
+            "rev",
+            "tag",
+            "branch",
+            "extra",
+            "package",
+            "script",
+            "locked",
+            "frozen",
+        ]
+    )]
+    pub bump_all: bool,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set, value_hint = ValueHint::Other)]
     pub rev: Option<String>,
@@ -4867,6 +5571,109 @@ pub struct TreeArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct WhyArgs {
+    /// The package to explain.
+    pub package: PackageName,
+
+    /// The format in which to display the dependency paths.
+    #[arg(long, value_enum, default_value_t = WhyFormat::default())]
+    pub output_format: WhyFormat,
+
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Display the dependency paths without locking the project [env: UV_FROZEN=]
+    ///
+    /// If the lockfile is missing, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// Explain the dependencies of the specified PEP 723 Python script, rather than the current
+    /// project.
+    ///
+    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
+    /// adherence with PEP 723.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub script: Option<PathBuf>,
+
+    /// The Python interpreter to use for locking.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct OutdatedArgs {
+    /// The format in which to display the outdated packages.
+    #[arg(long, value_enum, default_value_t = OutdatedFormat::default())]
+    pub output_format: OutdatedFormat,
+
+    /// Only display packages that are required directly by a workspace member.
+    #[arg(long)]
+    pub direct: bool,
+
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Check for updates without locking the project [env: UV_FROZEN=]
+    ///
+    /// If the lockfile is missing, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    /// Check the dependencies of the specified PEP 723 Python script for updates, rather than the
+    /// current project.
+    ///
+    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
+    /// adherence with PEP 723.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub script: Option<PathBuf>,
+
+    /// The Python interpreter to use for locking.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct ExportArgs {
     /// The format to which `uv.lock` should be exported.
@@ -4875,6 +5682,11 @@ pub struct ExportArgs {
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
+    ///
+    /// The `bundle` format writes every wheel and source distribution referenced by the lockfile
+    /// to the directory given by `--output-file`, along with a `pylock.toml` that points to them.
+    /// The directory can then be used as a flat index (e.g., `--find-links bundle/`) without
+    /// network access.
     #[arg(long, value_enum)]
     pub format: Option<ExportFormat>,
 
@@ -5028,9 +5840,24 @@ pub struct ExportArgs {
     pub no_hashes: bool,
 
     /// Write the exported requirements to the given file.
+    ///
+    /// When exporting with `--format bundle`, the bundle is written to the given directory.
     #[arg(long, short, value_hint = ValueHint::FilePath)]
     pub output_file: Option<PathBuf>,
 
+    /// The platforms for which to include wheels when exporting with `--format bundle`.
+    ///
+    /// By default, wheels for all platforms are included. When provided, packages and wheels that
+    /// are incompatible with all of the given platforms are omitted.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
     /// Do not emit the current project.
     ///
     /// By default, the current project is included in the exported requirements file with all of
@@ -5560,6 +6387,9 @@ pub struct AuditArgs {
     /// changed with `--service-url`. The defaults are:
     ///
     /// * OSV: <https://api.osv.dev/>
+    /// * OSV dump: A local copy of the OSV database in the uv cache, see `--update-db`. Use
+    ///   `--service-url` to provide the path to a directory with an unpacked OSV data dump
+    ///   instead.
     #[arg(long, value_enum, default_value = "osv")]
     pub service_format: VulnerabilityServiceFormat,
 
@@ -5571,6 +6401,22 @@ pub struct AuditArgs {
     /// format was requested by `--service-format`.
     #[arg(long, value_hint = ValueHint::Url)]
     pub service_url: Option<String>,
+
+    /// Download the latest OSV database for `--service-format osv-dump`, then exit.
+    ///
+    /// The database is stored in the uv cache, or in the directory given by `--service-url` when
+    /// using `--service-format osv-dump`.
+    #[arg(long)]
+    pub update_db: bool,
+
+    /// Upgrade vulnerable packages to the nearest version that fixes their vulnerabilities.
+    ///
+    /// Each vulnerable package is constrained to the lowest version that fixes all of its known
+    /// vulnerabilities, or higher, and the lockfile is updated while preferring the locked
+    /// versions of all other packages. Vulnerabilities that can't be fixed due to conflicting
+    /// requirements are reported, along with the resolver error.
+    #[arg(long, conflicts_with_all = ["locked", "frozen", "update_db"])]
+    pub fix: bool,
 }
 
 #[derive(Args)]
@@ -8126,6 +8972,13 @@ pub struct DisplayTreeArgs {
     /// Show compressed wheel sizes for packages in the tree.
     #[arg(long)]
     pub show_sizes: bool,
+
+    /// The format in which to display the dependency tree.
+    ///
+    /// The `json`, `dot`, and `mermaid` formats display the dependency graph, in which each
+    /// package appears once, rather than a tree.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub output_format: TreeFormat,
 }
 
 #[derive(Args, Debug)]
@@ -8379,6 +9232,11 @@ pub struct WorkspaceListArgs {
     /// List all standalone scripts with inline metadata in the workspace.
     #[arg(long)]
     pub scripts: bool,
+
+    /// List the tasks defined in `tool.uv.tasks` by each workspace member.
+    // Hidden while in preview.
+    #[arg(long, conflicts_with_all = ["paths", "scripts"], hide = true)]
+    pub tasks: bool,
 }
 
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
@@ -8409,4 +9267,6 @@ pub enum BuildBackendCommand {
     GetRequiresForBuildEditable,
     /// PEP 660 hook `prepare_metadata_for_build_editable`.
     PrepareMetadataForBuildEditable { wheel_directory: PathBuf },
+    /// Print the build hook configuration as JSON for the `uv_build` Python shim.
+    BuildHook,
 }
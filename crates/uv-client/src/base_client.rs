@@ -433,6 +433,7 @@ impl<'a> BaseClientBuilder<'a> {
             read_timeout: self.read_timeout,
             connect_timeout: self.connect_timeout,
             credentials_cache: self.credentials_cache.clone(),
+            preview: self.preview,
         })
     }
 
@@ -462,6 +463,7 @@ impl<'a> BaseClientBuilder<'a> {
             read_timeout: existing.read_timeout,
             connect_timeout: existing.connect_timeout,
             credentials_cache: existing.credentials_cache.clone(),
+            preview: self.preview,
         }
     }
 
@@ -694,6 +696,8 @@ pub struct BaseClient {
     no_retry_delay: bool,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
+    /// The preview features that are enabled.
+    preview: Preview,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -753,6 +757,11 @@ impl BaseClient {
         &self.credentials_cache
     }
 
+    /// The preview features that are enabled.
+    pub fn preview(&self) -> Preview {
+        self.preview
+    }
+
     /// The reqwest client without middleware.
     ///
     /// This strips important features such as retries and authenticating. Only use when passing
@@ -449,6 +449,9 @@ pub enum ErrorKind {
     #[error("Failed to read zip with range requests: `{0}`")]
     AsyncHttpRangeReader(DisplaySafeUrl, #[source] AsyncHttpRangeReaderError),
 
+    #[error("Expected a partial response to a range request for `{0}`, but received: {1}")]
+    RangeRequestFailed(DisplaySafeUrl, reqwest::StatusCode),
+
     #[error("{0} is not a valid wheel filename")]
     WheelFilename(#[source] WheelFilenameError),
 
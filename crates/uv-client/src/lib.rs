@@ -11,6 +11,7 @@ pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleDetailMetadata,
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
 };
+pub use resumable::ResumableDownload;
 pub use retry::{RetriableError, RetryState, UvRetryableStrategy, retryable_on_request_failure};
 pub use rkyvutil::OwnedArchive;
 
@@ -24,6 +25,7 @@ mod linehaul;
 mod middleware;
 mod registry_client;
 mod remote_metadata;
+mod resumable;
 mod retry;
 mod rkyvutil;
 mod tls;
@@ -0,0 +1,374 @@
+//! Resumable downloads of large files with HTTP range requests.
+//!
+//! A resumable download is written to a directory with a partial file per byte range, e.g.:
+//!
+//! ```text
+//! torch-2.9.0-cp312-cp312-manylinux_2_28_x86_64.partial/
+//!   validator
+//!   0-453741856.part
+//!   453741856-907483712.part
+//! ```
+//!
+//! The length of each partial file is the offset from which its range is resumed, such that an
+//! interrupted download (within a single invocation, or across invocations) picks up where it left
+//! off rather than restarting from the first byte. Once every range is complete, the partial files
+//! are concatenated into the first one.
+
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+
+use futures::{StreamExt, future::try_join_all};
+use http::HeaderValue;
+use http::header::{
+    ACCEPT_ENCODING, ACCEPT_RANGES, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE,
+};
+use reqwest::{Response, StatusCode};
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::{BaseClient, Error, ErrorKind, RetryState};
+
+/// The file that records the validator of the resource that the partial files were fetched from.
+const VALIDATOR: &str = "validator";
+
+/// A download that can be resumed with HTTP range requests.
+#[derive(Debug, Clone)]
+pub struct ResumableDownload {
+    url: DisplaySafeUrl,
+    size: u64,
+    /// The strong `ETag` or the `Last-Modified` date of the resource, used to ensure that every
+    /// range is fetched from the same version of the resource.
+    validator: Option<HeaderValue>,
+}
+
+impl ResumableDownload {
+    /// Create a [`ResumableDownload`] from the response to a `GET` request for the resource.
+    ///
+    /// Returns `None` if the server doesn't support range requests for the resource, or if its size
+    /// is unknown.
+    pub fn from_response(response: &Response, size: Option<u64>) -> Option<Self> {
+        let supports_ranges = response
+            .headers()
+            .get(ACCEPT_RANGES)
+            .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"bytes"));
+        if !supports_ranges {
+            return None;
+        }
+
+        let size = size.or_else(|| response.content_length())?;
+        if size == 0 {
+            return None;
+        }
+
+        // Weak `ETag`s can't be used with `If-Range`.
+        let validator = response
+            .headers()
+            .get(ETAG)
+            .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+            .or_else(|| response.headers().get(LAST_MODIFIED))
+            .cloned();
+
+        Some(Self {
+            url: DisplaySafeUrl::from_url(response.url().clone()),
+            size,
+            validator,
+        })
+    }
+
+    /// Download the resource into `directory`, fetching up to `ranges` byte ranges in parallel,
+    /// and return the path to the complete file.
+    ///
+    /// The body of the initial `response` is used for the first range, unless data for that range
+    /// was already downloaded by a previous attempt. `on_progress` is called with the number of
+    /// bytes written, including any bytes that were downloaded by a previous attempt.
+    ///
+    /// The caller is responsible for validating the contents of the file (e.g., against a known
+    /// hash), and for removing the directory once the file has been consumed.
+    pub async fn download(
+        &self,
+        client: &BaseClient,
+        response: Response,
+        directory: &Path,
+        ranges: NonZeroUsize,
+        on_progress: &(dyn Fn(u64) + Send + Sync),
+    ) -> Result<PathBuf, Error> {
+        fs_err::tokio::create_dir_all(directory)
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+
+        // If the resource changed since the previous attempt, discard any partial files.
+        let validator = self
+            .validator
+            .as_ref()
+            .map(HeaderValue::as_bytes)
+            .unwrap_or_default();
+        let previous = match fs_err::tokio::read(directory.join(VALIDATOR)).await {
+            Ok(previous) => Some(previous),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(ErrorKind::CacheWrite(err).into()),
+        };
+        let ranges = ByteRange::split(self.size, ranges);
+        let mut entries = fs_err::tokio::read_dir(directory)
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+        while let Some(entry) = entries.next_entry().await.map_err(ErrorKind::CacheWrite)? {
+            let name = entry.file_name();
+            if name == VALIDATOR {
+                continue;
+            }
+            // Discard partial files for a different set of ranges, e.g., from a previous attempt
+            // with a different level of parallelism.
+            let stale = previous.as_deref() != Some(validator)
+                || !ranges
+                    .iter()
+                    .any(|range| name == range.file_name().as_str());
+            if stale {
+                debug!(
+                    "Removing stale partial download: {}",
+                    entry.path().display()
+                );
+                fs_err::tokio::remove_file(entry.path())
+                    .await
+                    .map_err(ErrorKind::CacheWrite)?;
+            }
+        }
+        if previous.as_deref() != Some(validator) {
+            fs_err::tokio::write(directory.join(VALIDATOR), validator)
+                .await
+                .map_err(ErrorKind::CacheWrite)?;
+        }
+
+        let mut response = Some(response);
+        try_join_all(ranges.iter().map(|range| {
+            let response = if range.start == 0 {
+                response.take()
+            } else {
+                None
+            };
+            self.download_range(client, *range, directory, response, on_progress)
+        }))
+        .await?;
+
+        // Concatenate the ranges into the first partial file.
+        let (first, rest) = ranges.split_first().expect("at least one range");
+        let path = directory.join(first.file_name());
+        if !rest.is_empty() {
+            let mut file = fs_err::tokio::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .await
+                .map_err(ErrorKind::CacheWrite)?;
+            for range in rest {
+                let part = directory.join(range.file_name());
+                let mut reader = fs_err::tokio::File::open(&part)
+                    .await
+                    .map_err(ErrorKind::CacheWrite)?;
+                tokio::io::copy(&mut reader, &mut file)
+                    .await
+                    .map_err(ErrorKind::CacheWrite)?;
+                fs_err::tokio::remove_file(&part)
+                    .await
+                    .map_err(ErrorKind::CacheWrite)?;
+            }
+            file.flush().await.map_err(ErrorKind::CacheWrite)?;
+        }
+
+        Ok(path)
+    }
+
+    /// Download a single byte range to its partial file, resuming from the end of the file and
+    /// retrying on transient errors.
+    async fn download_range(
+        &self,
+        client: &BaseClient,
+        range: ByteRange,
+        directory: &Path,
+        mut response: Option<Response>,
+        on_progress: &(dyn Fn(u64) + Send + Sync),
+    ) -> Result<(), Error> {
+        let path = directory.join(range.file_name());
+        let mut file = fs_err::tokio::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+        let mut offset = file.metadata().await.map_err(ErrorKind::CacheWrite)?.len();
+        if offset > range.len() {
+            file.set_len(0).await.map_err(ErrorKind::CacheWrite)?;
+            offset = 0;
+        }
+        if offset > 0 {
+            debug!(
+                "Resuming download of {} at byte {} of range {}",
+                self.url,
+                offset,
+                range.file_name()
+            );
+            on_progress(offset);
+            response = None;
+        }
+
+        let mut retry_state = RetryState::start(client.retry_policy(), self.url.clone());
+        while offset < range.len() {
+            let remaining = ByteRange {
+                start: range.start + offset,
+                end: range.end,
+            };
+            let result = match response.take() {
+                Some(response) => {
+                    self.write_body(response, remaining.len(), &mut file, on_progress)
+                        .await
+                }
+                None => match self.fetch(client, remaining).await {
+                    Ok(response) => {
+                        self.write_body(response, remaining.len(), &mut file, on_progress)
+                            .await
+                    }
+                    Err(err) => Err(err),
+                },
+            };
+
+            let written = file.metadata().await.map_err(ErrorKind::CacheWrite)?.len();
+
+            if let Err(err) = result {
+                // Reset the retry budget whenever a request makes progress, such that a flaky
+                // connection can't exhaust it over the course of a long download.
+                if written > offset {
+                    retry_state = RetryState::start(client.retry_policy(), self.url.clone());
+                }
+                if let Some(backoff) = retry_state.should_retry(&err, err.retries()) {
+                    retry_state.sleep_backoff(backoff).await;
+                } else {
+                    return Err(err.with_retries(retry_state.total_retries()));
+                }
+            }
+            offset = written;
+        }
+
+        Ok(())
+    }
+
+    /// Request the given byte range of the resource.
+    async fn fetch(&self, client: &BaseClient, range: ByteRange) -> Result<Response, Error> {
+        let mut request = client
+            .for_host(&self.url)
+            .get(self.url.as_str())
+            .header(RANGE, format!("bytes={}-{}", range.start, range.end - 1))
+            // Ranges refer to the encoded representation, so request the file as-is.
+            .header(ACCEPT_ENCODING, HeaderValue::from_static("identity"));
+        if let Some(validator) = &self.validator {
+            request = request.header(IF_RANGE, validator.clone());
+        }
+        let request = request
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(self.url.clone(), err))?;
+
+        let start = std::time::Instant::now();
+        let response = client
+            .execute(request)
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(self.url.clone(), err, start))?
+            .error_for_status()
+            .map_err(|err| ErrorKind::from_reqwest(self.url.clone(), err))?;
+
+        // If the server ignored the range (e.g., because the resource changed, and the `If-Range`
+        // condition failed), it responds with the full resource.
+        let expected = format!("bytes {}-{}/", range.start, range.end - 1);
+        let content_range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok());
+        if response.status() != StatusCode::PARTIAL_CONTENT
+            || !content_range.is_some_and(|value| value.starts_with(&expected))
+        {
+            return Err(ErrorKind::RangeRequestFailed(self.url.clone(), response.status()).into());
+        }
+
+        Ok(response)
+    }
+
+    /// Append up to `limit` bytes of the response body to the file.
+    async fn write_body(
+        &self,
+        response: Response,
+        limit: u64,
+        file: &mut fs_err::tokio::File,
+        on_progress: &(dyn Fn(u64) + Send + Sync),
+    ) -> Result<(), Error> {
+        let mut remaining = limit;
+        let mut stream = response.bytes_stream();
+        while remaining > 0 {
+            let Some(chunk) = stream.next().await else {
+                return Err(ErrorKind::Io(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("Connection closed with {remaining} bytes remaining"),
+                ))
+                .into());
+            };
+            let chunk = chunk.map_err(|err| ErrorKind::from_reqwest(self.url.clone(), err))?;
+            let len = u64::try_from(chunk.len())
+                .unwrap_or(u64::MAX)
+                .min(remaining);
+            let chunk = &chunk[..usize::try_from(len).expect("length fits in chunk")];
+            file.write_all(chunk).await.map_err(ErrorKind::CacheWrite)?;
+            file.flush().await.map_err(ErrorKind::CacheWrite)?;
+            remaining -= len;
+            on_progress(len);
+        }
+        Ok(())
+    }
+}
+
+/// A half-open range of bytes, `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+impl ByteRange {
+    /// Split `[0, size)` into up to `count` contiguous ranges of (roughly) equal length.
+    fn split(size: u64, count: NonZeroUsize) -> Vec<Self> {
+        let count = u64::try_from(count.get()).unwrap_or(u64::MAX).min(size);
+        let len = size.div_ceil(count);
+        (0..count)
+            .map(|index| Self {
+                start: index * len,
+                end: ((index + 1) * len).min(size),
+            })
+            .filter(|range| range.start < range.end)
+            .collect()
+    }
+
+    fn len(self) -> u64 {
+        self.end - self.start
+    }
+
+    /// The name of the partial file for this range.
+    fn file_name(self) -> String {
+        format!("{}-{}.part", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::ByteRange;
+
+    #[test]
+    fn split() {
+        let ranges = |size, count| {
+            ByteRange::split(size, NonZeroUsize::new(count).unwrap())
+                .into_iter()
+                .map(|range| (range.start, range.end))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(10, 1), [(0, 10)]);
+        assert_eq!(ranges(10, 3), [(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(ranges(2, 4), [(0, 1), (1, 2)]);
+    }
+}
@@ -2,5 +2,6 @@ mod cached_client;
 mod http_util;
 mod proxy;
 mod remote_metadata;
+mod resumable;
 mod ssl_certs;
 mod user_agent_version;
@@ -0,0 +1,173 @@
+//! An integration test for resumable downloads with HTTP range requests.
+
+use std::num::NonZeroUsize;
+
+use anyhow::Result;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+use uv_client::{BaseClient, BaseClientBuilder, ErrorKind, ResumableDownload};
+use uv_redacted::DisplaySafeUrl;
+
+const ETAG: &str = "\"v1\"";
+
+/// A server that responds to range requests for a fixed body.
+struct RangeResponder {
+    body: Vec<u8>,
+    /// Whether the server honors the `Range` header, despite advertising support for it.
+    honor_ranges: bool,
+}
+
+impl Respond for RangeResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let range = request
+            .headers
+            .get("range")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("bytes="))
+            .and_then(|value| value.split_once('-'))
+            .map(|(start, end)| {
+                (
+                    start.parse::<usize>().unwrap(),
+                    end.parse::<usize>().unwrap(),
+                )
+            });
+        let template = |status| {
+            ResponseTemplate::new(status)
+                .insert_header("accept-ranges", "bytes")
+                .insert_header("etag", ETAG)
+        };
+        match range {
+            Some((start, end)) if self.honor_ranges => template(206)
+                .insert_header(
+                    "content-range",
+                    format!("bytes {start}-{end}/{}", self.body.len()).as_str(),
+                )
+                .set_body_bytes(&self.body[start..=end]),
+            _ => template(200).set_body_bytes(self.body.clone()),
+        }
+    }
+}
+
+async fn start(body: &[u8], honor_ranges: bool) -> (MockServer, BaseClient, DisplaySafeUrl) {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(RangeResponder {
+            body: body.to_vec(),
+            honor_ranges,
+        })
+        .mount(&server)
+        .await;
+    let client = BaseClientBuilder::default().retries(0).build().unwrap();
+    let url = DisplaySafeUrl::parse(&format!("{}/file.whl", server.uri())).unwrap();
+    (server, client, url)
+}
+
+/// Return the `Range` headers of the requests received by the server.
+async fn ranges(server: &MockServer) -> Vec<Option<String>> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get("range")
+                .map(|value| value.to_str().unwrap().to_string())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn resume_partial_download() -> Result<()> {
+    let body = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+    let (server, client, url) = start(&body, true).await;
+
+    // Simulate an interrupted download of the first 300 bytes.
+    let directory = tempfile::tempdir()?;
+    fs_err::write(directory.path().join("validator"), ETAG)?;
+    fs_err::write(directory.path().join("0-1000.part"), &body[..300])?;
+
+    let response = client.for_host(&url).get(url.as_str()).send().await?;
+    let download = ResumableDownload::from_response(&response, None).unwrap();
+    let path = download
+        .download(
+            &client,
+            response,
+            directory.path(),
+            NonZeroUsize::MIN,
+            &|_| {},
+        )
+        .await?;
+
+    assert_eq!(fs_err::read(path)?, body);
+    assert_eq!(
+        ranges(&server).await,
+        [None, Some("bytes=300-999".to_string())]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn parallel_ranges() -> Result<()> {
+    let body = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+    let (server, client, url) = start(&body, true).await;
+
+    let directory = tempfile::tempdir()?;
+    let response = client.for_host(&url).get(url.as_str()).send().await?;
+    let download = ResumableDownload::from_response(&response, Some(1000)).unwrap();
+    let path = download
+        .download(
+            &client,
+            response,
+            directory.path(),
+            NonZeroUsize::new(3).unwrap(),
+            &|_| {},
+        )
+        .await?;
+
+    assert_eq!(fs_err::read(path)?, body);
+
+    // The body of the initial response is used for the first range.
+    let mut ranges = ranges(&server).await;
+    ranges.sort();
+    assert_eq!(
+        ranges,
+        [
+            None,
+            Some("bytes=334-667".to_string()),
+            Some("bytes=668-999".to_string())
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn range_ignored() -> Result<()> {
+    let body = vec![1u8; 1000];
+    let (_server, client, url) = start(&body, false).await;
+
+    let directory = tempfile::tempdir()?;
+    fs_err::write(directory.path().join("validator"), ETAG)?;
+    fs_err::write(directory.path().join("0-1000.part"), &body[..300])?;
+
+    let response = client.for_host(&url).get(url.as_str()).send().await?;
+    let download = ResumableDownload::from_response(&response, None).unwrap();
+    let err = download
+        .download(
+            &client,
+            response,
+            directory.path(),
+            NonZeroUsize::MIN,
+            &|_| {},
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err.kind(), ErrorKind::RangeRequestFailed(_, status) if *status == 200));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+/// Represents an "adverse" project status, i.e. a status that indicates that
+/// a downstream user of the project should review their use of the project
+/// and consider removing it.
+///
+/// These are a subset of the possible project statuses defined in [PEP 792].
+///
+/// [PEP 792]: https://peps.python.org/pep-0792/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AdverseStatus {
+    /// The project is archived, meaning it is read-only and no longer maintained.
+    Archived,
+    /// The project is considered generally unsafe for use, e.g. due to malware.
+    Quarantined,
+    /// The project is considered obsolete, and may have been superseded by another project.
+    Deprecated,
+}
+
+impl std::fmt::Display for AdverseStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+            Self::Archived => "archived",
+            Self::Quarantined => "quarantined",
+            Self::Deprecated => "deprecated",
+        })
+    }
+}
+
+/// The qualitative severity rating of a vulnerability, as defined by the CVSS v3 specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Severity {
+    /// A CVSS base score between 0.1 and 3.9.
+    Low,
+    /// A CVSS base score between 4.0 and 6.9.
+    Medium,
+    /// A CVSS base score between 7.0 and 8.9.
+    High,
+    /// A CVSS base score between 9.0 and 10.0.
+    Critical,
+}
+
+impl Severity {
+    /// Return the severity rating for a CVSS base score, or `None` for a score of zero.
+    pub fn from_cvss_score(score: f64) -> Option<Self> {
+        if score >= 9.0 {
+            Some(Self::Critical)
+        } else if score >= 7.0 {
+            Some(Self::High)
+        } else if score >= 4.0 {
+            Some(Self::Medium)
+        } else if score > 0.0 {
+            Some(Self::Low)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        })
+    }
+}
@@ -20,6 +20,14 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of byte ranges to fetch concurrently when resuming a download.
+    ///
+    /// Note this value must be non-zero.
+    pub download_ranges: usize,
+    /// The maximum number of workspace members in which to run a command concurrently.
+    ///
+    /// Note this value must be non-zero.
+    pub runs: usize,
     /// A global semaphore to limit the number of concurrent downloads.
     pub downloads_semaphore: Arc<Semaphore>,
     /// A global semaphore to limit the number of concurrent builds.
@@ -34,13 +42,21 @@ impl fmt::Debug for Concurrency {
             .field("downloads", &self.downloads)
             .field("builds", &self.builds)
             .field("installs", &self.installs)
+            .field("download_ranges", &self.download_ranges)
+            .field("runs", &self.runs)
             .finish()
     }
 }
 
 impl Default for Concurrency {
     fn default() -> Self {
-        Self::new(Self::DEFAULT_DOWNLOADS, Self::threads(), Self::threads())
+        Self::new(
+            Self::DEFAULT_DOWNLOADS,
+            Self::threads(),
+            Self::threads(),
+            Self::DEFAULT_DOWNLOAD_RANGES,
+            Self::threads(),
+        )
     }
 }
 
@@ -48,18 +64,29 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
+    // The default concurrent download ranges limit.
+    pub const DEFAULT_DOWNLOAD_RANGES: usize = 1;
+
     /// Create a new [`Concurrency`] with the given limits.
-    pub fn new(downloads: usize, builds: usize, installs: usize) -> Self {
+    pub fn new(
+        downloads: usize,
+        builds: usize,
+        installs: usize,
+        download_ranges: usize,
+        runs: usize,
+    ) -> Self {
         Self {
             downloads,
             builds,
             installs,
+            download_ranges,
+            runs,
             downloads_semaphore: Arc::new(Semaphore::new(downloads)),
             builds_semaphore: Arc::new(Semaphore::new(builds)),
         }
     }
 
-    // The default concurrent builds and install limit.
+    // The default concurrent builds, installs, and runs limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
             .map(NonZeroUsize::get)
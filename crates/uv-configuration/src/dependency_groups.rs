@@ -132,6 +132,15 @@ impl DependencyGroups {
         })
     }
 
+    /// Return a copy of this specification that also includes the given groups, as if they were
+    /// provided via `--group`.
+    #[must_use]
+    pub fn with_groups(&self, groups: impl IntoIterator<Item = GroupName>) -> Self {
+        let mut history = self.history.clone();
+        history.group.extend(groups);
+        Self::from_history(history)
+    }
+
     /// Helper to make a spec from just a --group
     pub fn from_group(group: GroupName) -> Self {
         Self::from_history(DependencyGroupsHistory {
@@ -22,6 +22,10 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export a self-contained directory of distribution archives, along with a `pylock.toml`.
+    #[serde(rename = "bundle")]
+    #[cfg_attr(feature = "clap", clap(name = "bundle"))]
+    Bundle,
 }
 
 /// The output format to use in `uv pip compile`.
@@ -1,6 +1,8 @@
 use tracing::debug;
 
-use uv_pep508::MarkerEnvironment;
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
+};
 use uv_platform_tags::{Arch, Os, Platform};
 use uv_static::EnvVars;
 
@@ -1059,6 +1061,25 @@ impl TargetTriple {
             .with_platform_release(self.platform_release())
             .with_platform_version(self.platform_version())
     }
+
+    /// Return a [`MarkerTree`] that matches the platform markers of the [`TargetTriple`], for
+    /// any Python version.
+    pub fn platform_marker(self) -> MarkerTree {
+        let mut marker = MarkerTree::TRUE;
+        for (key, value) in [
+            (MarkerValueString::SysPlatform, self.sys_platform()),
+            (MarkerValueString::PlatformMachine, self.platform_machine()),
+            (MarkerValueString::PlatformSystem, self.platform_system()),
+            (MarkerValueString::OsName, self.os_name()),
+        ] {
+            marker.and(MarkerTree::expression(MarkerExpression::String {
+                key,
+                operator: MarkerOperator::Equal,
+                value: value.into(),
+            }));
+        }
+        marker
+    }
 }
 
 /// Return the macOS deployment target as parsed from the environment.
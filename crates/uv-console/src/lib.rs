@@ -97,6 +97,115 @@ fn confirm_inner(
     Ok(response)
 }
 
+/// Prompt the user to select any number of items in the given [`Term`].
+///
+/// This is a slimmed-down version of `dialoguer::MultiSelect`. Items are toggled with the space
+/// bar (or all at once with `a`), and the selection is confirmed with `Enter`. Returns the indices
+/// of the selected items, in order.
+pub fn multi_select(message: &str, items: &[String], term: &Term) -> std::io::Result<Vec<usize>> {
+    let prompt = format!(
+        "{} {} {}",
+        style("?".to_string()).for_stderr().yellow(),
+        style(message).for_stderr().bold(),
+        style("[space to toggle, enter to confirm]")
+            .for_stderr()
+            .black()
+            .bright(),
+    );
+
+    let render = |cursor: usize, selected: &[bool]| -> std::io::Result<()> {
+        for (index, item) in items.iter().enumerate() {
+            let pointer = if index == cursor { "❯" } else { " " };
+            let checkbox = if selected[index] { "◉" } else { "◯" };
+            let line = format!(
+                "{} {} {item}",
+                style(pointer).for_stderr().cyan(),
+                if selected[index] {
+                    style(checkbox).for_stderr().green()
+                } else {
+                    style(checkbox).for_stderr().black().bright()
+                },
+            );
+            term.write_line(&line)?;
+        }
+        term.flush()
+    };
+
+    let mut cursor = 0;
+    let mut selected = vec![false; items.len()];
+
+    term.write_line(&prompt)?;
+    term.hide_cursor()?;
+    render(cursor, &selected)?;
+
+    loop {
+        match term.read_key_raw()? {
+            Key::ArrowUp | Key::Char('k') if !items.is_empty() => {
+                cursor = (cursor + items.len() - 1) % items.len();
+            }
+            Key::ArrowDown | Key::Char('j') if !items.is_empty() => {
+                cursor = (cursor + 1) % items.len();
+            }
+            Key::Char(' ') if !items.is_empty() => {
+                selected[cursor] = !selected[cursor];
+            }
+            Key::Char('a') => {
+                let all = selected.iter().all(|selected| *selected);
+                selected.fill(!all);
+            }
+            Key::Enter => break,
+            Key::CtrlC => {
+                let term = Term::stderr();
+                term.show_cursor()?;
+                term.write_str("\n")?;
+                term.flush()?;
+
+                #[expect(clippy::exit, clippy::cast_possible_wrap)]
+                std::process::exit(if cfg!(windows) {
+                    0xC000_013A_u32 as i32
+                } else {
+                    130
+                });
+            }
+            _ => continue,
+        }
+        term.clear_last_lines(items.len())?;
+        render(cursor, &selected)?;
+    }
+
+    let selection = selected
+        .iter()
+        .enumerate()
+        .filter(|(_, selected)| **selected)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let report = format!(
+        "{} {} {} {}",
+        style("✔".to_string()).for_stderr().green(),
+        style(message).for_stderr().bold(),
+        style("·").for_stderr().black().bright(),
+        style(if selection.is_empty() {
+            "none".to_string()
+        } else {
+            selection
+                .iter()
+                .map(|index| items[*index].as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .for_stderr()
+        .cyan(),
+    );
+
+    term.clear_last_lines(items.len() + 1)?;
+    term.write_line(&report)?;
+    term.show_cursor()?;
+    term.flush()?;
+
+    Ok(selection)
+}
+
 /// Prompt the user for password in the given [`Term`].
 ///
 /// This is a slimmed-down version of `dialoguer::Password`.
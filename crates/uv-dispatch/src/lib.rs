@@ -241,6 +241,10 @@ impl BuildContext for BuildDispatch<'_> {
         self.build_isolation
     }
 
+    fn concurrency(&self) -> &Concurrency {
+        &self.concurrency
+    }
+
     fn config_settings(&self) -> &ConfigSettings {
         self.config_settings
     }
@@ -1,32 +1,36 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
-use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWriteExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    ResumableDownload,
 };
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildInfo, BuildableSource, BuiltDist, Dist, DistRef, File, HashPolicy, Hashed, IndexUrl,
-    InstalledDist, Name, SourceDist, ToUrlError,
+    InstalledDist, Name, RemoteSource, SourceDist, ToUrlError,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{Simplified, write_atomic};
 use uv_git::{GIT_LFS, GitError};
 use uv_install_wheel::validate_and_heal_record;
+use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
+use uv_preview::PreviewFeature;
 use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
 use uv_python::PythonVariant;
 use uv_redacted::DisplaySafeUrl;
@@ -40,6 +44,9 @@ use crate::metadata::{ArchiveMetadata, Metadata};
 use crate::source::SourceDistributionBuilder;
 use crate::{Error, LocalWheel, Reporter, RequiresDist};
 
+/// The minimum size of a wheel, in bytes, for it to be downloaded with a [`ResumableDownload`].
+const RESUMABLE_DOWNLOAD_THRESHOLD: u64 = 100 * 1024 * 1024;
+
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
 /// to a wheel or wheel metadata.
 ///
@@ -94,18 +101,23 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
     fn handle_response_errors(&self, err: reqwest::Error) -> io::Error {
-        if err.is_timeout() {
-            // Assumption: The connect timeout with the 10s default is not the culprit.
-            io::Error::new(
-                io::ErrorKind::TimedOut,
-                format!(
-                    "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",
-                    self.client.unmanaged.read_timeout().as_secs()
-                ),
-            )
-        } else {
-            io::Error::other(err)
-        }
+        handle_response_errors(self.client.unmanaged, err)
+    }
+
+    /// Returns `true` if a wheel of the given size should be downloaded with a
+    /// [`ResumableDownload`].
+    ///
+    /// Resumable downloads are only used for large wheels, for which the index provides digests, as
+    /// ranges downloaded by a previous attempt are only reused if the complete file can be verified.
+    fn is_resumable(&self, size: Option<u64>, index_hashes: &[HashDigest]) -> bool {
+        self.client
+            .unmanaged
+            .cached_client()
+            .uncached()
+            .preview()
+            .is_enabled(PreviewFeature::ResumableDownloads)
+            && size.is_some_and(|size| size >= RESUMABLE_DOWNLOAD_THRESHOLD)
+            && !index_hashes.is_empty()
     }
 
     /// Either fetch the wheel or fetch and build the source distribution
@@ -215,6 +227,37 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
+                // Download large wheels to disk rather than streaming them, such that an
+                // interrupted download can be resumed.
+                if self.is_resumable(size, wheel.file.hashes.as_slice()) {
+                    let archive = self
+                        .download_wheel(
+                            url,
+                            dist.index(),
+                            &wheel.filename,
+                            extension,
+                            size,
+                            &wheel_entry,
+                            dist,
+                            hashes,
+                            wheel.file.hashes.as_slice(),
+                        )
+                        .await?;
+
+                    return Ok(LocalWheel {
+                        dist: Dist::Built(dist.clone()),
+                        archive: self
+                            .build_context
+                            .cache()
+                            .archive(&archive.id)
+                            .into_boxed_path(),
+                        hashes: archive.hashes,
+                        filename: wheel.filename.clone(),
+                        cache: CacheInfo::default(),
+                        build: None,
+                    });
+                }
+
                 // Download and unzip.
                 match self
                     .stream_wheel(
@@ -264,6 +307,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                wheel.file.hashes.as_slice(),
                             )
                             .await?;
 
@@ -341,6 +385,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                &[],
                             )
                             .await?;
                         Ok(LocalWheel {
@@ -692,6 +737,77 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .await
     }
 
+    /// Download the original archive for a distribution (i.e., the wheel or source distribution
+    /// file, as published) into the given directory, rather than unpacking it into the cache.
+    ///
+    /// Hash-checking is enforced against the given policy.
+    ///
+    /// Returns `None` for distributions that aren't backed by an archive, like Git repositories
+    /// and local directories.
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn download_archive(
+        &self,
+        dist: &Dist,
+        directory: &Path,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Option<PathBuf>, Error> {
+        let (url, filename, size) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    wheel.file.url.to_url()?,
+                    wheel.filename.to_string(),
+                    wheel.file.size,
+                )
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                ((*wheel.location).clone(), wheel.filename.to_string(), None)
+            }
+            Dist::Built(BuiltDist::Path(wheel)) => (
+                DisplaySafeUrl::from_file_path(&wheel.install_path)
+                    .map_err(|()| Error::NotFound(wheel.url.to_url()))?,
+                wheel.filename.to_string(),
+                None,
+            ),
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                sdist.file.url.to_url()?,
+                sdist.file.filename.to_string(),
+                sdist.file.size,
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => (
+                (*sdist.location).clone(),
+                sdist.filename()?.into_owned(),
+                None,
+            ),
+            Dist::Source(SourceDist::Path(sdist)) => (
+                DisplaySafeUrl::from_file_path(&sdist.install_path)
+                    .map_err(|()| Error::NotFound(sdist.url.to_url()))?,
+                sdist.filename()?.into_owned(),
+                None,
+            ),
+            Dist::Built(BuiltDist::GitPath(_))
+            | Dist::Source(
+                SourceDist::GitDirectory(_) | SourceDist::GitPath(_) | SourceDist::Directory(_),
+            ) => return Ok(None),
+        };
+
+        let downloader = ArchiveDownloader {
+            client: ManagedClient::new(self.client.unmanaged, self.client.control.clone()),
+            reporter: self.reporter.clone(),
+        };
+        let archive = RemoteArchive {
+            name: dist.name(),
+            distribution: dist.to_string(),
+            url,
+            filename,
+            size,
+        };
+        downloader
+            .download(&archive, directory, hashes)
+            .await
+            .map(Some)
+    }
+
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
     async fn stream_wheel(
         &self,
@@ -868,6 +984,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Download a wheel from a URL, then unzip it into the cache.
+    ///
+    /// Large wheels are downloaded with a [`ResumableDownload`] if the server supports range
+    /// requests, such that an interrupted download resumes from where it left off. The complete
+    /// download is verified against the digests provided by the index (`index_hashes`).
     async fn download_wheel(
         &self,
         url: DisplaySafeUrl,
@@ -878,6 +998,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        index_hashes: &[HashDigest],
     ) -> Result<Archive, Error> {
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
@@ -889,6 +1010,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
 
+        // Create an entry for the partial files of a resumable download.
+        let partial_entry = wheel_entry.with_file(format!("{}.partial", filename.stem()));
+
         let download = |response: reqwest::Response| {
             async {
                 let size = size.or_else(|| content_length(&response));
@@ -898,43 +1022,75 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
-                let algorithms = http_hash_algorithms(hashes);
-                let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
-                let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+                let resumable = if self.is_resumable(size, index_hashes) {
+                    ResumableDownload::from_response(&response, size)
+                } else {
+                    None
+                };
 
-                // Download the wheel to a temporary file.
-                let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(fs_err::tokio::File::from_std(
-                    // It's an unnamed file on Linux so that's the best approximation.
-                    fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
-                ));
+                // Acquire an advisory lock, to guard against concurrent writes to the partial files.
+                let _lock = if resumable.is_some() {
+                    let lock_entry =
+                        wheel_entry.with_file(format!("{}.partial.lock", filename.stem()));
+                    Some(lock_entry.lock().await.map_err(Error::CacheLock)?)
+                } else {
+                    None
+                };
 
-                match progress {
-                    Some((reporter, progress)) => {
-                        // Wrap the reader in a progress reporter. This will report 100% progress once
-                        // the download is complete, before the wheel is unzipped.
-                        let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
+                let (mut file, hashes) = if let Some(resumable) = resumable {
+                    self.download_resumable(
+                        &resumable,
+                        response,
+                        &partial_entry,
+                        progress.map(|(reporter, progress)| (&**reporter, progress)),
+                        index_hashes,
+                        dist,
+                        hashes,
+                    )
+                    .await?
+                } else {
+                    let reader = response
+                        .bytes_stream()
+                        .map_err(|err| self.handle_response_errors(err))
+                        .into_async_read();
+                    let algorithms = http_hash_algorithms(hashes);
+                    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+                    let mut hasher =
+                        uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+
+                    // Download the wheel to a temporary file.
+                    let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
+                        .map_err(Error::CacheWrite)?;
+                    let mut writer = tokio::io::BufWriter::new(fs_err::tokio::File::from_std(
+                        // It's an unnamed file on Linux so that's the best approximation.
+                        fs_err::File::from_parts(temp_file, self.build_context.cache().root()),
+                    ));
 
-                        tokio::io::copy(&mut reader, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                    None => {
-                        tokio::io::copy(&mut hasher, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
+                    match progress {
+                        Some((reporter, progress)) => {
+                            // Wrap the reader in a progress reporter. This will report 100% progress once
+                            // the download is complete, before the wheel is unzipped.
+                            let mut reader =
+                                ProgressReader::new(&mut hasher, progress, &**reporter);
+
+                            tokio::io::copy(&mut reader, &mut writer)
+                                .await
+                                .map_err(Error::CacheWrite)?;
+                        }
+                        None => {
+                            tokio::io::copy(&mut hasher, &mut writer)
+                                .await
+                                .map_err(Error::CacheWrite)?;
+                        }
                     }
-                }
+
+                    let hashes = hashers.into_iter().map(HashDigest::from).collect();
+                    (writer.into_inner(), hashes)
+                };
 
                 // Unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
-                let mut file = writer.into_inner();
                 file.seek(io::SeekFrom::Start(0))
                     .await
                     .map_err(Error::CacheWrite)?;
@@ -949,7 +1105,6 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     WheelExtension::WhlZst => uv_extract::stream::untar_zst(file, &target).await,
                 }
                 .map_err(|err| Error::Extract(filename.to_string(), err))?;
-                let hashes = hashers.into_iter().map(HashDigest::from).collect();
 
                 // Before we make the wheel accessible by persisting it, ensure that the RECORD is
                 // valid.
@@ -964,6 +1119,13 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .await
                     .map_err(Error::CacheRead)?;
 
+                // Remove the partial files of a resumable download, now that the wheel is unzipped.
+                if partial_entry.path().is_dir() {
+                    fs_err::tokio::remove_dir_all(partial_entry.path())
+                        .await
+                        .map_err(Error::CacheWrite)?;
+                }
+
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
                 }
@@ -1043,6 +1205,73 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
+    /// Download a wheel with a [`ResumableDownload`], and verify it against the digests provided
+    /// by the index.
+    ///
+    /// Returns the downloaded file, along with its computed hashes.
+    async fn download_resumable(
+        &self,
+        resumable: &ResumableDownload,
+        response: reqwest::Response,
+        partial_entry: &CacheEntry,
+        progress: Option<(&dyn Reporter, usize)>,
+        index_hashes: &[HashDigest],
+        dist: &BuiltDist,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(fs_err::tokio::File, HashDigests), Error> {
+        let on_progress = |bytes| {
+            if let Some((reporter, progress)) = progress {
+                reporter.on_download_progress(progress, bytes);
+            }
+        };
+        let path = resumable
+            .download(
+                self.client.unmanaged.cached_client().uncached(),
+                response,
+                partial_entry.path(),
+                NonZeroUsize::new(self.build_context.concurrency().download_ranges)
+                    .unwrap_or(NonZeroUsize::MIN),
+                &on_progress,
+            )
+            .await?;
+
+        // Hash the complete file, since some of it may have been downloaded by a previous attempt.
+        let mut algorithms = http_hash_algorithms(hashes);
+        algorithms.extend(index_hashes.iter().map(|digest| digest.algorithm));
+        algorithms.sort();
+        algorithms.dedup();
+        let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+        let mut file = fs_err::tokio::File::open(&path)
+            .await
+            .map_err(Error::CacheRead)?;
+        uv_extract::hash::HashReader::new(&mut file, &mut hashers)
+            .finish()
+            .await
+            .map_err(Error::HashExhaustion)?;
+        let computed = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+
+        if !index_hashes
+            .iter()
+            .all(|expected| computed.contains(expected))
+        {
+            // Discard the partial files, such that the next attempt starts from scratch.
+            drop(file);
+            fs_err::tokio::remove_dir_all(partial_entry.path())
+                .await
+                .map_err(Error::CacheWrite)?;
+            return Err(Error::hash_mismatch(
+                dist.to_string(),
+                index_hashes,
+                &computed,
+            ));
+        }
+
+        Ok((file, HashDigests::from(computed)))
+    }
+
     /// Load a wheel from a local path.
     async fn load_wheel(
         &self,
@@ -1284,6 +1513,189 @@ impl<'a> ManagedClient<'a> {
     }
 }
 
+/// An archive to download with an [`ArchiveDownloader`].
+#[derive(Debug, Clone)]
+pub struct RemoteArchive<'a> {
+    /// The name of the package that the archive belongs to.
+    pub name: &'a PackageName,
+    /// The distribution to report in errors, e.g., `anyio==4.3.0`.
+    pub distribution: String,
+    /// The (possibly `file://`) URL of the archive.
+    pub url: DisplaySafeUrl,
+    /// The filename of the archive.
+    pub filename: String,
+    /// The size of the archive, in bytes, if known.
+    pub size: Option<u64>,
+}
+
+/// Downloads the original archives for distributions (i.e., the wheel or source distribution
+/// files, as published) into a directory, rather than unpacking them into the cache.
+pub struct ArchiveDownloader<'a> {
+    client: ManagedClient<'a>,
+    reporter: Option<Arc<dyn Reporter>>,
+}
+
+impl<'a> ArchiveDownloader<'a> {
+    pub fn new(client: &'a RegistryClient, downloads_semaphore: Arc<Semaphore>) -> Self {
+        Self {
+            client: ManagedClient::new(client, downloads_semaphore),
+            reporter: None,
+        }
+    }
+
+    /// Set the [`Reporter`] to use for the [`ArchiveDownloader`].
+    #[must_use]
+    pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
+        Self {
+            reporter: Some(reporter),
+            ..self
+        }
+    }
+
+    /// Download an archive into the given directory, enforcing the given hash policy.
+    ///
+    /// The archive is written to a temporary file, and only moved into place once its hashes have
+    /// been validated.
+    pub async fn download(
+        &self,
+        archive: &RemoteArchive<'_>,
+        directory: &Path,
+        hashes: HashPolicy<'_>,
+    ) -> Result<PathBuf, Error> {
+        let RemoteArchive {
+            name,
+            distribution,
+            url,
+            filename,
+            size,
+        } = archive;
+        let target = directory.join(filename);
+
+        // If the archive was already downloaded, and there are no hashes to validate, reuse it.
+        if !hashes.requires_validation() && target.is_file() {
+            debug!("Archive already exists at: {}", target.user_display());
+            return Ok(target);
+        }
+
+        // Stream the archive to a temporary file in the target directory.
+        let temp_file = tempfile::NamedTempFile::new_in(directory)
+            .map_err(|err| Error::WriteArchive(directory.to_path_buf(), err))?;
+        let digests = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| Error::NonFileUrl(url.clone()))?;
+            let reader = fs_err::tokio::File::open(&path)
+                .await
+                .map_err(|err| Error::ReadArchive(path.clone(), err))?;
+            write_archive(reader, temp_file.path(), hashes, None).await?
+        } else {
+            let response = self
+                .client
+                .managed(async |client| {
+                    client
+                        .uncached_client(url)
+                        .get(Url::from(url.clone()))
+                        .header(
+                            "accept-encoding",
+                            reqwest::header::HeaderValue::from_static("identity"),
+                        )
+                        .send()
+                        .await
+                        .map_err(Error::from)?
+                        .error_for_status()
+                        .map_err(Error::from)
+                })
+                .await?;
+            let size = size.or_else(|| content_length(&response));
+            let progress = self
+                .reporter
+                .as_ref()
+                .map(|reporter| (&**reporter, reporter.on_download_start(name, size)));
+            let reader = response
+                .bytes_stream()
+                .map_err(|err| handle_response_errors(self.client.unmanaged, err))
+                .into_async_read()
+                .compat();
+            let digests = write_archive(reader, temp_file.path(), hashes, progress).await?;
+            if let Some((reporter, progress)) = progress {
+                reporter.on_download_complete(name, progress);
+            }
+            digests
+        };
+
+        // Validate the hashes before making the archive visible.
+        if !digests.satisfies(hashes) {
+            return Err(Error::hash_mismatch(
+                distribution.clone(),
+                hashes.digests(),
+                &digests,
+            ));
+        }
+
+        temp_file
+            .persist(&target)
+            .map_err(|err| Error::WriteArchive(target.clone(), err.error))?;
+
+        Ok(target)
+    }
+}
+
+/// Handle a specific `reqwest` error, and convert it to [`io::Error`].
+fn handle_response_errors(client: &RegistryClient, err: reqwest::Error) -> io::Error {
+    if err.is_timeout() {
+        // Assumption: The connect timeout with the 10s default is not the culprit.
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",
+                client.read_timeout().as_secs()
+            ),
+        )
+    } else {
+        io::Error::other(err)
+    }
+}
+
+/// Write an archive from the given reader to a file, returning the computed hashes.
+async fn write_archive(
+    reader: impl AsyncRead + Unpin,
+    path: &Path,
+    hashes: HashPolicy<'_>,
+    progress: Option<(&dyn Reporter, usize)>,
+) -> Result<Vec<HashDigest>, Error> {
+    let algorithms = http_hash_algorithms(hashes);
+    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+    let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
+
+    let mut writer = tokio::io::BufWriter::new(
+        fs_err::tokio::File::create(path)
+            .await
+            .map_err(|err| Error::WriteArchive(path.to_path_buf(), err))?,
+    );
+    match progress {
+        Some((reporter, progress)) => {
+            let mut reader = ProgressReader::new(&mut hasher, progress, reporter);
+            tokio::io::copy(&mut reader, &mut writer)
+                .await
+                .map_err(|err| Error::WriteArchive(path.to_path_buf(), err))?;
+        }
+        None => {
+            tokio::io::copy(&mut hasher, &mut writer)
+                .await
+                .map_err(|err| Error::WriteArchive(path.to_path_buf(), err))?;
+        }
+    }
+    writer
+        .flush()
+        .await
+        .map_err(|err| Error::WriteArchive(path.to_path_buf(), err))?;
+
+    // Exhaust the reader to compute the hashes.
+    hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+    Ok(hashers.into_iter().map(HashDigest::from).collect())
+}
+
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
 fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
@@ -146,6 +146,12 @@ pub enum Error {
     MetadataLowering(#[from] MetadataError),
     #[error("Distribution not found at: {0}")]
     NotFound(DisplaySafeUrl),
+    #[error(transparent)]
+    Distribution(#[from] uv_distribution_types::Error),
+    #[error("Failed to read archive: `{}`", _0.user_display())]
+    ReadArchive(PathBuf, #[source] std::io::Error),
+    #[error("Failed to write archive to: `{}`", _0.user_display())]
+    WriteArchive(PathBuf, #[source] std::io::Error),
     #[error("Attempted to re-extract the source distribution for `{}`, but the {} hash didn't match. Run `{}` to clear the cache.", _0, _1, "uv cache clean".green())]
     CacheHeal(String, HashAlgorithm),
     #[error("The source distribution requires Python {0}, but {1} is installed")]
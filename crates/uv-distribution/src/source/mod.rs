@@ -24,6 +24,7 @@ use url::Url;
 use uv_auth::CredentialsCache;
 use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
+use uv_cache_key::{CanonicalUrl, RepositoryUrl, cache_digest};
 use uv_client::{
     BaseClientBuilder, CacheControl, CachedClientError, Connectivity, DataWithCachePolicy,
     RegistryClient,
@@ -46,6 +47,7 @@ use uv_platform_tags::Tags;
 use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
+use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::ToolUvSources;
 
 use crate::distribution_database::ManagedClient;
@@ -53,10 +55,12 @@ use crate::error::Error;
 use crate::hash::http_hash_algorithms;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
+use crate::source::remote::{RemoteWheels, file_digest};
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Access distribution metadata without requiring a build interpreter.
@@ -295,6 +299,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                             &cache_shard,
                             tags,
                             hashes,
+                            client,
                         )
                         .boxed_local()
                         .await;
@@ -367,6 +372,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     &cache_shard,
                     tags,
                     hashes,
+                    client,
                 )
                 .boxed_local()
                 .await?
@@ -412,7 +418,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(resource.url).root(),
                 );
-                self.archive(source, resource, &cache_shard, tags, hashes)
+                self.archive(source, resource, &cache_shard, tags, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -686,6 +692,45 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // Otherwise, check the remote cache for a compatible wheel. Remote archives are keyed by
+        // their contents in addition to their URL, since the archive at a URL may change.
+        let remote = revision
+            .hashes()
+            .iter()
+            .find(|digest| digest.algorithm() == HashAlgorithm::Sha256)
+            .and_then(|digest| {
+                RemoteWheels::new(
+                    self.build_context.cache(),
+                    cache_digest(&(
+                        CanonicalUrl::new(url),
+                        &*digest.digest,
+                        subdirectory
+                            .map(|subdirectory| subdirectory.portable_display().to_string()),
+                        build_info.cache_shard(),
+                    )),
+                )
+            });
+        if let Some(remote) = remote.as_ref()
+            && let Some((disk_filename, wheel_filename, metadata)) = self
+                .fetch_remote_wheel(source, remote, tags, &cache_shard, client)
+                .await
+        {
+            // Store the metadata.
+            let metadata_entry = cache_shard.entry(METADATA);
+            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            return Ok(BuiltWheelMetadata {
+                path: cache_shard.join(&disk_filename).into_boxed_path(),
+                target: cache_shard.join(wheel_filename.stem()).into_boxed_path(),
+                filename: wheel_filename,
+                hashes: revision.into_hashes(),
+                cache_info,
+                build_info,
+            });
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -735,6 +780,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel with other machines.
+        if let Some(remote) = remote.as_ref() {
+            self.store_remote_wheel(source, remote, tags, &cache_shard, &disk_filename, client)
+                .await;
+        }
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -1039,6 +1090,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
@@ -1089,6 +1141,46 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // Otherwise, check the remote cache for a compatible wheel. Local archives are keyed by
+        // their contents, since the same archive may be stored at different paths across machines.
+        let remote = if self.build_context.cache().remote().is_some() {
+            match file_digest(&resource.path).await {
+                Ok(digest) => RemoteWheels::new(
+                    self.build_context.cache(),
+                    cache_digest(&(digest, build_info.cache_shard())),
+                ),
+                Err(err) => {
+                    debug!(
+                        "Failed to hash `{}` for the remote cache: {err}",
+                        resource.path.user_display()
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        if let Some(remote) = remote.as_ref()
+            && let Some((disk_filename, filename, metadata)) = self
+                .fetch_remote_wheel(source, remote, tags, &cache_shard, client)
+                .await
+        {
+            // Store the metadata.
+            let metadata_entry = cache_shard.entry(METADATA);
+            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            return Ok(BuiltWheelMetadata {
+                path: cache_shard.join(&disk_filename).into_boxed_path(),
+                target: cache_shard.join(filename.stem()).into_boxed_path(),
+                filename,
+                hashes: revision.into_hashes(),
+                cache_info,
+                build_info,
+            });
+        }
+
         // Otherwise, we need to build a wheel, which requires a source distribution.
         let revision = if source_entry.path().is_dir() {
             revision
@@ -1118,6 +1210,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel with other machines.
+        if let Some(remote) = remote.as_ref() {
+            self.store_remote_wheel(source, remote, tags, &cache_shard, &disk_filename, client)
+                .await;
+        }
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -2143,6 +2241,38 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // Otherwise, check the remote cache for a compatible wheel.
+        let remote = RemoteWheels::new(
+            self.build_context.cache(),
+            cache_digest(&(
+                RepositoryUrl::new(resource.git.url()),
+                git_sha.as_str(),
+                resource
+                    .subdirectory
+                    .map(|subdirectory| subdirectory.portable_display().to_string()),
+                build_info.cache_shard(),
+            )),
+        );
+        if let Some(remote) = remote.as_ref()
+            && let Some((disk_filename, filename, metadata)) = self
+                .fetch_remote_wheel(source, remote, tags, &cache_shard, client)
+                .await
+        {
+            // Store the metadata.
+            write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            return Ok(BuiltWheelMetadata {
+                path: cache_shard.join(&disk_filename).into_boxed_path(),
+                target: cache_shard.join(filename.stem()).into_boxed_path(),
+                filename,
+                hashes,
+                cache_info,
+                build_info,
+            });
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -2164,6 +2294,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the wheel with other machines.
+        if let Some(remote) = remote.as_ref() {
+            self.store_remote_wheel(source, remote, tags, &cache_shard, &disk_filename, client)
+                .await;
+        }
+
         // Store the metadata.
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
             .await
@@ -2926,6 +3062,97 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
     }
 
+    /// Fetch a wheel built from the source distribution from the remote cache, and move it into
+    /// the given cache shard.
+    ///
+    /// Failures are reported as warnings, since the wheel can always be built locally instead.
+    async fn fetch_remote_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        remote: &RemoteWheels<'_>,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+        client: &ManagedClient<'_>,
+    ) -> Option<(String, WheelFilename, ResolutionMetadata)> {
+        let temp_dir = match self.build_context.cache().build_dir() {
+            Ok(temp_dir) => temp_dir,
+            Err(err) => {
+                debug!("Failed to create a temporary directory for the remote cache: {err}");
+                return None;
+            }
+        };
+
+        let (path, filename) = match client
+            .managed(async |client| remote.fetch(tags, temp_dir.path(), client).await)
+            .await
+        {
+            Ok(Some(wheel)) => wheel,
+            Ok(None) => return None,
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to fetch a built wheel for `{source}` from the remote cache: {err}"
+                );
+                return None;
+            }
+        };
+
+        // Validate the wheel, as we would for a local build, and move it into the cache.
+        let result = async {
+            let metadata = read_wheel_metadata(&filename, &path)?;
+            validate_metadata(source, &metadata)?;
+            validate_filename(&filename, &metadata)?;
+
+            let disk_filename = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .expect("Wheel filename to be valid UTF-8")
+                .to_string();
+            fs::create_dir_all(&cache_shard)
+                .await
+                .map_err(Error::CacheWrite)?;
+            rename_with_retry(&path, cache_shard.join(&disk_filename))
+                .await
+                .map_err(Error::CacheWrite)?;
+
+            Ok::<_, Error>((disk_filename, metadata))
+        }
+        .await;
+
+        match result {
+            Ok((disk_filename, metadata)) => {
+                debug!("Fetched built wheel from remote cache for: {source}");
+                Some((disk_filename, filename, metadata))
+            }
+            Err(err) => {
+                warn_user_once!("Ignoring invalid wheel for `{source}` in the remote cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Upload a built wheel to the remote cache.
+    ///
+    /// Failures are reported as warnings, since the wheel is already available locally.
+    async fn store_remote_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        remote: &RemoteWheels<'_>,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+        disk_filename: &str,
+        client: &ManagedClient<'_>,
+    ) {
+        let path = cache_shard.join(disk_filename);
+        if let Err(err) = client
+            .managed(async |client| remote.store(disk_filename, &path, tags, client).await)
+            .await
+        {
+            warn_user_once!(
+                "Failed to upload the built wheel for `{source}` to the remote cache: {err}"
+            );
+        }
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
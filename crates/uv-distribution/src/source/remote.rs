@@ -0,0 +1,436 @@
+//! Read and write built wheels in a shared [`RemoteCache`].
+//!
+//! Each entry in the remote cache is keyed by a digest of the source distribution and its build
+//! settings, and contains the wheels built for each platform, stored by their SHA-256 digest,
+//! alongside a manifest per platform, e.g.:
+//!
+//! ```text
+//! sdists-v9/
+//!   7a0e1b5f4c1d2e3f/
+//!     cp312-cp312-manylinux_2_39_x86_64.json
+//!     cp313-cp313-macosx_14_0_arm64.json
+//!     0c4a8f4ad5e6d1b2....whl
+//!     5f2b9d1e3a7c8e4f....whl
+//! ```
+//!
+//! Each manifest is named after the highest-priority tags of the platform that built the wheel,
+//! such that it can be found without a directory listing (which plain HTTP servers don't
+//! support). Pure Python wheels are recorded under a `py3-none-any.json` manifest instead, to share
+//! them across platforms.
+//!
+//! No file is ever modified by more than one platform, and wheels are written before the manifests
+//! that refer to them, so concurrent writers can't drop each other's wheels or leave a manifest
+//! that refers to a different wheel.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use futures::TryStreamExt;
+use reqwest::header::CONTENT_LENGTH;
+use reqwest::{Body, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tokio_util::io::ReaderStream;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, RemoteCache, RemoteCacheEntry};
+use uv_client::{Connectivity, RegistryClient, WrappedReqwestError};
+use uv_distribution_filename::WheelFilename;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{copy_atomic_sync, write_atomic};
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag, Tags};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// The name of the manifest for pure Python wheels, which are shared across platforms.
+const UNIVERSAL_MANIFEST: &str = "py3-none-any.json";
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RemoteCacheError {
+    #[error("Failed to read from the remote cache: `{0}`")]
+    Read(RemoteCacheEntry, #[source] std::io::Error),
+    #[error("Failed to write to the remote cache: `{0}`")]
+    Write(RemoteCacheEntry, #[source] std::io::Error),
+    #[error("Request to the remote cache failed: `{0}`")]
+    Request(RemoteCacheEntry, #[source] WrappedReqwestError),
+    #[error("Failed to parse the remote cache manifest: `{0}`")]
+    Manifest(RemoteCacheEntry, #[source] serde_json::Error),
+    #[error("Hash mismatch for `{entry}`: expected `{expected}`, but found `{actual}`")]
+    HashMismatch {
+        entry: RemoteCacheEntry,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// The manifest for a wheel stored in an entry in the remote cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Manifest {
+    /// The filename of the wheel, as built.
+    filename: String,
+    /// The SHA-256 digest of the wheel, which also determines its location in the entry.
+    sha256: String,
+}
+
+/// An entry in the [`RemoteCache`] for the wheels built from a single source distribution.
+#[derive(Debug)]
+pub(crate) struct RemoteWheels<'a> {
+    remote: &'a RemoteCache,
+    key: String,
+}
+
+impl<'a> RemoteWheels<'a> {
+    /// Return the entry for the given key, if a remote cache is configured.
+    pub(crate) fn new(cache: &'a Cache, key: String) -> Option<Self> {
+        Some(Self {
+            remote: cache.remote()?,
+            key,
+        })
+    }
+
+    fn entry(&self, file: &str) -> RemoteCacheEntry {
+        self.remote
+            .entry(CacheBucket::SourceDistributions, &self.key, file)
+    }
+
+    /// Download a wheel that's compatible with the given tags into `directory`, returning its
+    /// path and parsed filename.
+    pub(crate) async fn fetch(
+        &self,
+        tags: &Tags,
+        directory: &Path,
+        client: &RegistryClient,
+    ) -> Result<Option<(PathBuf, WheelFilename)>, RemoteCacheError> {
+        if !self.is_available(client) {
+            return Ok(None);
+        }
+
+        // Look for a wheel built on the same platform, then for a pure Python wheel.
+        for name in platform_manifest(tags)
+            .into_iter()
+            .chain([UNIVERSAL_MANIFEST.to_string()])
+        {
+            let entry = self.entry(&name);
+            let Some(manifest) = self.manifest(&entry, client).await? else {
+                debug!("No remote cache entry at: {entry}");
+                continue;
+            };
+            let Some(filename) = WheelFilename::from_str(&manifest.filename)
+                .ok()
+                .filter(|filename| filename.is_compatible(tags))
+            else {
+                debug!("No compatible wheel in remote cache entry: {entry}");
+                continue;
+            };
+
+            let entry = self.entry(&wheel_file(&manifest.sha256));
+            debug!("Downloading wheel from remote cache: {entry}");
+
+            // Stream the wheel to a temporary file in the target directory.
+            let temp_file = tempfile::NamedTempFile::new_in(directory)
+                .map_err(|err| RemoteCacheError::Read(entry.clone(), err))?;
+            let Some(actual) = self.download(&entry, temp_file.path(), client).await? else {
+                continue;
+            };
+
+            // Verify the wheel against the manifest, to guard against partial or corrupted
+            // uploads.
+            if actual != manifest.sha256 {
+                return Err(RemoteCacheError::HashMismatch {
+                    entry,
+                    expected: manifest.sha256,
+                    actual,
+                });
+            }
+
+            let path = directory.join(&manifest.filename);
+            temp_file
+                .persist(&path)
+                .map_err(|err| RemoteCacheError::Read(entry, err.error))?;
+
+            return Ok(Some((path, filename)));
+        }
+
+        Ok(None)
+    }
+
+    /// Upload a wheel built on the platform described by the given tags to the remote cache, and
+    /// record it in the platform's manifest.
+    pub(crate) async fn store(
+        &self,
+        filename: &str,
+        wheel: &Path,
+        tags: &Tags,
+        client: &RegistryClient,
+    ) -> Result<(), RemoteCacheError> {
+        if !self.is_available(client) {
+            return Ok(());
+        }
+
+        let name =
+            if WheelFilename::from_str(filename).is_ok_and(|filename| is_universal(&filename)) {
+                UNIVERSAL_MANIFEST.to_string()
+            } else if let Some(name) = platform_manifest(tags) {
+                name
+            } else {
+                return Ok(());
+            };
+
+        let reader = fs_err::tokio::File::open(wheel)
+            .await
+            .map_err(|err| RemoteCacheError::Read(self.entry(filename), err))?;
+        let sha256 = sha256(reader)
+            .await
+            .map_err(|err| RemoteCacheError::Read(self.entry(filename), err))?;
+
+        // Upload the wheel before the manifest, such that the manifest never refers to a missing
+        // wheel.
+        let entry = self.entry(&wheel_file(&sha256));
+        debug!("Uploading wheel to remote cache: {entry}");
+        self.upload(&entry, wheel, client).await?;
+
+        let entry = self.entry(&name);
+        let manifest = Manifest {
+            filename: filename.to_string(),
+            sha256,
+        };
+        let contents = serde_json::to_vec_pretty(&manifest)
+            .map_err(|err| RemoteCacheError::Manifest(entry.clone(), err))?;
+        self.write(&entry, contents, client).await?;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the remote cache can be reached with the given client.
+    fn is_available(&self, client: &RegistryClient) -> bool {
+        match self.remote {
+            RemoteCache::Directory(_) => true,
+            RemoteCache::Url(_) => !matches!(client.connectivity(), Connectivity::Offline),
+        }
+    }
+
+    /// Read a manifest from the entry, if it exists.
+    async fn manifest(
+        &self,
+        entry: &RemoteCacheEntry,
+        client: &RegistryClient,
+    ) -> Result<Option<Manifest>, RemoteCacheError> {
+        let Some(contents) = self.read(entry, client).await? else {
+            return Ok(None);
+        };
+        let manifest = serde_json::from_slice(&contents)
+            .map_err(|err| RemoteCacheError::Manifest(entry.clone(), err))?;
+        Ok(Some(manifest))
+    }
+
+    /// Read a file from the remote cache, returning `None` if it doesn't exist.
+    async fn read(
+        &self,
+        entry: &RemoteCacheEntry,
+        client: &RegistryClient,
+    ) -> Result<Option<Vec<u8>>, RemoteCacheError> {
+        match entry {
+            RemoteCacheEntry::Path(path) => match fs_err::tokio::read(path).await {
+                Ok(contents) => Ok(Some(contents)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(RemoteCacheError::Read(entry.clone(), err)),
+            },
+            RemoteCacheEntry::Url(url) => {
+                let request = async {
+                    let response = client.uncached_client(url).get(url.as_str()).send().await?;
+                    if response.status() == StatusCode::NOT_FOUND {
+                        return Ok(None);
+                    }
+                    let response = response.error_for_status()?;
+                    Ok(Some(response.bytes().await?.to_vec()))
+                };
+                request.await.map_err(|err: WrappedReqwestError| {
+                    RemoteCacheError::Request(entry.clone(), err)
+                })
+            }
+        }
+    }
+
+    /// Stream a file from the remote cache to `target`, returning its SHA-256 digest, or `None`
+    /// if it doesn't exist.
+    async fn download(
+        &self,
+        entry: &RemoteCacheEntry,
+        target: &Path,
+        client: &RegistryClient,
+    ) -> Result<Option<String>, RemoteCacheError> {
+        match entry {
+            RemoteCacheEntry::Path(path) => {
+                let reader = match fs_err::tokio::File::open(path).await {
+                    Ok(reader) => reader,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                    Err(err) => return Err(RemoteCacheError::Read(entry.clone(), err)),
+                };
+                let digest = copy_with_digest(reader, target)
+                    .await
+                    .map_err(|err| RemoteCacheError::Read(entry.clone(), err))?;
+                Ok(Some(digest))
+            }
+            RemoteCacheEntry::Url(url) => {
+                let response = async {
+                    let response = client.uncached_client(url).get(url.as_str()).send().await?;
+                    if response.status() == StatusCode::NOT_FOUND {
+                        return Ok(None);
+                    }
+                    Ok(Some(response.error_for_status()?))
+                };
+                let Some(response) = response.await.map_err(|err: WrappedReqwestError| {
+                    RemoteCacheError::Request(entry.clone(), err)
+                })?
+                else {
+                    return Ok(None);
+                };
+                let reader = response
+                    .bytes_stream()
+                    .map_err(std::io::Error::other)
+                    .into_async_read()
+                    .compat();
+                let digest = copy_with_digest(reader, target)
+                    .await
+                    .map_err(|err| RemoteCacheError::Read(entry.clone(), err))?;
+                Ok(Some(digest))
+            }
+        }
+    }
+
+    /// Stream the file at `source` to the remote cache.
+    async fn upload(
+        &self,
+        entry: &RemoteCacheEntry,
+        source: &Path,
+        client: &RegistryClient,
+    ) -> Result<(), RemoteCacheError> {
+        match entry {
+            RemoteCacheEntry::Path(path) => {
+                if let Some(parent) = path.parent() {
+                    fs_err::tokio::create_dir_all(parent)
+                        .await
+                        .map_err(|err| RemoteCacheError::Write(entry.clone(), err))?;
+                }
+                let (source, path) = (source.to_path_buf(), path.clone());
+                tokio::task::spawn_blocking(move || copy_atomic_sync(source, path))
+                    .await
+                    .map_err(|err| RemoteCacheError::Write(entry.clone(), err.into()))?
+                    .map_err(|err| RemoteCacheError::Write(entry.clone(), err))
+            }
+            RemoteCacheEntry::Url(url) => {
+                let file = fs_err::tokio::File::open(source)
+                    .await
+                    .map_err(|err| RemoteCacheError::Read(entry.clone(), err))?;
+                let size = file
+                    .metadata()
+                    .await
+                    .map_err(|err| RemoteCacheError::Read(entry.clone(), err))?
+                    .len();
+                let request = async {
+                    client
+                        .uncached_client(url)
+                        .raw_client()
+                        .put(url.as_str())
+                        .header(CONTENT_LENGTH, size)
+                        .body(Body::wrap_stream(ReaderStream::new(file)))
+                        .send()
+                        .await?
+                        .error_for_status()?;
+                    Ok(())
+                };
+                request.await.map_err(|err: WrappedReqwestError| {
+                    RemoteCacheError::Request(entry.clone(), err)
+                })
+            }
+        }
+    }
+
+    /// Write a file to the remote cache.
+    async fn write(
+        &self,
+        entry: &RemoteCacheEntry,
+        contents: Vec<u8>,
+        client: &RegistryClient,
+    ) -> Result<(), RemoteCacheError> {
+        match entry {
+            RemoteCacheEntry::Path(path) => {
+                if let Some(parent) = path.parent() {
+                    fs_err::tokio::create_dir_all(parent)
+                        .await
+                        .map_err(|err| RemoteCacheError::Write(entry.clone(), err))?;
+                }
+                write_atomic(path, contents)
+                    .await
+                    .map_err(|err| RemoteCacheError::Write(entry.clone(), err))
+            }
+            RemoteCacheEntry::Url(url) => {
+                let request = async {
+                    client
+                        .uncached_client(url)
+                        .raw_client()
+                        .put(url.as_str())
+                        .body(contents)
+                        .send()
+                        .await?
+                        .error_for_status()?;
+                    Ok(())
+                };
+                request.await.map_err(|err: WrappedReqwestError| {
+                    RemoteCacheError::Request(entry.clone(), err)
+                })
+            }
+        }
+    }
+}
+
+/// Return the name of the manifest for wheels built on the platform described by the given tags.
+fn platform_manifest(tags: &Tags) -> Option<String> {
+    Some(format!(
+        "{}-{}-{}.json",
+        tags.python_tag()?,
+        tags.abi_tag()?,
+        tags.platform_tag()?
+    ))
+}
+
+/// Returns `true` if the wheel is compatible with any Python 3 interpreter on any platform.
+fn is_universal(filename: &WheelFilename) -> bool {
+    filename.python_tags().contains(&LanguageTag::Python {
+        major: 3,
+        minor: None,
+    }) && filename.abi_tags().iter().all(|abi| *abi == AbiTag::None)
+        && filename.platform_tags().iter().all(PlatformTag::is_any)
+}
+
+/// Return the name of the file that stores the wheel with the given SHA-256 digest.
+fn wheel_file(sha256: &str) -> String {
+    format!("{sha256}.whl")
+}
+
+/// Compute the SHA-256 digest of a local file, e.g., a source distribution archive.
+pub(crate) async fn file_digest(path: &Path) -> Result<String, std::io::Error> {
+    let file = fs_err::tokio::File::open(path).await?;
+    sha256(file).await
+}
+
+/// Copy the given reader to `target`, returning the SHA-256 digest of its contents.
+async fn copy_with_digest(
+    reader: impl tokio::io::AsyncRead + Unpin,
+    target: &Path,
+) -> Result<String, std::io::Error> {
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    let mut reader = HashReader::new(reader, &mut hashers);
+    let mut writer = fs_err::tokio::File::create(target).await?;
+    tokio::io::copy(&mut reader, &mut writer).await?;
+    tokio::io::AsyncWriteExt::flush(&mut writer).await?;
+    let digest = HashDigest::from(hashers.remove(0));
+    Ok(digest.digest.to_string())
+}
+
+/// Compute the SHA-256 digest of the given reader.
+async fn sha256(reader: impl tokio::io::AsyncRead + Unpin) -> Result<String, std::io::Error> {
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    HashReader::new(reader, &mut hashers).finish().await?;
+    let digest = HashDigest::from(hashers.remove(0));
+    Ok(digest.digest.to_string())
+}
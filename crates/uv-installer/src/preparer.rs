@@ -1,4 +1,5 @@
 use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use futures::{FutureExt, Stream, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
@@ -106,22 +107,59 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
 
         Ok(wheels)
     }
-    /// Download, build, and unzip a single wheel.
-    #[instrument(skip_all, fields(name = % dist, size = ? dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
-    async fn get_wheel(
+
+    /// Download the original archives for a set of distributions into the given directory,
+    /// without building or unzipping them.
+    ///
+    /// Returns the path to each downloaded archive, or `None` for distributions that aren't backed
+    /// by an archive (like Git repositories and local directories).
+    #[instrument(skip_all, fields(total = distributions.len()))]
+    pub async fn download(
         &self,
-        dist: Dist,
-        in_flight: &InFlight,
+        mut distributions: Vec<Arc<Dist>>,
+        directory: &Path,
         resolution: &Resolution,
-    ) -> Result<CachedDist, Error> {
-        // Validate that the distribution is compatible with the build options.
+    ) -> Result<Vec<DownloadedArchive>, Error> {
+        // Sort the distributions by size.
+        distributions
+            .sort_unstable_by_key(|distribution| Reverse(distribution.size().unwrap_or(u64::MAX)));
+
+        let archives = distributions
+            .into_iter()
+            .map(async |dist| {
+                // Downloading an archive never builds it, so the build options only affect which
+                // file is selected during resolution.
+                let path = self
+                    .database
+                    .download_archive(&dist, directory, self.hashes.get(&*dist))
+                    .boxed_local()
+                    .await
+                    .map_err(|err| Error::from_dist((*dist).clone(), err, resolution))?;
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_archive(&dist);
+                }
+                Ok::<_, Error>((dist, path))
+            })
+            .collect::<FuturesUnordered<_>>()
+            .try_collect()
+            .await?;
+
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_complete();
+        }
+
+        Ok(archives)
+    }
+
+    /// Validate that the distribution is compatible with the build options.
+    fn validate_build_options(&self, dist: &Dist) -> Result<(), Error> {
         match dist {
-            Dist::Built(ref dist) => {
+            Dist::Built(dist) => {
                 if self.build_options.no_binary_package(dist.name()) {
                     return Err(Error::NoBinary(dist.name().clone()));
                 }
             }
-            Dist::Source(ref dist) => {
+            Dist::Source(dist) => {
                 if self.build_options.no_build_package(dist.name()) {
                     if dist.is_editable() {
                         debug!("Allowing build for editable source distribution: {dist}");
@@ -131,6 +169,18 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                 }
             }
         }
+        Ok(())
+    }
+
+    /// Download, build, and unzip a single wheel.
+    #[instrument(skip_all, fields(name = % dist, size = ? dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
+    async fn get_wheel(
+        &self,
+        dist: Dist,
+        in_flight: &InFlight,
+        resolution: &Resolution,
+    ) -> Result<CachedDist, Error> {
+        self.validate_build_options(&dist)?;
 
         let id = dist.distribution_id();
         if let Some(result) = in_flight.downloads.register_or_wait(&id).await {
@@ -204,6 +254,9 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
     }
 }
 
+/// A distribution, along with the path to its downloaded archive, if any.
+type DownloadedArchive = (Arc<Dist>, Option<PathBuf>);
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Building source distributions is disabled, but attempted to build `{0}`")]
@@ -242,6 +295,10 @@ pub trait Reporter: Send + Sync {
     /// if necessary, built.
     fn on_progress(&self, dist: &CachedDist);
 
+    /// Callback to invoke when an archive is downloaded to an output directory, without being
+    /// unzipped.
+    fn on_archive(&self, dist: &Dist);
+
     /// Callback to invoke when the operation is complete.
     fn on_complete(&self);
 
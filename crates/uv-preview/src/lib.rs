@@ -261,6 +261,20 @@ pub enum PreviewFeature {
     CentralizedProjectEnvs = 1 << 35,
     ToolInstallLocks = 1 << 36,
     WorkspaceListScripts = 1 << 37,
+    Why = 1 << 38,
+    LockDiff = 1 << 39,
+    LockMergeDriver = 1 << 40,
+    PipDownload = 1 << 41,
+    ExportBundle = 1 << 42,
+    IndexServe = 1 << 43,
+    CacheEviction = 1 << 44,
+    CacheInspect = 1 << 45,
+    RemoteCache = 1 << 46,
+    ResumableDownloads = 1 << 47,
+    Tasks = 1 << 48,
+    RunAllPackages = 1 << 49,
+    RunWatch = 1 << 50,
+    Outdated = 1 << 51,
 }
 
 impl PreviewFeature {
@@ -305,6 +319,20 @@ impl PreviewFeature {
             Self::CentralizedProjectEnvs => "centralized-project-envs",
             Self::ToolInstallLocks => "tool-install-locks",
             Self::WorkspaceListScripts => "workspace-list-scripts",
+            Self::Why => "why-command",
+            Self::LockDiff => "lock-diff",
+            Self::LockMergeDriver => "lock-merge-driver",
+            Self::PipDownload => "pip-download",
+            Self::ExportBundle => "export-bundle",
+            Self::IndexServe => "index-serve",
+            Self::CacheEviction => "cache-eviction",
+            Self::CacheInspect => "cache-inspect",
+            Self::RemoteCache => "remote-cache",
+            Self::ResumableDownloads => "resumable-downloads",
+            Self::Tasks => "tasks",
+            Self::RunAllPackages => "run-all-packages",
+            Self::RunWatch => "run-watch",
+            Self::Outdated => "outdated-command",
         }
     }
 }
@@ -362,6 +390,20 @@ impl FromStr for PreviewFeature {
             "centralized-project-envs" => Self::CentralizedProjectEnvs,
             "tool-install-locks" => Self::ToolInstallLocks,
             "workspace-list-scripts" => Self::WorkspaceListScripts,
+            "why-command" => Self::Why,
+            "lock-diff" => Self::LockDiff,
+            "lock-merge-driver" => Self::LockMergeDriver,
+            "pip-download" => Self::PipDownload,
+            "export-bundle" => Self::ExportBundle,
+            "index-serve" => Self::IndexServe,
+            "cache-eviction" => Self::CacheEviction,
+            "cache-inspect" => Self::CacheInspect,
+            "remote-cache" => Self::RemoteCache,
+            "resumable-downloads" => Self::ResumableDownloads,
+            "tasks" => Self::Tasks,
+            "run-all-packages" => Self::RunAllPackages,
+            "run-watch" => Self::RunWatch,
+            "outdated-command" => Self::Outdated,
             _ => return Err(PreviewFeatureParseError),
         })
     }
@@ -668,6 +710,26 @@ mod tests {
             PreviewFeature::WorkspaceListScripts.as_str(),
             "workspace-list-scripts"
         );
+        assert_eq!(PreviewFeature::Why.as_str(), "why-command");
+        assert_eq!(PreviewFeature::LockDiff.as_str(), "lock-diff");
+        assert_eq!(PreviewFeature::PipDownload.as_str(), "pip-download");
+        assert_eq!(PreviewFeature::ExportBundle.as_str(), "export-bundle");
+        assert_eq!(PreviewFeature::IndexServe.as_str(), "index-serve");
+        assert_eq!(PreviewFeature::CacheEviction.as_str(), "cache-eviction");
+        assert_eq!(PreviewFeature::CacheInspect.as_str(), "cache-inspect");
+        assert_eq!(PreviewFeature::RemoteCache.as_str(), "remote-cache");
+        assert_eq!(PreviewFeature::Tasks.as_str(), "tasks");
+        assert_eq!(PreviewFeature::RunAllPackages.as_str(), "run-all-packages");
+        assert_eq!(PreviewFeature::RunWatch.as_str(), "run-watch");
+        assert_eq!(PreviewFeature::Outdated.as_str(), "outdated-command");
+        assert_eq!(
+            PreviewFeature::ResumableDownloads.as_str(),
+            "resumable-downloads"
+        );
+        assert_eq!(
+            PreviewFeature::LockMergeDriver.as_str(),
+            "lock-merge-driver"
+        );
     }
 
     #[test]
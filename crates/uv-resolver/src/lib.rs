@@ -9,9 +9,11 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    DependencySelection, Installable, Lock, LockError, LockVersion, Metadata, Package, PackageMap,
-    PylockToml, PylockTomlError, PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest,
-    SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json,
+    BundleArchive, BundleExport, BundleSource, DependencyPath, DependencySelection, ForkPaths,
+    Installable, Lock, LockDiff, LockError, LockVersion, LockedVersion, Metadata, Package,
+    PackageChange, PackageDiff, PackageMap, PathStep, PylockToml, PylockTomlError,
+    PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
+    TreeEdge, TreeGraph, TreeNode, VERSION, WhyDisplay, cyclonedx_json,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
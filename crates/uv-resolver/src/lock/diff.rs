@@ -0,0 +1,512 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use uv_distribution_types::RequiresPython;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+
+use crate::lock::{Lock, Package, Source, canonical_marker_trees};
+use crate::universal_marker::UniversalMarker;
+
+/// A semantic diff between two lockfiles.
+///
+/// Packages are compared by name: a package that changed its version, source, hashes, or
+/// resolution markers between the two lockfiles is reported once, with all of its locked
+/// entries on either side.
+#[derive(Debug)]
+pub struct LockDiff<'lock> {
+    /// The `requires-python` bound, if it changed.
+    requires_python: Option<(&'lock RequiresPython, &'lock RequiresPython)>,
+    /// The resolution forks that are only present in the new lockfile.
+    forks_added: Vec<String>,
+    /// The resolution forks that are only present in the old lockfile.
+    forks_removed: Vec<String>,
+    /// The changed packages, sorted by name.
+    packages: Vec<PackageDiff<'lock>>,
+}
+
+impl<'lock> LockDiff<'lock> {
+    /// Compute the [`LockDiff`] from the `old` lockfile to the `new` lockfile.
+    pub fn new(old: &'lock Lock, new: &'lock Lock) -> Self {
+        let requires_python = (old.requires_python() != new.requires_python())
+            .then(|| (old.requires_python(), new.requires_python()));
+
+        let old_forks = fork_strings(&old.fork_markers, &old.requires_python);
+        let new_forks = fork_strings(&new.fork_markers, &new.requires_python);
+        let forks_added = new_forks.difference(&old_forks).cloned().collect();
+        let forks_removed = old_forks.difference(&new_forks).cloned().collect();
+
+        let mut by_name: BTreeMap<&PackageName, (Vec<&Package>, Vec<&Package>)> = BTreeMap::new();
+        for package in &old.packages {
+            by_name.entry(package.name()).or_default().0.push(package);
+        }
+        for package in &new.packages {
+            by_name.entry(package.name()).or_default().1.push(package);
+        }
+
+        let packages = by_name
+            .into_iter()
+            .filter_map(|(name, (old_packages, new_packages))| {
+                let change = PackageChange::classify(
+                    &old_packages,
+                    &new_packages,
+                    &old.requires_python,
+                    &new.requires_python,
+                )?;
+                Some(PackageDiff {
+                    name,
+                    change,
+                    before: old_packages.into_iter().map(LockedVersion::from).collect(),
+                    after: new_packages.into_iter().map(LockedVersion::from).collect(),
+                })
+            })
+            .collect();
+
+        Self {
+            requires_python,
+            forks_added,
+            forks_removed,
+            packages,
+        }
+    }
+
+    /// Returns `true` if the two lockfiles are semantically equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.requires_python.is_none()
+            && self.forks_added.is_empty()
+            && self.forks_removed.is_empty()
+            && self.packages.is_empty()
+    }
+
+    /// Returns the old and new `requires-python` bounds, if they differ.
+    pub fn requires_python(&self) -> Option<(&'lock RequiresPython, &'lock RequiresPython)> {
+        self.requires_python
+    }
+
+    /// Returns the resolution forks that were added.
+    pub fn forks_added(&self) -> &[String] {
+        &self.forks_added
+    }
+
+    /// Returns the resolution forks that were removed.
+    pub fn forks_removed(&self) -> &[String] {
+        &self.forks_removed
+    }
+
+    /// Returns the changed packages, sorted by name.
+    pub fn packages(&self) -> &[PackageDiff<'lock>] {
+        &self.packages
+    }
+}
+
+/// The change to a single package between two lockfiles.
+#[derive(Debug)]
+pub struct PackageDiff<'lock> {
+    name: &'lock PackageName,
+    change: PackageChange,
+    before: Vec<LockedVersion<'lock>>,
+    after: Vec<LockedVersion<'lock>>,
+}
+
+impl<'lock> PackageDiff<'lock> {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &'lock PackageName {
+        self.name
+    }
+
+    /// Returns the kind of change.
+    pub fn change(&self) -> PackageChange {
+        self.change
+    }
+
+    /// Returns the locked entries for the package in the old lockfile.
+    pub fn before(&self) -> &[LockedVersion<'lock>] {
+        &self.before
+    }
+
+    /// Returns the locked entries for the package in the new lockfile.
+    pub fn after(&self) -> &[LockedVersion<'lock>] {
+        &self.after
+    }
+}
+
+/// The kind of change to a package between two lockfiles.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PackageChange {
+    /// The package is only present in the new lockfile.
+    Added,
+    /// The package is only present in the old lockfile.
+    Removed,
+    /// Every new version is greater than every old version.
+    Upgraded,
+    /// Every new version is less than every old version.
+    Downgraded,
+    /// The set of versions changed, but not monotonically (e.g., a fork was added).
+    Changed,
+    /// The versions are unchanged, but the package is locked from a different source.
+    Source,
+    /// The versions and sources are unchanged, but the artifact hashes differ.
+    Hashes,
+    /// Only the resolution markers of the package differ.
+    Markers,
+}
+
+impl PackageChange {
+    /// Returns the kebab-case name of the change.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Upgraded => "upgraded",
+            Self::Downgraded => "downgraded",
+            Self::Changed => "changed",
+            Self::Source => "source",
+            Self::Hashes => "hashes",
+            Self::Markers => "markers",
+        }
+    }
+
+    /// Classify the change between the old and new entries for a package, if any.
+    fn classify(
+        old: &[&Package],
+        new: &[&Package],
+        old_requires_python: &RequiresPython,
+        new_requires_python: &RequiresPython,
+    ) -> Option<Self> {
+        if old.is_empty() {
+            return Some(Self::Added);
+        }
+        if new.is_empty() {
+            return Some(Self::Removed);
+        }
+
+        let old_ids = old
+            .iter()
+            .map(|package| &package.id)
+            .collect::<BTreeSet<_>>();
+        let new_ids = new
+            .iter()
+            .map(|package| &package.id)
+            .collect::<BTreeSet<_>>();
+        if old_ids != new_ids {
+            let old_versions = old
+                .iter()
+                .map(|package| package.version())
+                .collect::<BTreeSet<_>>();
+            let new_versions = new
+                .iter()
+                .map(|package| package.version())
+                .collect::<BTreeSet<_>>();
+            if old_versions == new_versions {
+                return Some(Self::Source);
+            }
+            // Dynamic versions (`None`) sort first, so they never count as an upgrade.
+            return if old_versions.last() < new_versions.first() {
+                Some(Self::Upgraded)
+            } else if new_versions.last() < old_versions.first() {
+                Some(Self::Downgraded)
+            } else {
+                Some(Self::Changed)
+            };
+        }
+
+        // The IDs match, so compare the remaining package entries pairwise by ID.
+        let mut old = old.to_vec();
+        let mut new = new.to_vec();
+        old.sort_by(|a, b| a.id.cmp(&b.id));
+        new.sort_by(|a, b| a.id.cmp(&b.id));
+
+        if old
+            .iter()
+            .zip(&new)
+            .any(|(old, new)| hashes(old) != hashes(new))
+        {
+            return Some(Self::Hashes);
+        }
+        if old.iter().zip(&new).any(|(old, new)| {
+            fork_strings(&old.fork_markers, old_requires_python)
+                != fork_strings(&new.fork_markers, new_requires_python)
+        }) {
+            return Some(Self::Markers);
+        }
+        None
+    }
+}
+
+/// A locked entry for a package, as reported in a [`LockDiff`].
+#[derive(Debug)]
+pub struct LockedVersion<'lock> {
+    version: Option<&'lock Version>,
+    source: String,
+}
+
+impl LockedVersion<'_> {
+    /// Returns the locked version, if the package isn't dynamic.
+    pub fn version(&self) -> Option<&Version> {
+        self.version
+    }
+
+    /// Returns the source of the package (e.g., `registry+https://pypi.org/simple`).
+    ///
+    /// Git sources include the resolved commit, such that a change in commit is visible.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl<'lock> From<&'lock Package> for LockedVersion<'lock> {
+    fn from(package: &'lock Package) -> Self {
+        let source = match &package.id.source {
+            Source::Git(url, git) => format!("git+{url}#{}", git.precise),
+            source => source.to_string(),
+        };
+        Self {
+            version: package.version(),
+            source,
+        }
+    }
+}
+
+/// Returns the set of artifact hashes for a package.
+fn hashes(package: &Package) -> BTreeSet<String> {
+    package
+        .hashes()
+        .as_slice()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Returns the canonical string form of a set of fork markers.
+fn fork_strings(markers: &[UniversalMarker], requires_python: &RequiresPython) -> BTreeSet<String> {
+    canonical_marker_trees(markers, requires_python)
+        .into_iter()
+        .filter_map(MarkerTree::try_to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse a lockfile with the given packages.
+    fn lock(packages: &str) -> Lock {
+        let data = format!("version = 1\nrevision = 3\nrequires-python = \">=3.12\"\n{packages}");
+        toml::from_str(&data).unwrap()
+    }
+
+    /// Summarize the package changes in a [`LockDiff`].
+    fn changes(diff: &LockDiff) -> Vec<(String, PackageChange)> {
+        diff.packages()
+            .iter()
+            .map(|package| (package.name().to_string(), package.change()))
+            .collect()
+    }
+
+    const FOO_1: &str = r#"
+[[package]]
+name = "foo"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/foo-1.0.0-py3-none-any.whl" },
+]
+"#;
+
+    const FOO_2: &str = r#"
+[[package]]
+name = "foo"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/foo-2.0.0-py3-none-any.whl" },
+]
+"#;
+
+    const BAR_1: &str = r#"
+[[package]]
+name = "bar"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/bar-1.0.0-py3-none-any.whl" },
+]
+"#;
+
+    const BAR_2: &str = r#"
+[[package]]
+name = "bar"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/bar-2.0.0-py3-none-any.whl" },
+]
+"#;
+
+    #[test]
+    fn unchanged() {
+        let old = lock(FOO_1);
+        let new = lock(FOO_1);
+        assert!(LockDiff::new(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let old = lock(FOO_1);
+        let new = lock(BAR_1);
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(
+            changes(&diff),
+            [
+                ("bar".to_string(), PackageChange::Added),
+                ("foo".to_string(), PackageChange::Removed),
+            ]
+        );
+
+        let bar = &diff.packages()[0];
+        assert!(bar.before().is_empty());
+        assert_eq!(bar.after()[0].version(), Some(&Version::new([1, 0, 0])));
+    }
+
+    #[test]
+    fn upgraded_and_downgraded() {
+        let old = lock(&format!("{FOO_1}{BAR_2}"));
+        let new = lock(&format!("{FOO_2}{BAR_1}"));
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(
+            changes(&diff),
+            [
+                ("bar".to_string(), PackageChange::Downgraded),
+                ("foo".to_string(), PackageChange::Upgraded),
+            ]
+        );
+    }
+
+    /// A version that's added alongside an existing one is neither an upgrade nor a downgrade.
+    #[test]
+    fn changed() {
+        let old = lock(FOO_1);
+        let new = lock(&format!(
+            r#"
+resolution-markers = [
+    "python_full_version >= '3.13'",
+    "python_full_version < '3.13'",
+]
+{}{}"#,
+            FOO_1.replace(
+                "source = {",
+                "resolution-markers = [\"python_full_version < '3.13'\"]\nsource = {"
+            ),
+            FOO_2.replace(
+                "source = {",
+                "resolution-markers = [\"python_full_version >= '3.13'\"]\nsource = {"
+            ),
+        ));
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(
+            changes(&diff),
+            [("foo".to_string(), PackageChange::Changed)]
+        );
+        assert_eq!(
+            diff.forks_added(),
+            [
+                "python_full_version < '3.13'".to_string(),
+                "python_full_version >= '3.13'".to_string(),
+            ]
+        );
+        assert!(diff.forks_removed().is_empty());
+    }
+
+    #[test]
+    fn source() {
+        let old = lock(FOO_1);
+        let new = lock(&FOO_1.replace("https://pypi.org/simple", "https://example.org/simple"));
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(changes(&diff), [("foo".to_string(), PackageChange::Source)]);
+
+        let foo = &diff.packages()[0];
+        assert_eq!(foo.before()[0].source(), "registry+https://pypi.org/simple");
+        assert_eq!(
+            foo.after()[0].source(),
+            "registry+https://example.org/simple"
+        );
+    }
+
+    #[test]
+    fn hashes() {
+        let with_hash = |hash: char| {
+            FOO_1.replace(
+                "foo-1.0.0-py3-none-any.whl\" }",
+                &format!(
+                    "foo-1.0.0-py3-none-any.whl\", hash = \"sha256:{}\" }}",
+                    hash.to_string().repeat(64)
+                ),
+            )
+        };
+        let old = lock(&with_hash('a'));
+        let new = lock(&with_hash('b'));
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(changes(&diff), [("foo".to_string(), PackageChange::Hashes)]);
+    }
+
+    /// A package whose versions are unchanged, but whose resolution markers differ.
+    #[test]
+    fn markers() {
+        let forked = |marker: &str, complement: &str| {
+            format!(
+                r#"
+resolution-markers = [
+    "{marker}",
+    "{complement}",
+]
+{}{}"#,
+                FOO_1.replace(
+                    "source = {",
+                    &format!("resolution-markers = [\"{marker}\"]\nsource = {{")
+                ),
+                BAR_1,
+            )
+        };
+        let old = lock(&forked(
+            "sys_platform == 'linux'",
+            "sys_platform != 'linux'",
+        ));
+        let new = lock(&forked(
+            "sys_platform == 'win32'",
+            "sys_platform != 'win32'",
+        ));
+        let diff = LockDiff::new(&old, &new);
+        assert_eq!(
+            changes(&diff),
+            [("foo".to_string(), PackageChange::Markers)]
+        );
+        assert_eq!(
+            diff.forks_added(),
+            [
+                "sys_platform != 'win32'".to_string(),
+                "sys_platform == 'win32'".to_string(),
+            ]
+        );
+        assert_eq!(
+            diff.forks_removed(),
+            [
+                "sys_platform != 'linux'".to_string(),
+                "sys_platform == 'linux'".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn requires_python() {
+        let old = lock(FOO_1);
+        let new: Lock = toml::from_str(&format!(
+            "version = 1\nrevision = 3\nrequires-python = \">=3.13\"\n{FOO_1}"
+        ))
+        .unwrap();
+        let diff = LockDiff::new(&old, &new);
+        let (before, after) = diff.requires_python().unwrap();
+        assert_eq!(before.to_string(), ">=3.12");
+        assert_eq!(after.to_string(), ">=3.13");
+        assert!(diff.packages().is_empty());
+    }
+}
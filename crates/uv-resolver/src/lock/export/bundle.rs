@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, EditableMode, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+use uv_pypi_types::HashDigests;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::Installable;
+use crate::lock::export::{PylockToml, PylockTomlErrorKind};
+
+/// An export of a [`Lock`] as a self-contained directory of distribution archives, along with a
+/// `pylock.toml` that references them by relative path.
+///
+/// The directory doubles as a flat index, such that it can be passed to `--find-links`.
+#[derive(Debug)]
+pub struct BundleExport {
+    pylock: PylockToml,
+    archives: Vec<BundleArchive>,
+}
+
+impl BundleExport {
+    /// Generate a [`BundleExport`] for the given lockfile, to be written to `directory`.
+    ///
+    /// If any `platforms` are provided, only the packages and wheels that are compatible with at
+    /// least one of them are included.
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        dev: &DependencyGroupsWithDefaults,
+        annotate: bool,
+        editable: Option<&EditableMode>,
+        install_options: &'lock InstallOptions,
+        platforms: &[MarkerTree],
+        directory: &Path,
+    ) -> Result<Self, PylockTomlErrorKind> {
+        let mut pylock = PylockToml::from_lock(
+            target,
+            prune,
+            extras,
+            dev,
+            annotate,
+            editable,
+            install_options,
+        )?;
+        let archives = pylock.relocate(target.install_path(), platforms, directory)?;
+        Ok(Self { pylock, archives })
+    }
+
+    /// Return the `pylock.toml` that describes the bundle.
+    pub fn pylock(&self) -> &PylockToml {
+        &self.pylock
+    }
+
+    /// Return the archives that must be retrieved to populate the bundle.
+    pub fn archives(&self) -> &[BundleArchive] {
+        &self.archives
+    }
+}
+
+/// A distribution archive to include in a [`BundleExport`].
+#[derive(Debug, Clone)]
+pub struct BundleArchive {
+    /// The name of the package that the archive belongs to.
+    pub name: PackageName,
+    /// The filename of the archive within the bundle directory.
+    pub filename: String,
+    /// The location from which the archive can be retrieved.
+    pub source: BundleSource,
+    /// The expected hashes of the archive.
+    pub hashes: HashDigests,
+    /// The expected size of the archive, in bytes.
+    pub size: Option<u64>,
+}
+
+/// The location of a [`BundleArchive`].
+#[derive(Debug, Clone)]
+pub enum BundleSource {
+    /// The archive is available at a (possibly `file://`) URL.
+    Url(DisplaySafeUrl),
+    /// The archive is available at an absolute path on the local filesystem.
+    Path(PathBuf),
+}
@@ -17,8 +17,6 @@ use uv_configuration::{
 use uv_fs::PortablePath;
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
-use uv_preview::{Preview, PreviewFeature};
-use uv_warnings::warn_user;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
 use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
@@ -264,16 +262,8 @@ pub fn from_lock<'lock>(
     groups: &DependencyGroupsWithDefaults,
     annotate: bool,
     install_options: &'lock InstallOptions,
-    preview: Preview,
     all_packages: bool,
 ) -> Result<Bom, LockError> {
-    if !preview.is_enabled(PreviewFeature::SbomExport) {
-        warn_user!(
-            "`uv export --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
-            PreviewFeature::SbomExport
-        );
-    }
-
     // Extract the packages from the lock file.
     let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
         target,
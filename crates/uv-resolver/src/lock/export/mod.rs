@@ -17,6 +17,7 @@ use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::Reachable;
 use crate::lock::LockErrorKind;
+pub use crate::lock::export::bundle::{BundleArchive, BundleExport, BundleSource};
 pub use crate::lock::export::metadata::Metadata;
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlError, PylockTomlErrorKind};
@@ -24,6 +25,7 @@ pub use crate::lock::export::requirements_txt::RequirementsTxtExport;
 use crate::universal_marker::resolve_activated_extras;
 use crate::{Installable, LockError, Package};
 
+mod bundle;
 pub mod cyclonedx_json;
 mod metadata;
 mod pylock_toml;
@@ -8,6 +8,7 @@ use jiff::Timestamp;
 use jiff::civil::{Date, DateTime, Time};
 use jiff::tz::{Offset, TimeZone};
 use petgraph::graph::NodeIndex;
+use rustc_hash::FxHashSet;
 use serde::Deserialize;
 use toml_edit::{Array, ArrayOfTables, Item, Table, value};
 use url::Url;
@@ -25,9 +26,9 @@ use uv_distribution_types::{
     BuiltDist, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist, Dist, Edge,
     FileLocation, GitDirectorySourceDist, IndexUrl, Name, Node, PathBuiltDist, PathSourceDist,
     RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist, RemoteSource, RequiresPython,
-    Resolution, ResolvedDist, SourceDist, ToUrlError, UrlString,
+    Resolution, ResolvedDist, SourceDist, ToUrlError, UrlString, implied_markers,
 };
-use uv_fs::{PortablePathBuf, normalize_path, try_relative_to_if};
+use uv_fs::{PortablePathBuf, normalize_path, relative_to, try_relative_to_if};
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
 use uv_git_types::{GitLfs, GitOid, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -39,6 +40,7 @@ use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::lock::export::ExportableRequirements;
+use crate::lock::export::bundle::{BundleArchive, BundleSource};
 use crate::lock::{Source, WheelTagHint, each_element_on_its_line_array, is_wheel_unreachable};
 use crate::{Installable, LockError, ResolverOutput};
 
@@ -966,6 +968,140 @@ impl<'lock> PylockToml {
         })
     }
 
+    /// Rewrite the archives referenced by the lockfile to point to files in the given bundle
+    /// directory, returning the archives that must be retrieved to populate it.
+    ///
+    /// If any `platforms` are provided, packages and wheels that are incompatible with all of them
+    /// are omitted. Directory and VCS paths are rewritten to be relative to the bundle directory.
+    pub(crate) fn relocate(
+        &mut self,
+        install_path: &Path,
+        platforms: &[MarkerTree],
+        directory: &Path,
+    ) -> Result<Vec<BundleArchive>, PylockTomlErrorKind> {
+        let is_compatible = |marker: MarkerTree| {
+            platforms.is_empty()
+                || platforms
+                    .iter()
+                    .any(|platform| !marker.is_disjoint(*platform))
+        };
+
+        // Omit any packages that aren't relevant to the selected platforms.
+        self.packages
+            .retain(|package| is_compatible(package.marker));
+
+        let mut archives = Vec::new();
+        let mut seen = FxHashSet::default();
+        let mut relocate = |name: &PackageName,
+                            filename: &str,
+                            url: &mut Option<DisplaySafeUrl>,
+                            path: &mut Option<PortablePathBuf>,
+                            hashes: &Hashes,
+                            size: Option<u64>| {
+            let source = if let Some(url) = url.take() {
+                BundleSource::Url(url)
+            } else if let Some(path) = path.take() {
+                BundleSource::Path(install_path.join(path))
+            } else {
+                return;
+            };
+            *path = Some(PortablePathBuf::from(
+                PathBuf::from(filename).into_boxed_path(),
+            ));
+            if seen.insert(filename.to_string()) {
+                archives.push(BundleArchive {
+                    name: name.clone(),
+                    filename: filename.to_string(),
+                    source,
+                    hashes: HashDigests::from(hashes.clone()),
+                    size,
+                });
+            }
+        };
+
+        for package in &mut self.packages {
+            if let Some(wheels) = package.wheels.take() {
+                let mut retained = Vec::with_capacity(wheels.len());
+                for mut wheel in wheels {
+                    let filename = wheel.filename(&package.name)?.into_owned();
+                    if !is_compatible(implied_markers(&filename)) {
+                        continue;
+                    }
+                    wheel.name = None;
+                    relocate(
+                        &package.name,
+                        &filename.to_string(),
+                        &mut wheel.url,
+                        &mut wheel.path,
+                        &wheel.hashes,
+                        wheel.size,
+                    );
+                    retained.push(wheel);
+                }
+                if !retained.is_empty() {
+                    package.wheels = Some(retained);
+                }
+            }
+
+            if let Some(sdist) = package.sdist.as_mut() {
+                let filename = sdist.filename(&package.name)?.into_owned();
+                sdist.name = None;
+                relocate(
+                    &package.name,
+                    &filename,
+                    &mut sdist.url,
+                    &mut sdist.path,
+                    &sdist.hashes,
+                    sdist.size,
+                );
+            }
+
+            if let Some(archive) = package.archive.as_mut() {
+                let filename = if let Some(url) = archive.url.as_ref() {
+                    url.filename()
+                        .map_err(|_| PylockTomlErrorKind::UrlMissingFilename(url.clone()))?
+                        .to_string()
+                } else if let Some(path) = archive.path.as_ref() {
+                    path.as_ref()
+                        .file_name()
+                        .and_then(OsStr::to_str)
+                        .ok_or_else(|| {
+                            PylockTomlErrorKind::PathMissingFilename(Box::<Path>::from(
+                                path.clone(),
+                            ))
+                        })?
+                        .to_string()
+                } else {
+                    return Err(PylockTomlErrorKind::ArchiveMissingPathUrl(
+                        package.name.clone(),
+                    ));
+                };
+                relocate(
+                    &package.name,
+                    &filename,
+                    &mut archive.url,
+                    &mut archive.path,
+                    &archive.hashes,
+                    archive.size,
+                );
+            }
+
+            if let Some(source) = package.directory.as_mut() {
+                source.path = PortablePathBuf::from(
+                    relative_to(install_path.join(&source.path), directory)?.into_boxed_path(),
+                );
+            }
+
+            if let Some(path) = package.vcs.as_mut().and_then(|vcs| vcs.path.as_mut()) {
+                *path = PortablePathBuf::from(
+                    relative_to(install_path.join(&*path), directory)?.into_boxed_path(),
+                );
+            }
+        }
+
+        Ok(archives)
+    }
+
     /// Returns the TOML representation of this lockfile.
     pub fn to_toml(&self) -> Result<String, toml_edit::ser::Error> {
         // We construct a TOML document manually instead of going through Serde to enable
@@ -41,16 +41,17 @@ use uv_fs::{
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
 use uv_git_types::{GitLfs, GitOid, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{
-    MarkerEnvironment, MarkerTree, Scheme, VerbatimUrl, VerbatimUrlError, split_scheme,
+    MarkerEnvironment, MarkerTree, Scheme, VerbatimUrl, VerbatimUrlError, VersionOrUrl,
+    split_scheme,
 };
 use uv_platform_tags::{
     AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
 };
 use uv_pypi_types::{
     ConflictKind, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
-    ParsedGitDirectoryUrl, ParsedGitPathUrl, PyProjectToml,
+    ParsedGitDirectoryUrl, ParsedGitPathUrl, PyProjectToml, VerbatimParsedUrl,
 };
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_small_str::SmallString;
@@ -58,14 +59,17 @@ use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::{Editability, WorkspaceMember};
 
 use crate::fork_strategy::ForkStrategy;
+pub use crate::lock::diff::{LockDiff, LockedVersion, PackageChange, PackageDiff};
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{
-    Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind, cyclonedx_json,
+    BundleArchive, BundleExport, BundleSource, Metadata, PylockToml, PylockTomlError,
+    PylockTomlErrorKind, cyclonedx_json,
 };
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
-pub use crate::lock::tree::TreeDisplay;
+pub use crate::lock::tree::{TreeDisplay, TreeEdge, TreeGraph, TreeNode};
+pub use crate::lock::why::{DependencyPath, ForkPaths, PathStep, WhyDisplay};
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
@@ -73,10 +77,12 @@ use crate::{
     InMemoryIndex, MetadataResponse, PrereleaseMode, ResolutionMode, ResolverOutput,
 };
 
+mod diff;
 pub(crate) mod export;
 mod installable;
 mod map;
 mod tree;
+mod why;
 
 /// The current version of the lockfile format.
 pub const VERSION: u32 = 1;
@@ -830,6 +836,177 @@ impl Lock {
         &self.manifest.dependency_groups
     }
 
+    /// Returns the workspace members in this lock.
+    ///
+    /// For a workspace with a single member, the members aren't recorded in the lockfile, so the
+    /// workspace root is returned instead.
+    pub(crate) fn member_packages(&self) -> Vec<&Package> {
+        if self.members().is_empty() {
+            self.root().into_iter().collect()
+        } else {
+            self.packages
+                .iter()
+                .filter(|package| self.members().contains(&package.id.name))
+                .collect()
+        }
+    }
+
+    /// Returns the direct requirements of the workspace.
+    ///
+    /// Includes the dependencies, optional dependencies, and dependency groups declared by each
+    /// workspace member, along with any requirements attached to the workspace itself (e.g., the
+    /// dependencies of a PEP 723 script).
+    pub fn direct_requirements(&self) -> impl Iterator<Item = &Requirement> {
+        self.member_packages()
+            .into_iter()
+            .flat_map(|package| {
+                package
+                    .metadata
+                    .requires_dist
+                    .iter()
+                    .chain(package.metadata.dependency_groups.values().flatten())
+            })
+            .chain(self.requirements())
+            .chain(self.dependency_groups().values().flatten())
+    }
+
+    /// Returns the direct requirements of the workspace, along with the workspace member and
+    /// dependency group that declare each of them.
+    ///
+    /// Requirements attached to the workspace itself (e.g., the dependencies of a PEP 723 script)
+    /// have no member.
+    pub fn declared_requirements(
+        &self,
+    ) -> impl Iterator<Item = (Option<&PackageName>, Option<&GroupName>, &Requirement)> {
+        self.member_packages()
+            .into_iter()
+            .flat_map(|package| {
+                let name = Some(package.name());
+                package
+                    .metadata
+                    .requires_dist
+                    .iter()
+                    .map(move |requirement| (name, None, requirement))
+                    .chain(package.metadata.dependency_groups.iter().flat_map(
+                        move |(group, requirements)| {
+                            requirements
+                                .iter()
+                                .map(move |requirement| (name, Some(group), requirement))
+                        },
+                    ))
+            })
+            .chain(
+                self.requirements()
+                    .iter()
+                    .map(|requirement| (None, None, requirement)),
+            )
+            .chain(
+                self.dependency_groups()
+                    .iter()
+                    .flat_map(|(group, requirements)| {
+                        requirements
+                            .iter()
+                            .map(move |requirement| (None, Some(group), requirement))
+                    }),
+            )
+    }
+
+    /// Returns the version specifiers that the dependents of a locked package place on it.
+    ///
+    /// The specifiers are collected from the requirements behind each dependency edge that leads
+    /// to the package, along with any requirements attached to the workspace itself. Since each
+    /// edge leads to a single locked version, requirements that select a different version of the
+    /// package in another resolution fork are excluded.
+    ///
+    /// The lockfile only records the requirements of packages from mutable sources (e.g.,
+    /// workspace members); the requirements of any other dependent are read from `requires_dist`.
+    /// If they're unknown for any dependent, `None` is returned.
+    pub fn dependent_specifiers<'lock, 'metadata>(
+        &'lock self,
+        package: &Package,
+        requires_dist: impl Fn(
+            &'lock Package,
+        )
+            -> Option<&'metadata [uv_pep508::Requirement<VerbatimParsedUrl>]>,
+    ) -> Option<VersionSpecifiers> {
+        let name = &package.id.name;
+        let mut specifiers = Vec::new();
+
+        // Include any requirements attached to the workspace itself.
+        for requirement in self
+            .requirements()
+            .iter()
+            .chain(self.dependency_groups().values().flatten())
+        {
+            if requirement.name == *name
+                && self.root_requirement_marker(requirement, package).is_some()
+            {
+                if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+                    specifiers.extend(specifier.iter().cloned());
+                }
+            }
+        }
+
+        for parent in &self.packages {
+            let edges = parent
+                .dependencies
+                .iter()
+                .map(|dep| (None, None, dep))
+                .chain(
+                    parent
+                        .optional_dependencies
+                        .iter()
+                        .flat_map(|(extra, deps)| {
+                            deps.iter().map(move |dep| (Some(extra), None, dep))
+                        }),
+                )
+                .chain(parent.dependency_groups.iter().flat_map(|(group, deps)| {
+                    deps.iter().map(move |dep| (None, Some(group), dep))
+                }));
+            for (extra, group, dep) in edges {
+                if dep.package_id != package.id {
+                    continue;
+                }
+
+                // A requirement applies to the edge if it's declared for the same extra (or none),
+                // and its marker overlaps with that of the edge.
+                let edge_marker = dep.complexified_marker.pep508();
+                let applies = |marker: MarkerTree| {
+                    marker.top_level_extra_name().as_deref() == extra
+                        && !marker.is_disjoint(edge_marker)
+                };
+
+                if parent.id.source.is_immutable() {
+                    for requirement in requires_dist(parent)? {
+                        if requirement.name != *name || !applies(requirement.marker) {
+                            continue;
+                        }
+                        if let Some(VersionOrUrl::VersionSpecifier(specifier)) =
+                            &requirement.version_or_url
+                        {
+                            specifiers.extend(specifier.iter().cloned());
+                        }
+                    }
+                } else {
+                    let requirements = match group {
+                        Some(group) => parent.metadata.dependency_groups.get(group),
+                        None => Some(&parent.metadata.requires_dist),
+                    };
+                    for requirement in requirements.into_iter().flatten() {
+                        if requirement.name != *name || !applies(requirement.marker) {
+                            continue;
+                        }
+                        if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+                            specifiers.extend(specifier.iter().cloned());
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(specifiers.into_iter().unique().collect())
+    }
+
     /// Returns the environment-specific direct dependency selections for a lock target.
     ///
     /// If `project_name` is provided, dependencies attached to that package are used. Otherwise,
@@ -3904,6 +4081,60 @@ impl Package {
         }
     }
 
+    /// Returns a wheel from which the metadata of a registry package can be read without a build.
+    ///
+    /// Returns `None` for packages that aren't from a registry, or that don't have any wheels.
+    pub fn registry_wheel(&self, root: &Path) -> Result<Option<BuiltDist>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(None);
+        };
+        if self.wheels.is_empty() {
+            return Ok(None);
+        }
+        let wheels = self
+            .wheels
+            .iter()
+            .map(|wheel| wheel.to_registry_wheel(source, root))
+            .collect::<Result<_, LockError>>()?;
+        Ok(Some(BuiltDist::Registry(RegistryBuiltDist {
+            wheels,
+            best_wheel_index: 0,
+            sdist: None,
+        })))
+    }
+
+    /// Returns the requirement in the package metadata that introduced a dependency on the given
+    /// package, under the given extra or dependency group.
+    ///
+    /// Returns `None` if the requirement isn't recorded in the lockfile, as for packages from
+    /// immutable sources.
+    fn dependency_requirement(
+        &self,
+        name: &PackageName,
+        extra: Option<&ExtraName>,
+        group: Option<&GroupName>,
+        marker: MarkerTree,
+    ) -> Option<&Requirement> {
+        match group {
+            Some(group) => find_requirement(
+                self.metadata
+                    .dependency_groups
+                    .get(group)
+                    .into_iter()
+                    .flatten(),
+                name,
+                marker,
+            ),
+            None => find_requirement(
+                self.metadata.requires_dist.iter().filter(|requirement| {
+                    requirement.marker.top_level_extra_name().as_deref() == extra
+                }),
+                name,
+                marker,
+            ),
+        }
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
@@ -7108,6 +7339,35 @@ fn canonical_marker_trees(
         .collect()
 }
 
+/// Find the requirement on the given package among a set of requirements.
+///
+/// If there are multiple matching requirements, prefer the one whose marker overlaps with that of
+/// the dependency edge.
+fn find_requirement<'a>(
+    requirements: impl IntoIterator<Item = &'a Requirement>,
+    name: &PackageName,
+    marker: MarkerTree,
+) -> Option<&'a Requirement> {
+    let mut candidates = requirements
+        .into_iter()
+        .filter(|requirement| requirement.name == *name)
+        .peekable();
+    let first = candidates.peek().copied();
+    candidates
+        .find(|requirement| !requirement.marker.without_extras().is_disjoint(marker))
+        .or(first)
+}
+
+/// Format the constraint that a requirement places on a package, e.g., `>=1.0`, or `*` if it's
+/// unconstrained.
+fn requirement_specifier(requirement: &Requirement) -> String {
+    match &requirement.source {
+        RequirementSource::Registry { specifier, .. } if specifier.is_empty() => "*".to_string(),
+        RequirementSource::Registry { specifier, .. } => specifier.to_string(),
+        source => source.to_string().trim().to_string(),
+    }
+}
+
 /// Filter out wheels that can't be selected for installation due to environment markers.
 ///
 /// For example, a package included under `sys_platform == 'win32'` does not need Linux
@@ -7843,4 +8103,93 @@ wheels = [
             ))
         );
     }
+
+    /// The specifiers on a package are collected from the edges that lead to each locked version,
+    /// rather than from every requirement on the package.
+    #[test]
+    fn dependent_specifiers() {
+        let data = r#"
+version = 1
+revision = 3
+requires-python = ">=3.9"
+resolution-markers = [
+    "python_full_version >= '3.10'",
+    "python_full_version < '3.10'",
+]
+
+[[package]]
+name = "bar"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "foo", version = "2.0.0", source = { registry = "https://pypi.org/simple" }, marker = "python_full_version >= '3.10'" },
+]
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/bar-1.0.0-py3-none-any.whl" },
+]
+
+[[package]]
+name = "foo"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "python_full_version < '3.10'",
+]
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/foo-1.0.0-py3-none-any.whl" },
+]
+
+[[package]]
+name = "foo"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "python_full_version >= '3.10'",
+]
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/foo-2.0.0-py3-none-any.whl" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "bar", marker = "python_full_version >= '3.10'" },
+    { name = "foo", version = "1.0.0", source = { registry = "https://pypi.org/simple" }, marker = "python_full_version < '3.10'" },
+    { name = "foo", version = "2.0.0", source = { registry = "https://pypi.org/simple" }, marker = "python_full_version >= '3.10'" },
+]
+
+[package.metadata]
+requires-dist = [
+    { name = "bar", marker = "python_full_version >= '3.10'" },
+    { name = "foo", marker = "python_full_version < '3.10'", specifier = "<2" },
+    { name = "foo", marker = "python_full_version >= '3.10'", specifier = ">=2" },
+]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        let foo = |version: &str| {
+            lock.packages
+                .iter()
+                .find(|package| {
+                    package.id.name.as_str() == "foo"
+                        && package.id.version == Some(Version::from_str(version).unwrap())
+                })
+                .unwrap()
+        };
+        let bar = vec![uv_pep508::Requirement::from_str("foo>=2.1").unwrap()];
+
+        let specifiers = lock.dependent_specifiers(foo("1.0.0"), |_| Some(bar.as_slice()));
+        assert_eq!(specifiers, Some(VersionSpecifiers::from_str("<2").unwrap()));
+
+        let specifiers = lock.dependent_specifiers(foo("2.0.0"), |_| Some(bar.as_slice()));
+        assert_eq!(
+            specifiers,
+            Some(VersionSpecifiers::from_str(">=2, >=2.1").unwrap())
+        );
+
+        // Without the requirements of `bar`, the specifiers on `foo` 2.0.0 are unknown.
+        let specifiers = lock.dependent_specifiers(foo("2.0.0"), |_| None);
+        assert_eq!(specifiers, None);
+    }
 }
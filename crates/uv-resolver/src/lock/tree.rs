@@ -12,12 +12,13 @@ use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_console::human_readable_bytes;
+use uv_distribution_types::{RequiresPython, SimplifiedMarkerTree};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 use uv_pypi_types::ResolverMarkerEnvironment;
 
-use crate::lock::{Package, PackageId};
+use crate::lock::{Package, PackageId, find_requirement, requirement_specifier};
 use crate::{ConflictMarker, Lock, PackageMap, UniversalMarker};
 
 #[derive(Debug)]
@@ -733,6 +734,275 @@ impl<'env> TreeDisplay<'env> {
     }
 }
 
+impl<'env> TreeDisplay<'env> {
+    /// Return the dependency graph, for display in a machine-readable format.
+    ///
+    /// Unlike the rendered tree, each package appears once, and all of its dependencies are
+    /// included, regardless of which extras are activated. Packages beyond the maximum display
+    /// depth are omitted.
+    pub fn graph(&self) -> TreeGraph<'env> {
+        // Perform a breadth-first traversal from the roots, tracking the depth of each package.
+        let mut depths = FxHashMap::default();
+        let mut queue = VecDeque::new();
+        let mut edges = Vec::new();
+        for root in &self.roots {
+            match self.graph[*root] {
+                Node::Root => {
+                    for edge in self.graph.edges_directed(*root, Direction::Outgoing) {
+                        edges.push((None, edge.target(), edge.id()));
+                        if depths.insert(edge.target(), 0).is_none() {
+                            queue.push_back(edge.target());
+                        }
+                    }
+                }
+                Node::Package(_) => {
+                    if depths.insert(*root, 0).is_none() {
+                        queue.push_back(*root);
+                    }
+                }
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let depth = depths[&node];
+            if depth >= self.depth {
+                continue;
+            }
+            for edge in self.graph.edges_directed(node, Direction::Outgoing) {
+                if matches!(self.graph[edge.target()], Node::Root) {
+                    continue;
+                }
+                edges.push((Some(node), edge.target(), edge.id()));
+                if depths.insert(edge.target(), depth + 1).is_none() {
+                    queue.push_back(edge.target());
+                }
+            }
+        }
+
+        // Sort the packages, such that the output is deterministic.
+        let mut nodes = depths.keys().copied().collect::<Vec<_>>();
+        nodes.sort_by_key(|index| &self.graph[*index]);
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, index)| (*index, position))
+            .collect::<FxHashMap<_, _>>();
+
+        let roots = {
+            let mut roots = depths
+                .iter()
+                .filter(|(_, depth)| **depth == 0)
+                .map(|(index, _)| positions[index])
+                .collect::<Vec<_>>();
+            roots.sort_unstable();
+            roots
+        };
+
+        let mut edges = edges
+            .into_iter()
+            .map(|(source, target, edge)| TreeEdge {
+                source: source.map(|index| positions[&index]),
+                target: positions[&target],
+                specifier: self.specifier(source, target, &self.graph[edge]),
+                edge: self.graph[edge].clone(),
+                requires_python: self.lock.requires_python(),
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by(|a, b| {
+            (a.source, a.target, a.edge.kind()).cmp(&(b.source, b.target, b.edge.kind()))
+        });
+
+        let nodes = nodes
+            .into_iter()
+            .filter_map(|index| match self.graph[index] {
+                Node::Package(package_id) => Some(TreeNode {
+                    package: self.lock.find_by_id(package_id),
+                    latest: self.latest.get(package_id),
+                    show_sizes: self.show_sizes,
+                }),
+                Node::Root => None,
+            })
+            .collect();
+
+        TreeGraph {
+            nodes,
+            edges,
+            roots,
+        }
+    }
+}
+
+impl TreeDisplay<'_> {
+    /// Return the version specifier of the requirement behind an edge, if recorded in the
+    /// lockfile.
+    ///
+    /// A `source` of `None` refers to the requirements attached to the workspace itself.
+    fn specifier(
+        &self,
+        source: Option<NodeIndex>,
+        target: NodeIndex,
+        edge: &Edge<'_>,
+    ) -> Option<String> {
+        // In `--invert` mode, the edge is declared by its target.
+        let (parent, child) = if self.invert {
+            (Some(target), source?)
+        } else {
+            (source, target)
+        };
+        let Node::Package(child) = self.graph[child] else {
+            return None;
+        };
+        let group = match edge {
+            Edge::Dev(group, ..) => Some(*group),
+            Edge::Prod(..) | Edge::Optional(..) => None,
+        };
+        let marker = edge.marker().pep508();
+        let requirement = match parent.map(|parent| &self.graph[parent]) {
+            Some(Node::Package(parent)) => self.lock.find_by_id(parent).dependency_requirement(
+                &child.name,
+                edge.required_extra(),
+                group,
+                marker,
+            ),
+            Some(Node::Root) | None => match group {
+                Some(group) => find_requirement(
+                    self.lock
+                        .dependency_groups()
+                        .get(group)
+                        .into_iter()
+                        .flatten(),
+                    &child.name,
+                    marker,
+                ),
+                None => find_requirement(self.lock.requirements(), &child.name, marker),
+            },
+        };
+        requirement.map(requirement_specifier)
+    }
+}
+
+/// The dependency graph of a [`TreeDisplay`], for display in a machine-readable format.
+///
+/// Edges point from a package to its dependencies, or, in `--invert` mode, to its dependents.
+#[derive(Debug)]
+pub struct TreeGraph<'env> {
+    nodes: Vec<TreeNode<'env>>,
+    edges: Vec<TreeEdge<'env>>,
+    roots: Vec<usize>,
+}
+
+impl<'env> TreeGraph<'env> {
+    /// The packages in the graph.
+    pub fn nodes(&self) -> &[TreeNode<'env>] {
+        &self.nodes
+    }
+
+    /// The edges between packages in the graph.
+    pub fn edges(&self) -> &[TreeEdge<'env>] {
+        &self.edges
+    }
+
+    /// The indices of the packages at the root of the graph.
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+}
+
+/// A package in a [`TreeGraph`].
+#[derive(Debug)]
+pub struct TreeNode<'env> {
+    package: &'env Package,
+    latest: Option<&'env Version>,
+    show_sizes: bool,
+}
+
+impl<'env> TreeNode<'env> {
+    /// The name of the package.
+    pub fn name(&self) -> &'env PackageName {
+        &self.package.id.name
+    }
+
+    /// The locked version of the package, if any.
+    pub fn version(&self) -> Option<&'env Version> {
+        self.package.id.version.as_ref()
+    }
+
+    /// The source of the package, e.g., `registry+https://pypi.org/simple`.
+    pub fn source(&self) -> String {
+        self.package.id.source.to_string()
+    }
+
+    /// The latest available version of the package, if newer than the locked version.
+    pub fn latest(&self) -> Option<&'env Version> {
+        self.latest
+    }
+
+    /// The compressed size of the package's wheel in bytes, if requested and known.
+    pub fn size(&self) -> Option<u64> {
+        if !self.show_sizes {
+            return None;
+        }
+        self.package.wheels.iter().find_map(|wheel| wheel.size)
+    }
+}
+
+/// An edge between two packages in a [`TreeGraph`].
+#[derive(Debug)]
+pub struct TreeEdge<'env> {
+    source: Option<usize>,
+    target: usize,
+    specifier: Option<String>,
+    edge: Edge<'env>,
+    requires_python: &'env RequiresPython,
+}
+
+impl<'env> TreeEdge<'env> {
+    /// The index of the package that the edge starts from, or `None` if the edge starts from the
+    /// project itself (e.g., for the dependencies of a PEP 723 script).
+    pub fn source(&self) -> Option<usize> {
+        self.source
+    }
+
+    /// The index of the package that the edge leads to.
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    /// The extras requested on the dependency.
+    pub fn extras(&self) -> Vec<&'env ExtraName> {
+        self.edge
+            .extras()
+            .into_iter()
+            .flat_map(RequestedExtras::iter)
+            .collect()
+    }
+
+    /// The extra of the parent package that activates the edge, if any.
+    pub fn extra(&self) -> Option<&'env ExtraName> {
+        self.edge.required_extra()
+    }
+
+    /// The dependency group of the parent package that contains the edge, if any.
+    pub fn group(&self) -> Option<&'env GroupName> {
+        match self.edge {
+            Edge::Dev(group, ..) => Some(group),
+            Edge::Prod(..) | Edge::Optional(..) => None,
+        }
+    }
+
+    /// The version specifier of the requirement behind the edge, if recorded in the lockfile.
+    ///
+    /// The lockfile only records the requirements of workspace members and packages from mutable
+    /// sources (e.g., local directories), so edges from other packages have no specifier.
+    pub fn specifier(&self) -> Option<&str> {
+        self.specifier.as_deref()
+    }
+
+    /// The marker under which the edge applies, if any.
+    pub fn marker(&self) -> Option<String> {
+        SimplifiedMarkerTree::new(self.requires_python, self.edge.marker().pep508()).try_to_string()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct VisitedNode<'env> {
     package_id: &'env PackageId,
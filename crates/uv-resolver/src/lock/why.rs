@@ -0,0 +1,569 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_distribution_types::{Requirement, SimplifiedMarkerTree};
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::{MarkerTree, VersionOrUrl};
+use uv_pypi_types::VerbatimParsedUrl;
+
+use crate::lock::{Dependency, Package, PackageId, canonical_marker_trees, requirement_specifier};
+use crate::{ConflictMarker, Lock, UniversalMarker};
+
+/// The set of dependency paths that lead from the workspace roots to a given package.
+#[derive(Debug)]
+pub struct WhyDisplay<'env> {
+    /// The lockfile that the paths were computed from.
+    lock: &'env Lock,
+    /// The package being explained.
+    target: &'env PackageName,
+    /// The dependency paths that lead to the target package.
+    paths: Vec<DependencyPath<'env>>,
+}
+
+impl<'env> WhyDisplay<'env> {
+    /// Compute every dependency path from the workspace roots to the given package.
+    ///
+    /// All extras and dependency groups are considered, such that the paths explain why the
+    /// package is present in the lockfile rather than in any one environment.
+    pub fn new(lock: &'env Lock, target: &'env PackageName) -> Self {
+        // Identify any workspace members, as in `uv tree`.
+        let members: BTreeSet<&PackageId> = lock
+            .member_packages()
+            .into_iter()
+            .map(|package| &package.id)
+            .collect();
+
+        // Identify the packages from which the target is reachable, to avoid exploring paths that
+        // can never lead to it.
+        let reachable = {
+            let mut parents: FxHashMap<&PackageId, Vec<&PackageId>> = FxHashMap::default();
+            for package in &lock.packages {
+                for dep in package
+                    .dependencies
+                    .iter()
+                    .chain(package.optional_dependencies.values().flatten())
+                    .chain(package.dependency_groups.values().flatten())
+                {
+                    parents
+                        .entry(&dep.package_id)
+                        .or_default()
+                        .push(&package.id);
+                }
+            }
+
+            let mut reachable = lock
+                .packages
+                .iter()
+                .filter(|package| package.id.name == *target)
+                .map(|package| &package.id)
+                .collect::<FxHashSet<_>>();
+            let mut stack = reachable.iter().copied().collect::<Vec<_>>();
+            while let Some(id) = stack.pop() {
+                for parent in parents.get(id).into_iter().flatten() {
+                    if reachable.insert(parent) {
+                        stack.push(parent);
+                    }
+                }
+            }
+            reachable
+        };
+
+        let mut walker = Walker {
+            lock,
+            target,
+            reachable,
+            path: Vec::new(),
+            paths: Vec::new(),
+        };
+
+        // Conflict extras and groups are encoded as marker expressions. Include the declared
+        // mutual-exclusion constraints when checking whether a path is satisfiable.
+        let conflict_marker = UniversalMarker::new(
+            MarkerTree::TRUE,
+            ConflictMarker::from_conflicts(lock.conflicts()),
+        );
+
+        // Walk from each workspace member, with all of its extras and groups enabled.
+        for id in &members {
+            let package = lock.find_by_id(id);
+            if id.name == *target {
+                walker.paths.push(DependencyPath {
+                    root: Some(id),
+                    steps: Vec::new(),
+                    marker: conflict_marker,
+                });
+                continue;
+            }
+            walker.visit(package, Some(id), &Activated::All, true, conflict_marker);
+        }
+
+        // Walk from any requirements attached to the workspace itself, e.g., the `dependencies`
+        // of a PEP 723 script or the `[dependency-groups]` of a virtual workspace root.
+        let requirements = lock
+            .requirements()
+            .iter()
+            .map(|requirement| (None, requirement))
+            .chain(
+                lock.dependency_groups()
+                    .iter()
+                    .flat_map(|(group, requirements)| {
+                        requirements
+                            .iter()
+                            .map(move |requirement| (Some(group), requirement))
+                    }),
+            );
+        for (group, requirement) in requirements {
+            for package in lock
+                .packages
+                .iter()
+                .filter(|package| package.id.name == requirement.name)
+            {
+                if !walker.reachable.contains(&package.id) {
+                    continue;
+                }
+                let Some(marker) = lock.root_requirement_marker(requirement, package) else {
+                    continue;
+                };
+                let mut marker = UniversalMarker::from_combined(marker);
+                marker.and(conflict_marker);
+                if marker.is_false() {
+                    continue;
+                }
+
+                walker.path.push(PathStep {
+                    package: &package.id,
+                    extras: requirement.extras.iter().collect(),
+                    kind: group.map_or(StepKind::Prod, StepKind::Group),
+                    parent: None,
+                    specifier: Some(requirement_specifier(requirement)),
+                    marker: None,
+                });
+                if package.id.name == *target {
+                    walker.record(None, marker);
+                } else {
+                    let activated = Activated::Some(requirement.extras.iter().collect());
+                    walker.visit(package, None, &activated, false, marker);
+                }
+                walker.path.pop();
+            }
+        }
+
+        let mut paths = walker.paths;
+        paths.sort_by_cached_key(ToString::to_string);
+        paths.dedup_by(|a, b| a.to_string() == b.to_string());
+
+        Self {
+            lock,
+            target,
+            paths,
+        }
+    }
+
+    /// Returns the packages from immutable sources (e.g., registries) that declare an edge along
+    /// any of the paths.
+    ///
+    /// The lockfile doesn't record the requirements of these packages, so their metadata must be
+    /// read to determine the specifiers on their edges (see [`WhyDisplay::with_requires_dist`]).
+    pub fn immutable_parents(&self) -> Vec<&'env Package> {
+        self.paths
+            .iter()
+            .flat_map(|path| &path.steps)
+            .filter_map(|step| step.parent.map(|(parent, _)| parent))
+            .filter(|parent| parent.id.source.is_immutable())
+            .unique_by(|parent| &parent.id)
+            .collect()
+    }
+
+    /// Fill in the specifiers on the edges declared by packages from immutable sources, given the
+    /// requirements of each such package, if known.
+    #[must_use]
+    pub fn with_requires_dist<'metadata>(
+        mut self,
+        requires_dist: impl Fn(
+            &'env Package,
+        )
+            -> Option<&'metadata [uv_pep508::Requirement<VerbatimParsedUrl>]>,
+    ) -> Self {
+        for step in self.paths.iter_mut().flat_map(|path| &mut path.steps) {
+            let Some((parent, dep)) = step.parent else {
+                continue;
+            };
+            if !parent.id.source.is_immutable() {
+                continue;
+            }
+            let Some(requirements) = requires_dist(parent) else {
+                continue;
+            };
+
+            // As for recorded requirements, prefer the requirement whose marker overlaps with
+            // that of the dependency edge.
+            let mut candidates = requirements
+                .iter()
+                .filter(|requirement| {
+                    requirement.name == dep.package_id.name
+                        && requirement.marker.top_level_extra_name().as_deref() == step.extra()
+                })
+                .peekable();
+            let first = candidates.peek().copied();
+            let requirement = candidates
+                .find(|requirement| {
+                    !requirement
+                        .marker
+                        .without_extras()
+                        .is_disjoint(dep.complexified_marker.pep508())
+                })
+                .or(first);
+
+            step.specifier = requirement.map(|requirement| match &requirement.version_or_url {
+                Some(VersionOrUrl::VersionSpecifier(specifier)) if !specifier.is_empty() => {
+                    specifier.to_string()
+                }
+                Some(VersionOrUrl::Url(url)) => url.to_string(),
+                Some(VersionOrUrl::VersionSpecifier(_)) | None => "*".to_string(),
+            });
+        }
+        self
+    }
+
+    /// Returns `true` if no path leads to the target package.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns the versions of the target package that are present in the lockfile.
+    pub fn versions(&self) -> impl Iterator<Item = &'env Version> {
+        self.lock
+            .packages
+            .iter()
+            .filter(|package| package.id.name == *self.target)
+            .filter_map(|package| package.id.version.as_ref())
+    }
+
+    /// Group the dependency paths by the resolution fork(s) under which they apply.
+    ///
+    /// If the lockfile was produced by a single-fork resolution, a single group is returned with
+    /// no marker.
+    pub fn forks(&self) -> Vec<ForkPaths<'_, 'env>> {
+        let requires_python = self.lock.requires_python();
+        let forks = canonical_marker_trees(self.lock.fork_markers(), requires_python);
+        if forks.is_empty() {
+            return vec![ForkPaths {
+                marker: None,
+                paths: self.paths.iter().collect(),
+            }];
+        }
+
+        forks
+            .into_iter()
+            .filter_map(|fork| {
+                let universal = UniversalMarker::from_combined(
+                    SimplifiedMarkerTree::new(requires_python, fork).into_marker(requires_python),
+                );
+                let paths = self
+                    .paths
+                    .iter()
+                    .filter(|path| !path.marker.is_disjoint(universal))
+                    .collect::<Vec<_>>();
+                if paths.is_empty() {
+                    None
+                } else {
+                    Some(ForkPaths {
+                        marker: fork.try_to_string(),
+                        paths,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// The dependency paths that apply under a single resolution fork.
+#[derive(Debug)]
+pub struct ForkPaths<'a, 'env> {
+    marker: Option<String>,
+    paths: Vec<&'a DependencyPath<'env>>,
+}
+
+impl<'a, 'env> ForkPaths<'a, 'env> {
+    /// The marker of the fork, or `None` if the resolution did not fork.
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+
+    /// The dependency paths that apply under the fork.
+    pub fn paths(&self) -> &[&'a DependencyPath<'env>] {
+        &self.paths
+    }
+}
+
+/// A single path from a workspace root to the target package.
+#[derive(Debug)]
+pub struct DependencyPath<'env> {
+    /// The workspace member at which the path starts, or `None` if the path starts at a
+    /// requirement attached to the workspace itself.
+    root: Option<&'env PackageId>,
+    /// The packages along the path, ending at the target package.
+    steps: Vec<PathStep<'env>>,
+    /// The conjunction of the markers along the path.
+    marker: UniversalMarker,
+}
+
+impl<'env> DependencyPath<'env> {
+    /// The name and version of the workspace member at which the path starts, if any.
+    pub fn root(&self) -> Option<(&'env PackageName, Option<&'env Version>)> {
+        self.root.map(|id| (&id.name, id.version.as_ref()))
+    }
+
+    /// The edges along the path.
+    pub fn steps(&self) -> &[PathStep<'env>] {
+        &self.steps
+    }
+}
+
+impl std::fmt::Display for DependencyPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let root = self.root.map(|id| {
+            let mut line = id.name.to_string();
+            if let Some(version) = &id.version {
+                let _ = write!(line, " v{version}");
+            }
+            line
+        });
+        let steps = self.steps.iter().map(ToString::to_string);
+        write!(f, "{}", root.into_iter().chain(steps).join(" -> "))
+    }
+}
+
+/// An edge in a [`DependencyPath`], along with the package that it leads to.
+#[derive(Debug, Clone)]
+pub struct PathStep<'env> {
+    /// The package that the edge leads to.
+    package: &'env PackageId,
+    /// The extras requested on the package.
+    extras: Vec<&'env ExtraName>,
+    /// The kind of dependency that the edge represents.
+    kind: StepKind<'env>,
+    /// The package that declares the edge, along with the locked dependency, or `None` if the
+    /// edge starts at a requirement attached to the workspace itself.
+    parent: Option<(&'env Package, &'env Dependency)>,
+    /// The version specifier that constrains the edge, if known.
+    specifier: Option<String>,
+    /// The marker under which the edge applies, if any.
+    marker: Option<String>,
+}
+
+impl<'env> PathStep<'env> {
+    /// The name of the package that the edge leads to.
+    pub fn name(&self) -> &'env PackageName {
+        &self.package.name
+    }
+
+    /// The locked version of the package that the edge leads to.
+    pub fn version(&self) -> Option<&'env Version> {
+        self.package.version.as_ref()
+    }
+
+    /// The extras requested on the package.
+    pub fn extras(&self) -> &[&'env ExtraName] {
+        &self.extras
+    }
+
+    /// The extra of the parent package that activates the edge, if any.
+    pub fn extra(&self) -> Option<&'env ExtraName> {
+        match self.kind {
+            StepKind::Optional(extra) => Some(extra),
+            StepKind::Prod | StepKind::Group(_) => None,
+        }
+    }
+
+    /// The dependency group of the parent package that contains the edge, if any.
+    pub fn group(&self) -> Option<&'env GroupName> {
+        match self.kind {
+            StepKind::Group(group) => Some(group),
+            StepKind::Prod | StepKind::Optional(_) => None,
+        }
+    }
+
+    /// The version specifier that constrains the edge, if known.
+    ///
+    /// Specifiers are recorded in the lockfile for requirements declared by workspace members and
+    /// other mutable (e.g., local) sources. For packages from immutable sources, they're only
+    /// known once read from the package metadata (see [`WhyDisplay::with_requires_dist`]).
+    pub fn specifier(&self) -> Option<&str> {
+        self.specifier.as_deref()
+    }
+
+    /// The marker under which the edge applies, if any.
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+}
+
+impl std::fmt::Display for PathStep<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.package.name)?;
+        if !self.extras.is_empty() {
+            write!(f, "[{}]", self.extras.iter().join(", "))?;
+        }
+        if let Some(version) = &self.package.version {
+            write!(f, " v{version}")?;
+        }
+
+        let mut annotations = Vec::new();
+        match self.kind {
+            StepKind::Prod => {}
+            StepKind::Optional(extra) => annotations.push(format!("extra: {extra}")),
+            StepKind::Group(group) => annotations.push(format!("group: {group}")),
+        }
+        if let Some(specifier) = &self.specifier {
+            annotations.push(format!("specifier: {specifier}"));
+        }
+        if !annotations.is_empty() {
+            write!(f, " ({})", annotations.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum StepKind<'env> {
+    Prod,
+    Optional(&'env ExtraName),
+    Group(&'env GroupName),
+}
+
+/// The extras that are enabled on a package as it is traversed.
+enum Activated<'env> {
+    /// All extras are enabled, as for workspace members.
+    All,
+    /// Only the given extras are enabled.
+    Some(BTreeSet<&'env ExtraName>),
+}
+
+impl Activated<'_> {
+    fn contains(&self, extra: &ExtraName) -> bool {
+        match self {
+            Self::All => true,
+            Self::Some(extras) => extras.contains(extra),
+        }
+    }
+}
+
+/// A depth-first traversal of the lockfile that records every path to the target package.
+struct Walker<'env> {
+    lock: &'env Lock,
+    target: &'env PackageName,
+    reachable: FxHashSet<&'env PackageId>,
+    path: Vec<PathStep<'env>>,
+    paths: Vec<DependencyPath<'env>>,
+}
+
+impl<'env> Walker<'env> {
+    fn visit(
+        &mut self,
+        package: &'env Package,
+        root: Option<&'env PackageId>,
+        activated: &Activated<'env>,
+        is_root: bool,
+        marker: UniversalMarker,
+    ) {
+        let prod = package.dependencies.iter().map(|dep| (StepKind::Prod, dep));
+        let optional = package
+            .optional_dependencies
+            .iter()
+            .filter(|(extra, _)| activated.contains(extra))
+            .flat_map(|(extra, deps)| deps.iter().map(|dep| (StepKind::Optional(extra), dep)));
+        let groups = package
+            .dependency_groups
+            .iter()
+            .filter(|_| is_root)
+            .flat_map(|(group, deps)| deps.iter().map(|dep| (StepKind::Group(group), dep)));
+
+        for (kind, dep) in prod.chain(optional).chain(groups) {
+            if !self.reachable.contains(&dep.package_id) {
+                continue;
+            }
+
+            // Avoid cycles.
+            if root == Some(&dep.package_id)
+                || self.path.iter().any(|step| step.package == &dep.package_id)
+            {
+                continue;
+            }
+
+            // Skip any edges that cannot coexist with the path so far.
+            let mut marker = marker;
+            marker.and(dep.complexified_marker);
+            if marker.is_false() {
+                continue;
+            }
+
+            self.path.push(PathStep {
+                package: &dep.package_id,
+                extras: dep.extra.iter().collect(),
+                kind,
+                parent: Some((package, dep)),
+                specifier: Self::requirement(package, kind, dep).map(requirement_specifier),
+                marker: dep.simplified_marker.try_to_string(),
+            });
+            if dep.package_id.name == *self.target {
+                self.record(root, marker);
+            } else {
+                let package = self.lock.find_by_id(&dep.package_id);
+                let activated = Activated::Some(dep.extra.iter().collect());
+                self.visit(package, root, &activated, false, marker);
+            }
+            self.path.pop();
+        }
+    }
+
+    /// Record the current path.
+    fn record(&mut self, root: Option<&'env PackageId>, marker: UniversalMarker) {
+        self.paths.push(DependencyPath {
+            root,
+            steps: self.path.clone(),
+            marker,
+        });
+    }
+
+    /// Find the requirement in the package metadata that introduced the given dependency.
+    fn requirement(
+        package: &'env Package,
+        kind: StepKind<'env>,
+        dep: &Dependency,
+    ) -> Option<&'env Requirement> {
+        let (extra, group) = match kind {
+            StepKind::Prod => (None, None),
+            StepKind::Optional(extra) => (Some(extra), None),
+            StepKind::Group(group) => (None, Some(group)),
+        };
+        package.dependency_requirement(
+            &dep.package_id.name,
+            extra,
+            group,
+            dep.complexified_marker.pep508(),
+        )
+    }
+}
+
+impl std::fmt::Display for WhyDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for fork in self.forks() {
+            let indent = if let Some(marker) = fork.marker() {
+                writeln!(f, "{}", format!("{marker}:").bold())?;
+                "    "
+            } else {
+                ""
+            };
+            for path in fork.paths() {
+                writeln!(f, "{indent}{path}")?;
+            }
+        }
+        Ok(())
+    }
+}
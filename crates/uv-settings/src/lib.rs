@@ -307,6 +307,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dev_dependencies,
         default_groups,
         dependency_groups,
+        tasks,
         managed,
         package,
         build_backend,
@@ -341,6 +342,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "dependency-groups",
         ));
     }
+    if tasks.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "tasks"));
+    }
     if managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
@@ -382,6 +386,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                remote_cache,
                 preview,
                 python_preference,
                 python_downloads,
@@ -456,6 +461,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dev_dependencies: _,
         default_groups: _,
         dependency_groups: _,
+        tasks: _,
         managed: _,
         package: _,
         build_backend: _,
@@ -481,6 +487,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if remote_cache.is_some() {
+        masked_fields.push("remote-cache");
+    }
     match preview {
         Some(PreviewOption::Preview(_)) => masked_fields.push("preview"),
         Some(PreviewOption::PreviewFeatures(_)) => masked_fields.push("preview-features"),
@@ -689,6 +698,8 @@ pub struct Concurrency {
     pub downloads: Option<NonZeroUsize>,
     pub builds: Option<NonZeroUsize>,
     pub installs: Option<NonZeroUsize>,
+    pub download_ranges: Option<NonZeroUsize>,
+    pub runs: Option<NonZeroUsize>,
 }
 
 /// A boolean flag parsed from an environment variable.
@@ -813,6 +824,11 @@ impl EnvironmentOptions {
                     EnvVars::UV_CONCURRENT_INSTALLS,
                     None,
                 )?,
+                download_ranges: parse_integer_environment_variable(
+                    EnvVars::UV_CONCURRENT_DOWNLOAD_RANGES,
+                    None,
+                )?,
+                runs: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_RUNS, None)?,
             },
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: parse_string_environment_variable(
@@ -2,12 +2,15 @@
 use std::borrow::Cow;
 use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
+use jiff::civil::Date;
 use serde::{Deserialize, Serialize};
 
+use uv_cache::RemoteCache;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    AdverseStatus, BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, ProxyUrl, Reinstall, RequiredVersion, Severity, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, Origin,
@@ -184,6 +187,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) dependency_groups: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) tasks: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) managed: Option<serde::de::IgnoredAny>,
 
@@ -329,6 +335,22 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// A shared cache for built wheels, as a path to a directory (e.g., on a network file system)
+    /// or an `http://` or `https://` URL that supports `GET` and `PUT` requests.
+    ///
+    /// When a source distribution is built, the resulting wheel is uploaded to the remote cache.
+    /// On a local cache miss, uv will check the remote cache for a compatible wheel before
+    /// building the source distribution. Failures to read from or write to the remote cache are
+    /// not fatal.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            remote-cache = "https://cache.example.com/uv"
+        "#
+    )]
+    pub remote_cache: Option<RemoteCache>,
 
     /// The user's preview configuration.
     #[serde(flatten)]
@@ -447,6 +469,7 @@ struct GlobalOptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    remote_cache: Option<RemoteCache>,
 
     preview: Option<bool>,
     preview_features: Option<PreviewFeaturesOption>,
@@ -474,6 +497,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             offline,
             no_cache,
             cache_dir,
+            remote_cache,
             preview,
             preview_features,
             python_preference,
@@ -494,6 +518,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             offline,
             no_cache,
             cache_dir,
+            remote_cache,
             preview: PreviewOption::try_from(preview, preview_features)?,
             python_preference,
             python_downloads,
@@ -2453,6 +2478,7 @@ struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    remote_cache: Option<RemoteCache>,
     preview: Option<bool>,
     preview_features: Option<PreviewFeaturesOption>,
     python_preference: Option<PythonPreference>,
@@ -2540,6 +2566,7 @@ struct OptionsWire {
     r#package: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
+    tasks: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
 
     // Build backend
@@ -2558,6 +2585,7 @@ impl TryFrom<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            remote_cache,
             preview,
             preview_features,
             python_preference,
@@ -2619,6 +2647,7 @@ impl TryFrom<OptionsWire> for Options {
             sources,
             default_groups,
             dependency_groups,
+            tasks,
             extra_build_dependencies,
             extra_build_variables,
             dev_dependencies,
@@ -2637,6 +2666,7 @@ impl TryFrom<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                remote_cache,
                 preview: PreviewOption::try_from(preview, preview_features)?,
                 python_preference,
                 python_downloads,
@@ -2710,6 +2740,7 @@ impl TryFrom<OptionsWire> for Options {
             dev_dependencies,
             default_groups,
             dependency_groups,
+            tasks,
             managed,
             package,
         })
@@ -2824,6 +2855,73 @@ pub struct AuditOptions {
         "#
     )]
     pub ignore_until_fixed: Option<Vec<String>>,
+
+    /// A list of vulnerabilities to suppress during auditing, along with the reason for each
+    /// suppression.
+    ///
+    /// Each suppression requires an `id` (matched against vulnerability IDs and aliases) and a
+    /// `reason`. A suppression can be limited to a single `package`, and can be given an
+    /// `expires` date, after which the vulnerability is reported again.
+    ///
+    /// Suppressed vulnerabilities are reported as `not_affected`, with the given reason, in VEX
+    /// output formats.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            suppress = [
+                { id = "PYSEC-2022-43017", reason = "The vulnerable parser is never used", package = "requests", expires = "2026-06-30" },
+            ]
+        "#
+    )]
+    pub suppress: Option<Vec<AuditSuppression>>,
+
+    /// The minimum severity for a vulnerability to fail the audit.
+    ///
+    /// Severities are derived from the CVSS v3 base score of a vulnerability: `low` (0.1 to 3.9),
+    /// `medium` (4.0 to 6.9), `high` (7.0 to 8.9), or `critical` (9.0 to 10.0). For example,
+    /// with `high`, only vulnerabilities with a score of at least 7.0 fail the audit.
+    ///
+    /// Vulnerabilities below the threshold are still reported. Vulnerabilities without a CVSS v3
+    /// score always fail the audit.
+    #[option(
+        default = "\"low\"",
+        value_type = "str",
+        example = r#"
+            severity-threshold = "high"
+        "#
+    )]
+    pub severity_threshold: Option<Severity>,
+
+    /// The adverse project statuses that fail the audit.
+    ///
+    /// Accepts `archived`, `quarantined`, and `deprecated`. By default, adverse project statuses
+    /// are reported, but don't fail the audit.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            fail-on-status = ["quarantined", "archived"]
+        "#
+    )]
+    pub fail_on_status: Option<Vec<AdverseStatus>>,
+}
+
+/// A vulnerability to suppress during auditing.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AuditSuppression {
+    /// The ID of the vulnerability to suppress, e.g., `PYSEC-2022-43017`.
+    pub id: String,
+    /// The reason for suppressing the vulnerability.
+    pub reason: String,
+    /// The package in which to suppress the vulnerability. If omitted, the vulnerability is
+    /// suppressed in all packages.
+    pub package: Option<PackageName>,
+    /// The date after which the suppression expires, e.g., `2026-06-30`.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub expires: Option<Date>,
 }
 
 #[derive(Debug, Clone)]
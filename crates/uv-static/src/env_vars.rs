@@ -68,6 +68,11 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--remote-cache` command-line argument. If set, uv will use this
+    /// directory or URL as a shared cache for built wheels.
+    #[attr_added_in("0.11.27")]
+    pub const UV_REMOTE_CACHE: &'static str = "UV_REMOTE_CACHE";
+
     /// The directory for storage of credentials when using a plain text backend.
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
@@ -412,6 +417,16 @@ impl EnvVars {
     #[attr_added_in("0.1.43")]
     pub const UV_CONCURRENT_DOWNLOADS: &'static str = "UV_CONCURRENT_DOWNLOADS";
 
+    /// Sets the number of byte ranges that uv will fetch in parallel when resuming a large wheel
+    /// download with HTTP range requests. (default: 1)
+    #[attr_added_in("0.11.27")]
+    pub const UV_CONCURRENT_DOWNLOAD_RANGES: &'static str = "UV_CONCURRENT_DOWNLOAD_RANGES";
+
+    /// Sets the maximum number of workspace members in which `uv run --all-packages` will run
+    /// the command concurrently. (default: the number of available CPU cores)
+    #[attr_added_in("0.11.27")]
+    pub const UV_CONCURRENT_RUNS: &'static str = "UV_CONCURRENT_RUNS";
+
     /// Sets the maximum number of source distributions that uv will build
     /// concurrently at any given time.
     #[attr_added_in("0.1.43")]
@@ -664,6 +679,18 @@ impl EnvVars {
     #[attr_added_in("0.11.22")]
     pub const UV_INTERNAL__BUILD_DIR: &'static str = "UV_INTERNAL__BUILD_DIR";
 
+    /// Used to pass the directory with the artifacts of the build hook from the `uv_build` Python
+    /// shim to the build backend.
+    #[attr_hidden]
+    #[attr_added_in("0.11.27")]
+    pub const UV_INTERNAL__BUILD_HOOK_DIR: &'static str = "UV_INTERNAL__BUILD_HOOK_DIR";
+
+    /// Used to pass the wheel tag of the build interpreter from the `uv_build` Python shim to the
+    /// build backend when using a build hook.
+    #[attr_hidden]
+    #[attr_added_in("0.11.27")]
+    pub const UV_INTERNAL__BUILD_HOOK_WHEEL_TAG: &'static str = "UV_INTERNAL__BUILD_HOOK_WHEEL_TAG";
+
     /// Used to force showing the derivation tree during resolver error reporting.
     #[attr_hidden]
     #[attr_added_in("0.3.0")]
@@ -899,6 +926,13 @@ impl EnvVars {
     #[attr_added_in("0.1.42")]
     pub const MACOSX_DEPLOYMENT_TARGET: &'static str = "MACOSX_DEPLOYMENT_TARGET";
 
+    /// Sets the modification time of the files in source distributions and wheels built by the
+    /// uv build backend, as a Unix timestamp.
+    ///
+    /// See [reproducible-builds.org](https://reproducible-builds.org/docs/source-date-epoch/).
+    #[attr_added_in("0.11.27")]
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Used with `--python-platform arm64-apple-ios` and related variants to set the
     /// deployment target (i.e., the minimum supported iOS version).
     ///
@@ -1286,6 +1286,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip audit` command with options shared across scenarios.
+    pub fn pip_audit(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("audit");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = self.new_command();
         command.arg("pip").arg("list");
@@ -1309,6 +1317,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip download` command with options shared across scenarios.
+    pub fn pip_download(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("download");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -1617,6 +1633,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv why` command with options shared across scenarios.
+    pub fn why(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("why");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv outdated` command with options shared across scenarios.
+    pub fn outdated(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("outdated");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -1641,6 +1673,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache inspect` command.
+    pub fn cache_inspect(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("inspect");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index serve` command.
+    pub fn index_serve(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("serve");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
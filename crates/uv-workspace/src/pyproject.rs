@@ -418,6 +418,29 @@ pub struct ToolUv {
     )]
     pub(crate) dependency_groups: Option<ToolUvDependencyGroups>,
 
+    /// Tasks that can be run in the project environment with `uv run --task <name>`.
+    ///
+    /// A task is either a command string, or a table with the following keys:
+    ///
+    /// - `cmd`: The command to run. Arguments are split according to POSIX shell quoting rules,
+    ///   but the command is not run in a shell.
+    /// - `env`: Environment variables to set when running the command.
+    /// - `groups`: Dependency groups to include when syncing the environment for the task.
+    /// - `depends-on`: Other tasks to run (in order) before the task itself.
+    /// - `description`: A description of the task, as displayed by `uv workspace list --tasks`.
+    ///
+    /// Additional arguments passed to `uv run --task` are appended to the task's command.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.tasks]
+            lint = { cmd = "ruff check", groups = ["lint"] }
+            test = { cmd = "pytest", env = { PYTHONHASHSEED = "0" }, depends-on = ["lint"] }
+        "#
+    )]
+    pub tasks: Option<ToolUvTasks>,
+
     /// The project's development dependencies.
     ///
     /// Development dependencies will be installed by default in `uv run` and `uv sync`, but will
@@ -814,6 +837,155 @@ pub(crate) struct DependencyGroupSettings {
     pub(crate) requires_python: Option<VersionSpecifiers>,
 }
 
+/// The tasks defined in `tool.uv.tasks`, keyed by name.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ToolUvTasks(BTreeMap<String, Task>);
+
+impl ToolUvTasks {
+    /// Returns the underlying `BTreeMap` of task names to tasks.
+    pub fn inner(&self) -> &BTreeMap<String, Task> {
+        &self.0
+    }
+
+    /// Returns the named task, preceded by the tasks it depends on (transitively), in the order
+    /// in which they should run.
+    ///
+    /// Each task is included at most once, even if multiple tasks depend on it.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> Result<Vec<(&'a str, &'a Task)>, TaskError> {
+        let mut resolved = Vec::new();
+        let mut stack = Vec::new();
+        self.visit(name, &mut stack, &mut resolved)?;
+        Ok(resolved)
+    }
+
+    /// Visit a task in depth-first order, after its dependencies.
+    fn visit<'a>(
+        &'a self,
+        name: &'a str,
+        stack: &mut Vec<&'a str>,
+        resolved: &mut Vec<(&'a str, &'a Task)>,
+    ) -> Result<(), TaskError> {
+        if resolved.iter().any(|(visited, _)| *visited == name) {
+            return Ok(());
+        }
+        if let Some(start) = stack.iter().position(|visiting| *visiting == name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(name);
+            return Err(TaskError::Cycle(cycle.join(" -> ")));
+        }
+        let Some(task) = self.0.get(name) else {
+            return Err(match stack.last() {
+                Some(parent) => TaskError::MissingDependency {
+                    task: (*parent).to_string(),
+                    dependency: name.to_string(),
+                },
+                None => TaskError::NotFound(name.to_string()),
+            });
+        };
+
+        stack.push(name);
+        for dependency in &task.depends_on {
+            self.visit(dependency, stack, resolved)?;
+        }
+        stack.pop();
+
+        resolved.push((name, task));
+        Ok(())
+    }
+}
+
+/// Ensure that all keys in the TOML table are unique.
+impl<'de> serde::de::Deserialize<'de> for ToolUvTasks {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_unique_map(deserializer, |key: &String| {
+            format!("duplicate task `{key}`")
+        })
+        .map(ToolUvTasks)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum TaskError {
+    #[error("Task `{0}` is not defined in `tool.uv.tasks`")]
+    NotFound(String),
+    #[error("Task `{task}` depends on `{dependency}`, which is not defined in `tool.uv.tasks`")]
+    MissingDependency { task: String, dependency: String },
+    #[error("Detected a cycle in `tool.uv.tasks`: {0}")]
+    Cycle(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+enum TaskWire {
+    /// A command to run.
+    Command(String),
+    /// A command, along with the environment in which to run it and the tasks to run first.
+    #[serde(rename_all = "kebab-case")]
+    Table {
+        cmd: Option<String>,
+        #[serde(default)]
+        env: BTreeMap<String, String>,
+        #[serde(default)]
+        groups: Vec<GroupName>,
+        #[serde(default)]
+        depends_on: Vec<String>,
+        description: Option<String>,
+    },
+}
+
+/// A task in `tool.uv.tasks`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "TaskWire")]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Task {
+    /// The command to run, if any.
+    ///
+    /// A task without a command can be used to group other tasks via `depends-on`.
+    pub cmd: Option<String>,
+    /// Environment variables to set when running the command.
+    pub env: BTreeMap<String, String>,
+    /// Dependency groups to include in the environment.
+    pub groups: Vec<GroupName>,
+    /// The tasks to run before this one.
+    pub depends_on: Vec<String>,
+    /// A description of the task.
+    pub description: Option<String>,
+}
+
+impl From<TaskWire> for Task {
+    fn from(wire: TaskWire) -> Self {
+        match wire {
+            TaskWire::Command(cmd) => Self {
+                cmd: Some(cmd),
+                env: BTreeMap::new(),
+                groups: Vec::new(),
+                depends_on: Vec::new(),
+                description: None,
+            },
+            TaskWire::Table {
+                cmd,
+                env,
+                groups,
+                depends_on,
+                description,
+            } => Self {
+                cmd,
+                env,
+                groups,
+                depends_on,
+                description,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged, rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
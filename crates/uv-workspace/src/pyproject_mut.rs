@@ -131,7 +131,8 @@ impl Display for AddBoundsKind {
 }
 
 impl AddBoundsKind {
-    fn specifiers(self, version: Version) -> VersionSpecifiers {
+    /// Returns the version specifiers for the given version under this kind of bound.
+    pub fn specifiers(self, version: Version) -> VersionSpecifiers {
         // Nomenclature: "major" is the most significant component of the version, "minor" is the
         // second most significant component, so most versions are either major.minor.patch or
         // 0.major.minor.
@@ -1266,6 +1267,90 @@ impl PyProjectTomlMut {
         types
     }
 
+    /// Returns all the dependencies in this `pyproject.toml`, along with their type and their
+    /// position in the corresponding array.
+    ///
+    /// This method searches `project.dependencies`, `project.optional-dependencies`,
+    /// `dependency-groups`, and `tool.uv.dev-dependencies`. Entries that aren't valid PEP 508
+    /// requirements (e.g., `include-group` tables) are skipped.
+    pub fn dependencies(&self) -> Vec<(DependencyType, usize, Requirement)> {
+        fn parse(dependencies: &Array) -> impl Iterator<Item = (usize, Requirement)> {
+            dependencies
+                .iter()
+                .enumerate()
+                .filter_map(|(index, dependency)| {
+                    let requirement = dependency.as_str().and_then(try_parse_requirement)?;
+                    Some((index, requirement))
+                })
+        }
+
+        let mut dependencies = Vec::new();
+
+        if let Some(project) = self.doc.get("project").and_then(Item::as_table) {
+            // Check `project.dependencies`.
+            if let Some(array) = project.get("dependencies").and_then(Item::as_array) {
+                dependencies.extend(
+                    parse(array).map(|(index, req)| (DependencyType::Production, index, req)),
+                );
+            }
+
+            // Check `project.optional-dependencies`.
+            if let Some(extras) = project
+                .get("optional-dependencies")
+                .and_then(Item::as_table_like)
+            {
+                for (extra, array) in extras.iter() {
+                    let Some(array) = array.as_array() else {
+                        continue;
+                    };
+                    let Ok(extra) = ExtraName::from_str(extra) else {
+                        continue;
+                    };
+                    dependencies.extend(
+                        parse(array).map(|(index, req)| {
+                            (DependencyType::Optional(extra.clone()), index, req)
+                        }),
+                    );
+                }
+            }
+        }
+
+        // Check `dependency-groups`.
+        if let Some(groups) = self
+            .doc
+            .get("dependency-groups")
+            .and_then(Item::as_table_like)
+        {
+            for (group, array) in groups.iter() {
+                let Some(array) = array.as_array() else {
+                    continue;
+                };
+                let Ok(group) = GroupName::from_str(group) else {
+                    continue;
+                };
+                dependencies.extend(
+                    parse(array)
+                        .map(|(index, req)| (DependencyType::Group(group.clone()), index, req)),
+                );
+            }
+        }
+
+        // Check `tool.uv.dev-dependencies`.
+        if let Some(array) = self
+            .doc
+            .get("tool")
+            .and_then(Item::as_table)
+            .and_then(|tool| tool.get("uv"))
+            .and_then(Item::as_table)
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array)
+        {
+            dependencies.extend(parse(array).map(|(index, req)| (DependencyType::Dev, index, req)));
+        }
+
+        dependencies
+    }
+
     pub fn version(&mut self) -> Result<Version, Error> {
         let version = self
             .doc
@@ -1875,10 +1960,12 @@ mod test {
     use std::str::FromStr;
     use toml_edit::DocumentMut;
     use uv_distribution_types::Index;
-    use uv_normalize::PackageName;
+    use uv_normalize::{ExtraName, GroupName, PackageName};
     use uv_pep440::Version;
     use uv_pep508::Requirement;
 
+    use crate::pyproject::DependencyType;
+
     #[test]
     fn split() {
         assert_eq!(split_specifiers("flask>=1.0"), ("flask", ">=1.0"));
@@ -2393,4 +2480,58 @@ format = "flat"
 
         Ok(())
     }
+
+    #[test]
+    fn dependencies_lists_all_dependency_tables() -> Result<()> {
+        let toml = r#"
+[project]
+name = "project"
+dependencies = ["anyio>=3", "iniconfig"]
+
+[project.optional-dependencies]
+cli = ["rich"]
+
+[dependency-groups]
+dev = [{ include-group = "lint" }, "pytest>=8"]
+lint = ["ruff"]
+
+[tool.uv]
+dev-dependencies = ["typing-extensions"]
+"#;
+
+        let doc = PyProjectTomlMut::from_toml(toml, DependencyTarget::PyProjectToml)?;
+
+        let dependencies = doc
+            .dependencies()
+            .into_iter()
+            .map(|(dependency_type, index, requirement)| {
+                (dependency_type, index, requirement.to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dependencies,
+            vec![
+                (DependencyType::Production, 0, "anyio>=3".to_string()),
+                (DependencyType::Production, 1, "iniconfig".to_string()),
+                (
+                    DependencyType::Optional(ExtraName::from_str("cli")?),
+                    0,
+                    "rich".to_string()
+                ),
+                (
+                    DependencyType::Group(GroupName::from_str("dev")?),
+                    1,
+                    "pytest>=8".to_string()
+                ),
+                (
+                    DependencyType::Group(GroupName::from_str("lint")?),
+                    0,
+                    "ruff".to_string()
+                ),
+                (DependencyType::Dev, 0, "typing-extensions".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -2503,6 +2503,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -2604,6 +2605,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -2939,6 +2941,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3049,6 +3052,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3172,6 +3176,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
@@ -3269,6 +3274,7 @@ mod tests {
                       "package": null,
                       "default-groups": null,
                       "dependency-groups": null,
+                      "tasks": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
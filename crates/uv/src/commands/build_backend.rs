@@ -89,3 +89,16 @@ pub(crate) fn prepare_metadata_for_build_editable(metadata_directory: &Path) ->
     writeln!(&mut std::io::stdout(), "{filename}").context("stdout is closed")?;
     Ok(ExitStatus::Success)
 }
+
+/// Print the build hook configuration, which the Python shim uses to call the build hook.
+pub(crate) fn build_hook() -> Result<ExitStatus> {
+    let build_hook = uv_build_backend::build_hook(&env::current_dir()?, uv_version::version())?;
+    // Tell the Python shim about the build hook, or `null` if there is none
+    writeln!(
+        &mut std::io::stdout(),
+        "{}",
+        serde_json::to_string(&build_hook)?
+    )
+    .context("stdout is closed")?;
+    Ok(ExitStatus::Success)
+}
@@ -38,6 +38,7 @@ use uv_python::{
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildStack, HashStrategy, SourceTreeEditablePolicy};
 use uv_warnings::warn_user;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -47,6 +48,7 @@ use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::reproducible;
 use crate::printer::Printer;
 use crate::settings::ResolverSettings;
 
@@ -97,6 +99,8 @@ enum Error {
     InvalidBuiltWheelFilename(#[source] uv_distribution_filename::WheelFilenameError),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error("The build is not reproducible, the two builds differ:\n{0}")]
+    NotReproducible(String),
 }
 
 /// Collect hints from a build [`Error`] by inspecting its inner types.
@@ -123,6 +127,7 @@ pub(crate) async fn build_frontend(
     sdist: bool,
     wheel: bool,
     list: bool,
+    verify_reproducible: bool,
     build_logs: bool,
     gitignore: bool,
     force_pep517: bool,
@@ -152,6 +157,7 @@ pub(crate) async fn build_frontend(
         sdist,
         wheel,
         list,
+        verify_reproducible,
         build_logs,
         gitignore,
         force_pep517,
@@ -201,6 +207,7 @@ async fn build_impl(
     sdist: bool,
     wheel: bool,
     list: bool,
+    verify_reproducible: bool,
     build_logs: bool,
     gitignore: bool,
     force_pep517: bool,
@@ -421,6 +428,7 @@ async fn build_impl(
             sdist,
             wheel,
             list,
+            verify_reproducible,
             dependency_metadata,
             *link_mode,
             config_setting,
@@ -537,6 +545,7 @@ async fn build_package(
     sdist: bool,
     wheel: bool,
     list: bool,
+    verify_reproducible: bool,
     dependency_metadata: &DependencyMetadata,
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
@@ -691,6 +700,18 @@ async fn build_package(
         preview,
     );
 
+    // Check reproducibility with a fixed modification time for the files in the archives, unless
+    // the user provided one.
+    let build_dispatch = if verify_reproducible {
+        let source_date_epoch = std::env::var(EnvVars::SOURCE_DATE_EPOCH)
+            .ok()
+            .filter(|epoch| !epoch.is_empty())
+            .unwrap_or_else(|| reproducible::DEFAULT_SOURCE_DATE_EPOCH.to_string());
+        build_dispatch.with_build_extra_env_vars([(EnvVars::SOURCE_DATE_EPOCH, source_date_epoch)])
+    } else {
+        build_dispatch
+    };
+
     prepare_output_directory(&output_dir, gitignore).await?;
 
     // Determine the build plan.
@@ -727,11 +748,6 @@ async fn build_package(
         }
     };
 
-    // Prepare some common arguments for the build.
-    let dist = None;
-    let subdirectory = None;
-    let version_id = source.path().file_name().and_then(|name| name.to_str());
-
     let build_output = match printer {
         Printer::Default | Printer::NoProgress | Printer::Verbose => {
             if build_logs && !uv_flags::contains(uv_flags::EnvironmentFlags::HIDE_BUILD_OUTPUT) {
@@ -743,6 +759,108 @@ async fn build_package(
         Printer::Quiet | Printer::Silent => BuildOutput::Quiet,
     };
 
+    if !verify_reproducible {
+        return build_plan(
+            plan,
+            &source,
+            &output_dir,
+            build_action,
+            &build_dispatch,
+            &sources,
+            list,
+            build_output,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    // Build the distributions twice, in separate temporary directories, and compare the archives.
+    let first_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
+    let second_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
+    let mut builds = Vec::with_capacity(2);
+    for build_dir in [&first_dir, &second_dir] {
+        builds.push(
+            build_plan(
+                plan,
+                &source,
+                build_dir.path(),
+                build_action,
+                &build_dispatch,
+                &sources,
+                list,
+                build_output,
+                cache,
+                printer,
+            )
+            .await?,
+        );
+    }
+    let second_build = builds.pop().expect("two builds");
+    let first_build = builds.pop().expect("two builds");
+
+    let mut report = String::new();
+    for (first, second) in first_build.iter().zip(&second_build) {
+        if first.raw_filename() != second.raw_filename() {
+            writeln!(
+                report,
+                "`{}` was built as `{}`",
+                first.raw_filename(),
+                second.raw_filename()
+            )?;
+            continue;
+        }
+        let differences = reproducible::compare_archives(
+            &first_dir.path().join(first.raw_filename()),
+            &second_dir.path().join(second.raw_filename()),
+        )
+        .await?;
+        if differences.is_empty() {
+            continue;
+        }
+        writeln!(report, "`{}`:", first.raw_filename())?;
+        for difference in differences {
+            writeln!(report, "  - {difference}")?;
+        }
+    }
+    if !report.is_empty() {
+        return Err(Error::NotReproducible(report.trim_end().to_string()));
+    }
+
+    // Both builds are identical, move the distributions from the first build into place.
+    let mut build_results = Vec::with_capacity(first_build.len());
+    for build in first_build {
+        let filename = build.raw_filename();
+        fs_err::tokio::copy(first_dir.path().join(filename), output_dir.join(filename)).await?;
+        writeln!(
+            printer.stderr(),
+            "Verified that {} is reproducible",
+            filename.cyan()
+        )?;
+        build_results.push(build.with_output_dir(&output_dir));
+    }
+
+    Ok(build_results)
+}
+
+/// Build the distributions for the given build plan into the output directory.
+async fn build_plan(
+    plan: BuildPlan,
+    source: &AnnotatedSource<'_>,
+    output_dir: &Path,
+    build_action: BuildAction,
+    build_dispatch: &BuildDispatch<'_>,
+    sources: &NoSources,
+    list: bool,
+    build_output: BuildOutput,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Vec<BuildMessage>, Error> {
+    // Prepare some common arguments for the build.
+    let dist = None;
+    let subdirectory = None;
+    let version_id = source.path().file_name().and_then(|name| name.to_str());
+
     let mut build_results = Vec::new();
     match plan {
         BuildPlan::SdistToWheel => {
@@ -751,13 +869,13 @@ async fn build_package(
             if list {
                 let sdist_list = build_sdist(
                     source.path(),
-                    &output_dir,
+                    output_dir,
                     build_action,
-                    &source,
+                    source,
                     printer,
                     "source distribution",
-                    &build_dispatch,
-                    &sources,
+                    build_dispatch,
+                    sources,
                     dist,
                     subdirectory,
                     version_id,
@@ -768,13 +886,13 @@ async fn build_package(
             }
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                output_dir,
                 build_action.force_build(),
-                &source,
+                source,
                 printer,
                 "source distribution",
-                &build_dispatch,
-                &sources,
+                build_dispatch,
+                sources,
                 dist,
                 subdirectory,
                 version_id,
@@ -800,13 +918,13 @@ async fn build_package(
 
             let wheel_build = build_wheel(
                 &extracted,
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "wheel from source distribution",
-                &build_dispatch,
-                sources,
+                build_dispatch,
+                sources.clone(),
                 dist,
                 subdirectory,
                 version_id,
@@ -819,13 +937,13 @@ async fn build_package(
         BuildPlan::Sdist => {
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "source distribution",
-                &build_dispatch,
-                &sources,
+                build_dispatch,
+                sources,
                 dist,
                 subdirectory,
                 version_id,
@@ -837,13 +955,13 @@ async fn build_package(
         BuildPlan::Wheel => {
             let wheel_build = build_wheel(
                 source.path(),
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "wheel",
-                &build_dispatch,
-                sources,
+                build_dispatch,
+                sources.clone(),
                 dist,
                 subdirectory,
                 version_id,
@@ -856,13 +974,13 @@ async fn build_package(
         BuildPlan::SdistAndWheel => {
             let sdist_build = build_sdist(
                 source.path(),
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "source distribution",
-                &build_dispatch,
-                &sources,
+                build_dispatch,
+                sources,
                 dist,
                 subdirectory,
                 version_id,
@@ -872,13 +990,13 @@ async fn build_package(
 
             let wheel_build = build_wheel(
                 source.path(),
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "wheel",
-                &build_dispatch,
-                sources,
+                build_dispatch,
+                sources.clone(),
                 dist,
                 subdirectory,
                 version_id,
@@ -895,7 +1013,7 @@ async fn build_package(
             let ext = SourceDistExtension::from_path(source.path()).map_err(|err| {
                 Error::InvalidSourceDistExt(source.path().user_display().to_string(), err)
             })?;
-            let temp_dir = tempfile::tempdir_in(&output_dir)?;
+            let temp_dir = tempfile::tempdir_in(output_dir)?;
             uv_extract::stream::archive(source.path().display(), reader, ext, temp_dir.path())
                 .await?;
 
@@ -916,13 +1034,13 @@ async fn build_package(
 
             let wheel_build = build_wheel(
                 &extracted,
-                &output_dir,
+                output_dir,
                 build_action,
-                &source,
+                source,
                 printer,
                 "wheel from source distribution",
-                &build_dispatch,
-                sources,
+                build_dispatch,
+                sources.clone(),
                 dist,
                 subdirectory,
                 version_id,
@@ -1337,6 +1455,22 @@ impl BuildMessage {
         }
     }
 
+    /// Move the message for a built distribution to a different output directory.
+    fn with_output_dir(self, output_dir: &Path) -> Self {
+        match self {
+            Self::Build {
+                normalized_filename,
+                raw_filename,
+                ..
+            } => Self::Build {
+                normalized_filename,
+                raw_filename,
+                output_dir: output_dir.to_path_buf(),
+            },
+            Self::List { .. } => self,
+        }
+    }
+
     fn print(&self, printer: Printer) -> Result<()> {
         match self {
             Self::Build {
@@ -0,0 +1,200 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+use uv_cache::{Cache, CacheSource, CachedVersion, WheelCache};
+use uv_cli::CacheFormat;
+use uv_distribution_types::{Index, PYPI_URL};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+/// Show the cached versions of a package.
+pub(crate) fn cache_inspect(
+    package: &PackageName,
+    human_readable: bool,
+    output_format: CacheFormat,
+    indexes: &[Index],
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheInspect) {
+        warn_user!(
+            "`uv cache inspect` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheInspect
+        );
+    }
+
+    let versions = if cache.root().exists() {
+        cache.inspect(package)?
+    } else {
+        Vec::new()
+    };
+
+    match output_format {
+        CacheFormat::Text => {
+            if versions.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No cache entries found for {}",
+                    package.cyan()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let rows = versions
+                .iter()
+                .map(|version| {
+                    let size = if human_readable {
+                        let (bytes, unit) = human_readable_bytes(version.size);
+                        format!("{bytes:.1}{unit}")
+                    } else {
+                        version.size.to_string()
+                    };
+                    let buckets = version
+                        .buckets
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    (
+                        version
+                            .version
+                            .as_ref()
+                            .map_or_else(|| "(index)".to_string(), |version| format!("v{version}")),
+                        Source::new(&version.source, indexes).to_string(),
+                        buckets,
+                        size,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            // Compute the width of the first two columns.
+            let version_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0);
+            let source_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0);
+
+            writeln!(printer.stdout_important(), "{}", package.bold())?;
+            for (version, source, buckets, size) in rows {
+                writeln!(
+                    printer.stdout_important(),
+                    "  {version:version_width$}  {source:source_width$}  {buckets} {}",
+                    format!("({size})").dimmed()
+                )?;
+            }
+        }
+        CacheFormat::Json => {
+            let output = InspectJson {
+                name: package,
+                versions: versions
+                    .iter()
+                    .map(|version| VersionJson::new(version, indexes))
+                    .collect(),
+            };
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&output)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The source of a cache entry, resolved against the configured indexes where possible.
+#[derive(Serialize)]
+struct Source<'a> {
+    kind: &'static str,
+    /// The digest of the source URL, as used in the cache structure.
+    digest: Option<&'a str>,
+    /// The URL of the source, if known.
+    url: Option<String>,
+}
+
+impl<'a> Source<'a> {
+    fn new(source: &'a CacheSource, indexes: &[Index]) -> Self {
+        match source {
+            CacheSource::Pypi => Self {
+                kind: "index",
+                digest: None,
+                url: Some(PYPI_URL.to_string()),
+            },
+            CacheSource::Index(digest) => Self {
+                kind: "index",
+                digest: Some(digest),
+                // The cache is keyed by a digest of the index URL, so we can only recover the URL
+                // for configured indexes.
+                url: indexes
+                    .iter()
+                    .find(|index| {
+                        WheelCache::Index(&index.url)
+                            .root()
+                            .file_name()
+                            .is_some_and(|name| name == digest.as_str())
+                    })
+                    .map(|index| index.url.to_string()),
+            },
+            CacheSource::Url(digest) => Self {
+                kind: "url",
+                digest: Some(digest),
+                url: None,
+            },
+            CacheSource::Path(digest) => Self {
+                kind: "path",
+                digest: Some(digest),
+                url: None,
+            },
+            CacheSource::Editable(digest) => Self {
+                kind: "editable",
+                digest: Some(digest),
+                url: None,
+            },
+            CacheSource::Git(digest) => Self {
+                kind: "git",
+                digest: Some(digest),
+                url: None,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Source<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.url, self.digest) {
+            (Some(url), _) => f.write_str(url),
+            (None, Some(digest)) => write!(f, "{} ({digest})", self.kind),
+            (None, None) => f.write_str(self.kind),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct InspectJson<'a> {
+    name: &'a PackageName,
+    versions: Vec<VersionJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct VersionJson<'a> {
+    version: Option<&'a Version>,
+    source: Source<'a>,
+    buckets: Vec<String>,
+    size: u64,
+}
+
+impl<'a> VersionJson<'a> {
+    fn new(version: &'a CachedVersion, indexes: &[Index]) -> Self {
+        Self {
+            version: version.version.as_ref(),
+            source: Source::new(&version.source, indexes),
+            buckets: version.buckets.iter().map(ToString::to_string).collect(),
+            size: version.size,
+        }
+    }
+}
@@ -4,19 +4,31 @@ use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, EvictionPolicy, Removal};
 use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
-/// Prune dangling cache entries and cached environments.
+/// Prune dangling cache entries and cached environments, and evict entries according to the
+/// given [`EvictionPolicy`].
 pub(crate) async fn cache_prune(
     ci: bool,
     force: bool,
+    policy: EvictionPolicy,
     cache: Cache,
     printer: Printer,
+    preview: Preview,
 ) -> Result<ExitStatus> {
+    if !policy.is_empty() && !preview.is_enabled(PreviewFeature::CacheEviction) {
+        warn_user!(
+            "The `--max-age` and `--max-size` options are experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheEviction
+        );
+    }
+
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -58,6 +70,14 @@ pub(crate) async fn cache_prune(
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict the least-recently-used entries, if requested.
+    summary += cache.evict(&policy).with_context(|| {
+        format!(
+            "Failed to evict from cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -2,17 +2,26 @@ use std::fmt::Write;
 
 use anyhow::Result;
 use diskus::DiskUsage;
+use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheStats};
+use uv_cli::CacheFormat;
+use uv_normalize::PackageName;
 use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
+/// The number of packages to display for each bucket in the text output.
+const LARGEST_PACKAGES: usize = 5;
+
 /// Display the total size of the cache.
 pub(crate) fn cache_size(
     cache: &Cache,
     human_readable: bool,
+    buckets: bool,
+    output_format: CacheFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -23,6 +32,14 @@ pub(crate) fn cache_size(
         );
     }
 
+    if buckets || matches!(output_format, CacheFormat::Json) {
+        let stats = cache.stats()?;
+        return match output_format {
+            CacheFormat::Text => write_text(&stats, human_readable, printer),
+            CacheFormat::Json => write_json(&stats, printer),
+        };
+    }
+
     if !cache.root().exists() {
         if human_readable {
             writeln!(printer.stdout_important(), "0B")?;
@@ -45,3 +62,147 @@ pub(crate) fn cache_size(
 
     Ok(ExitStatus::Success)
 }
+
+/// Display the size of each bucket, along with its largest packages.
+fn write_text(stats: &CacheStats, human_readable: bool, printer: Printer) -> Result<ExitStatus> {
+    let format = |bytes: u64| {
+        if human_readable {
+            let (bytes, unit) = human_readable_bytes(bytes);
+            format!("{bytes:.1}{unit}")
+        } else {
+            bytes.to_string()
+        }
+    };
+
+    // Compute the width of the first column.
+    let width = stats
+        .buckets
+        .iter()
+        .flat_map(|bucket| {
+            std::iter::once(bucket.bucket.to_string().len()).chain(
+                bucket
+                    .packages
+                    .iter()
+                    .take(LARGEST_PACKAGES)
+                    .map(|(name, _)| name.as_str().len() + 2),
+            )
+        })
+        .chain(["other".len(), "total".len()])
+        .max()
+        .unwrap_or_default();
+
+    for bucket in &stats.buckets {
+        if bucket.size == 0 {
+            continue;
+        }
+        writeln!(
+            printer.stdout_important(),
+            "{:width$}  {}",
+            bucket.bucket.to_string().bold(),
+            format(bucket.size)
+        )?;
+        for (name, size) in bucket.packages.iter().take(LARGEST_PACKAGES) {
+            writeln!(
+                printer.stdout_important(),
+                "  {:width$}  {}",
+                name.as_str(),
+                format(*size).dimmed(),
+                width = width - 2
+            )?;
+        }
+    }
+    if stats.other > 0 {
+        writeln!(
+            printer.stdout_important(),
+            "{:width$}  {}",
+            "other".bold(),
+            format(stats.other)
+        )?;
+    }
+    writeln!(
+        printer.stdout_important(),
+        "{:width$}  {}",
+        "total".bold(),
+        format(stats.total())
+    )?;
+
+    if stats.unreferenced_archives > 0 {
+        writeln!(
+            printer.stdout_important(),
+            "\n{} unreferenced {} ({}); run `{}` to remove {}",
+            stats.unreferenced_archives,
+            if stats.unreferenced_archives == 1 {
+                "archive"
+            } else {
+                "archives"
+            },
+            format(stats.unreferenced_archives_size),
+            "uv cache prune".green(),
+            if stats.unreferenced_archives == 1 {
+                "it"
+            } else {
+                "them"
+            },
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Serialize)]
+struct CacheSizeJson<'a> {
+    total: u64,
+    buckets: Vec<BucketJson<'a>>,
+    other: u64,
+    unreferenced_archives: UnreferencedArchivesJson,
+}
+
+#[derive(Serialize)]
+struct BucketJson<'a> {
+    name: String,
+    size: u64,
+    packages: Vec<PackageJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct PackageJson<'a> {
+    name: &'a PackageName,
+    size: u64,
+}
+
+#[derive(Serialize)]
+struct UnreferencedArchivesJson {
+    count: usize,
+    size: u64,
+}
+
+/// Display the size of each bucket, along with all of its packages, as JSON.
+fn write_json(stats: &CacheStats, printer: Printer) -> Result<ExitStatus> {
+    let output = CacheSizeJson {
+        total: stats.total(),
+        buckets: stats
+            .buckets
+            .iter()
+            .map(|bucket| BucketJson {
+                name: bucket.bucket.to_string(),
+                size: bucket.size,
+                packages: bucket
+                    .packages
+                    .iter()
+                    .map(|(name, size)| PackageJson { name, size: *size })
+                    .collect(),
+            })
+            .collect(),
+        other: stats.other,
+        unreferenced_archives: UnreferencedArchivesJson {
+            count: stats.unreferenced_archives,
+            size: stats.unreferenced_archives_size,
+        },
+    };
+    writeln!(
+        printer.stdout_important(),
+        "{}",
+        serde_json::to_string_pretty(&output)?
+    )?;
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,769 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_zip::base::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use futures::TryStreamExt;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Bytes, Frame, Incoming};
+use hyper::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use serde::Serialize;
+use tokio::net::TcpListener;
+use tokio::sync::OnceCell;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+use tokio_util::io::ReaderStream;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_distribution::{HttpArchivePointer, PathArchivePointer};
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{PortablePath, Simplified};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::{HashAlgorithm, HashDigest, ResolutionMetadata};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The media type for JSON responses, per PEP 691.
+const JSON_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The media type for HTML responses, per PEP 691.
+const HTML_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+html";
+
+/// The version of the Simple Repository API implemented by the index.
+///
+/// Version 1.1 (PEP 700) adds the `size` and `versions` keys to the JSON responses.
+const API_VERSION: &str = "1.1";
+
+/// The version of the Simple Repository API implemented by an index that can't report the size of
+/// each file up front (i.e., when serving wheels from the cache, which are repacked on demand).
+const API_VERSION_WITHOUT_SIZE: &str = "1.0";
+
+/// Percent-encode filenames for use as a path segment in file URLs.
+///
+/// This follows Python's `urllib.parse.quote(filename, safe="")`, such that, e.g., the `+` of a
+/// local version is encoded as `%2B`.
+const FILENAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+type Body = BoxBody<Bytes, std::io::Error>;
+
+/// Serve a directory of distributions, or the wheels in the cache, as a package index.
+pub(crate) async fn index_serve(
+    directory: Option<&Path>,
+    from_cache: bool,
+    host: IpAddr,
+    port: u16,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::IndexServe) {
+        warn_user!(
+            "`uv index serve` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::IndexServe
+        );
+    }
+
+    // When serving from the cache, hold a shared lock on the cache for the lifetime of the server,
+    // such that `uv cache clean` and `uv cache prune` can't remove wheels while they're being
+    // served. The wheels are repacked into a temporary directory on demand, which must also
+    // outlive the server.
+    let _cache;
+    let _temp_dir;
+    let index = if from_cache {
+        let cache = cache.init().await?;
+        let temp_dir = tempfile::tempdir()?;
+        let index = Index::from_cache(&cache, temp_dir.path())
+            .await
+            .with_context(|| format!("Failed to read `{}`", cache.root().user_display()))?;
+        _cache = cache;
+        _temp_dir = temp_dir;
+        index
+    } else {
+        let directory = directory.map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+        Index::from_directory(&directory)
+            .await
+            .with_context(|| format!("Failed to read `{}`", directory.user_display()))?
+    };
+    let index = Arc::new(index);
+
+    let listener = TcpListener::bind(SocketAddr::new(host, port))
+        .await
+        .with_context(|| format!("Failed to bind to `{}`", SocketAddr::new(host, port)))?;
+    let address = listener.local_addr()?;
+
+    let files = index.files.len();
+    let projects = index.projects.len();
+    writeln!(
+        printer.stderr(),
+        "Serving {} for {} at {}",
+        format!("{files} file{}", if files == 1 { "" } else { "s" }).bold(),
+        format!("{projects} package{}", if projects == 1 { "" } else { "s" }).bold(),
+        format!("http://{address}/simple/").cyan()
+    )?;
+    writeln!(printer.stderr(), "{}", "Press Ctrl+C to stop".dimmed())?;
+
+    loop {
+        let stream = tokio::select! {
+            result = listener.accept() => result?.0,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        let index = index.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let index = index.clone();
+                async move { Ok::<_, Infallible>(index.respond(&request).await) }
+            });
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("Failed to serve connection: {err}");
+            }
+        });
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A set of distributions, grouped by package.
+#[derive(Debug)]
+struct Index {
+    /// The version of the Simple Repository API implemented by the index.
+    api_version: &'static str,
+    /// The files for each package, sorted by version.
+    projects: BTreeMap<PackageName, Vec<Arc<IndexFile>>>,
+    /// The files in the index, keyed by filename.
+    files: BTreeMap<String, Arc<IndexFile>>,
+}
+
+/// A distribution served by the [`Index`].
+#[derive(Debug)]
+struct IndexFile {
+    /// The name of the file, as served.
+    name: String,
+    filename: DistFilename,
+    source: FileSource,
+    /// The size of the file, if known up front.
+    size: Option<u64>,
+    /// The SHA-256 digest of the file, if known up front.
+    sha256: Option<String>,
+    /// The `Requires-Python` of the distribution, if known.
+    requires_python: Option<String>,
+    /// The core metadata of the distribution, for wheels.
+    metadata: Option<CoreMetadata>,
+}
+
+/// The location of a distribution served by the [`Index`].
+#[derive(Debug)]
+enum FileSource {
+    /// An archive on disk.
+    Path(PathBuf),
+    /// An unzipped wheel in the cache, which is repacked into an archive at `target` when it's
+    /// first requested.
+    Cached {
+        archive: PathBuf,
+        target: PathBuf,
+        /// The size of the repacked archive, once written.
+        packed: OnceCell<u64>,
+    },
+}
+
+/// The contents of a `.metadata` file (PEP 658).
+#[derive(Debug)]
+struct CoreMetadata {
+    contents: Bytes,
+    sha256: String,
+}
+
+impl Index {
+    /// Read the wheels and source distributions in the given directory.
+    ///
+    /// Files that aren't recognized as distributions are ignored.
+    async fn from_directory(directory: &Path) -> Result<Self> {
+        let mut files = BTreeMap::new();
+
+        for entry in fs_err::read_dir(directory)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let Some(name) = entry.file_name().to_str().map(ToString::to_string) else {
+                continue;
+            };
+            let Some(filename) = DistFilename::try_from_normalized_filename(&name) else {
+                debug!("Ignoring non-distribution file: {name}");
+                continue;
+            };
+
+            let path = entry.path();
+            let file = IndexFile::read(name.clone(), filename, path)
+                .await
+                .with_context(|| format!("Failed to read `{name}`"))?;
+            files.insert(name, Arc::new(file));
+        }
+
+        Ok(Self::from_files(API_VERSION, files))
+    }
+
+    /// Read the wheels in the cache.
+    ///
+    /// Each wheel is resolved through its pointer in the wheels bucket, which records the original
+    /// filename and the unzipped archive. The wheels are repacked into `directory` when they're
+    /// first requested. The cache doesn't retain the original archives, only the unzipped wheels,
+    /// and the repacked archives aren't byte-for-byte identical to the originals. The hashes in
+    /// the pointers are those of the originals, which the repacked archives wouldn't match, so
+    /// they're omitted from the index.
+    async fn from_cache(cache: &Cache, directory: &Path) -> Result<Self> {
+        let mut files = BTreeMap::new();
+
+        let root = cache.bucket(CacheBucket::Wheels);
+        if root.is_dir() {
+            for entry in WalkDir::new(&root).sort_by_file_name() {
+                let entry = entry?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                let pointer = entry.path();
+
+                // Remote wheels have an `.http` pointer, local wheels a `.rev` pointer.
+                let archive = match pointer.extension().and_then(OsStr::to_str) {
+                    Some("http") => HttpArchivePointer::read_from(pointer)
+                        .map(|pointer| pointer.map(HttpArchivePointer::into_archive)),
+                    Some("rev") => PathArchivePointer::read_from(pointer)
+                        .map(|pointer| pointer.map(PathArchivePointer::into_archive)),
+                    _ => continue,
+                };
+                let archive = match archive {
+                    Ok(Some(archive)) => archive,
+                    Ok(None) => continue,
+                    Err(err) => {
+                        debug!(
+                            "Skipping unreadable cache entry `{}`: {err}",
+                            pointer.user_display()
+                        );
+                        continue;
+                    }
+                };
+
+                // The same wheel may be present in the cache more than once (e.g., across
+                // indexes).
+                let name = archive.filename.to_string();
+                if files.contains_key(&name) {
+                    continue;
+                }
+
+                let unzipped = cache.archive(&archive.id);
+                let metadata = match read_cached_metadata(&unzipped) {
+                    Ok(Some(metadata)) => metadata,
+                    Ok(None) => {
+                        debug!(
+                            "Skipping cache entry without a wheel: {}",
+                            unzipped.user_display()
+                        );
+                        continue;
+                    }
+                    Err(err) => {
+                        debug!(
+                            "Skipping unreadable cache entry `{}`: {err}",
+                            unzipped.user_display()
+                        );
+                        continue;
+                    }
+                };
+
+                let requires_python = ResolutionMetadata::parse_metadata(&metadata)
+                    .ok()
+                    .and_then(|metadata| metadata.requires_python)
+                    .map(|requires_python| requires_python.to_string());
+                let (sha256, _) = hash_sha256(metadata.as_slice()).await?;
+                let metadata = CoreMetadata {
+                    contents: Bytes::from(metadata),
+                    sha256,
+                };
+
+                let file = IndexFile {
+                    source: FileSource::Cached {
+                        archive: unzipped,
+                        target: directory.join(&name),
+                        packed: OnceCell::new(),
+                    },
+                    name: name.clone(),
+                    filename: DistFilename::WheelFilename(archive.filename),
+                    size: None,
+                    sha256: None,
+                    requires_python,
+                    metadata: Some(metadata),
+                };
+                files.insert(name, Arc::new(file));
+            }
+        }
+
+        Ok(Self::from_files(API_VERSION_WITHOUT_SIZE, files))
+    }
+
+    /// Group the given files by package.
+    fn from_files(api_version: &'static str, files: BTreeMap<String, Arc<IndexFile>>) -> Self {
+        let mut projects: BTreeMap<PackageName, Vec<Arc<IndexFile>>> = BTreeMap::new();
+        for file in files.values() {
+            projects
+                .entry(file.filename.name().clone())
+                .or_default()
+                .push(file.clone());
+        }
+        for files in projects.values_mut() {
+            files.sort_by(|a, b| a.filename.version().cmp(b.filename.version()));
+        }
+
+        Self {
+            api_version,
+            projects,
+            files,
+        }
+    }
+
+    /// Respond to an HTTP request.
+    async fn respond(&self, request: &Request<Incoming>) -> Response<Body> {
+        if !matches!(*request.method(), Method::GET | Method::HEAD) {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let json = accepts_json(request);
+        let path = request.uri().path();
+
+        // Decode each path segment, such that filenames with local versions match whether the `+`
+        // is encoded (as `%2B`) or not.
+        let Ok(segments) = path
+            .trim_matches('/')
+            .split('/')
+            .map(|segment| percent_decode_str(segment).decode_utf8())
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return status(StatusCode::NOT_FOUND);
+        };
+        let segments = segments.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+
+        let response = match segments.as_slice() {
+            ["simple"] => Some(self.project_list(json)),
+            ["simple", name] => PackageName::from_str(name)
+                .ok()
+                .and_then(|name| self.project_detail(&name, json)),
+            ["files", filename] => {
+                if let Some(filename) = filename.strip_suffix(".metadata") {
+                    self.files
+                        .get(filename)
+                        .and_then(|file| file.metadata.as_ref())
+                        .map(|metadata| {
+                            bytes("application/octet-stream", metadata.contents.clone())
+                        })
+                } else if let Some(file) = self.files.get(*filename) {
+                    match file_response(file).await {
+                        Ok(response) => Some(response),
+                        Err(err) => {
+                            debug!("Failed to serve `{}`: {err:#}", file.name);
+                            return status(StatusCode::INTERNAL_SERVER_ERROR);
+                        }
+                    }
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        let response = response.unwrap_or_else(|| status(StatusCode::NOT_FOUND));
+        debug!("{} {path} {}", request.method(), response.status().as_u16());
+        response
+    }
+
+    /// Render the list of projects in the index.
+    fn project_list(&self, json: bool) -> Response<Body> {
+        if json {
+            let list = ProjectList {
+                meta: Meta {
+                    api_version: self.api_version,
+                },
+                projects: self
+                    .projects
+                    .keys()
+                    .map(|name| ProjectListEntry { name })
+                    .collect(),
+            };
+            return bytes(
+                JSON_CONTENT_TYPE,
+                Bytes::from(serde_json::to_vec(&list).expect("serialization is infallible")),
+            );
+        }
+
+        let mut html = html_header(self.api_version, "Simple index");
+        for name in self.projects.keys() {
+            let _ = writeln!(html, "    <a href=\"/simple/{name}/\">{name}</a><br/>");
+        }
+        html.push_str("  </body>\n</html>\n");
+        bytes(HTML_CONTENT_TYPE, Bytes::from(html))
+    }
+
+    /// Render the files for a single project, if it exists.
+    fn project_detail(&self, name: &PackageName, json: bool) -> Option<Response<Body>> {
+        let files = self.projects.get(name)?;
+
+        if json {
+            let detail = ProjectDetail {
+                meta: Meta {
+                    api_version: self.api_version,
+                },
+                name,
+                files: files
+                    .iter()
+                    .map(|file| ProjectFile {
+                        filename: &file.name,
+                        url: file.url(),
+                        hashes: file
+                            .sha256
+                            .as_deref()
+                            .map(|sha256| BTreeMap::from([("sha256", sha256)]))
+                            .unwrap_or_default(),
+                        requires_python: file.requires_python.as_deref(),
+                        core_metadata: file.metadata.as_ref().map_or(
+                            CoreMetadataField::Available(false),
+                            |metadata| {
+                                CoreMetadataField::Hashes(BTreeMap::from([(
+                                    "sha256",
+                                    metadata.sha256.as_str(),
+                                )]))
+                            },
+                        ),
+                        size: file.size,
+                        yanked: false,
+                    })
+                    .collect(),
+                versions: files
+                    .iter()
+                    .map(|file| file.filename.version())
+                    .dedup()
+                    .collect(),
+            };
+            return Some(bytes(
+                JSON_CONTENT_TYPE,
+                Bytes::from(serde_json::to_vec(&detail).expect("serialization is infallible")),
+            ));
+        }
+
+        let mut html = html_header(self.api_version, &format!("Links for {name}"));
+        let _ = writeln!(html, "    <h1>Links for {name}</h1>");
+        for file in files {
+            let _ = write!(html, "    <a href=\"{}", file.url());
+            if let Some(sha256) = &file.sha256 {
+                let _ = write!(html, "#sha256={sha256}");
+            }
+            let _ = write!(html, "\"");
+            if let Some(requires_python) = &file.requires_python {
+                let _ = write!(
+                    html,
+                    " data-requires-python=\"{}\"",
+                    escape_html(requires_python)
+                );
+            }
+            if let Some(metadata) = &file.metadata {
+                let _ = write!(
+                    html,
+                    " data-core-metadata=\"sha256={sha256}\" data-dist-info-metadata=\"sha256={sha256}\"",
+                    sha256 = metadata.sha256,
+                );
+            }
+            let _ = writeln!(html, ">{}</a><br/>", escape_html(&file.name));
+        }
+        html.push_str("  </body>\n</html>\n");
+        Some(bytes(HTML_CONTENT_TYPE, Bytes::from(html)))
+    }
+}
+
+impl IndexFile {
+    /// The URL of the file, relative to the root of the index.
+    fn url(&self) -> String {
+        format!(
+            "/files/{}",
+            utf8_percent_encode(&self.name, FILENAME_ENCODE_SET)
+        )
+    }
+
+    /// Read a distribution from disk, computing its hash and, for wheels, extracting its core
+    /// metadata.
+    async fn read(name: String, filename: DistFilename, path: PathBuf) -> Result<Self> {
+        let (sha256, size) = hash_sha256(fs_err::tokio::File::open(&path).await?).await?;
+
+        let metadata = match &filename {
+            DistFilename::WheelFilename(wheel) => {
+                let reader = std::io::BufReader::new(fs_err::File::open(&path)?);
+                match uv_metadata::read_archive_metadata(wheel, reader) {
+                    Ok(contents) => {
+                        let (sha256, _) = hash_sha256(contents.as_slice()).await?;
+                        Some(CoreMetadata {
+                            contents: Bytes::from(contents),
+                            sha256,
+                        })
+                    }
+                    Err(err) => {
+                        warn_user!(
+                            "Failed to read metadata from `{name}`; serving it without core metadata: {err}"
+                        );
+                        None
+                    }
+                }
+            }
+            DistFilename::SourceDistFilename(_) => None,
+        };
+
+        let requires_python = metadata
+            .as_ref()
+            .and_then(|metadata| ResolutionMetadata::parse_metadata(&metadata.contents).ok())
+            .and_then(|metadata| metadata.requires_python)
+            .map(|requires_python| requires_python.to_string());
+
+        Ok(Self {
+            name,
+            filename,
+            source: FileSource::Path(path),
+            size: Some(size),
+            sha256: Some(sha256),
+            requires_python,
+            metadata,
+        })
+    }
+}
+
+/// Compute the SHA-256 digest and size of the given reader.
+async fn hash_sha256(reader: impl tokio::io::AsyncRead + Unpin) -> Result<(String, u64)> {
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    let mut hasher = HashReader::new(reader, &mut hashers);
+    let size = tokio::io::copy(&mut hasher, &mut tokio::io::sink()).await?;
+    hasher.finish().await?;
+    let digest = HashDigest::from(hashers.remove(0));
+    Ok((digest.digest.to_string(), size))
+}
+
+/// Return `true` if the request prefers a JSON response (PEP 691).
+fn accepts_json(request: &Request<Incoming>) -> bool {
+    let Some(accept) = request
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+    else {
+        return false;
+    };
+    accept.split(',').any(|media_range| {
+        let mut parts = media_range.split(';').map(str::trim);
+        parts.next() == Some(JSON_CONTENT_TYPE)
+            && !parts.any(|param| matches!(param, "q=0" | "q=0.0" | "q=0.00" | "q=0.000"))
+    })
+}
+
+/// Stream a distribution from disk, repacking it first if it's an unzipped wheel in the cache.
+async fn file_response(file: &IndexFile) -> Result<Response<Body>> {
+    let (path, size) = match &file.source {
+        FileSource::Path(path) => (path, file.size),
+        FileSource::Cached {
+            archive,
+            target,
+            packed,
+        } => {
+            let size = packed
+                .get_or_try_init(|| async {
+                    debug!("Repacking cached wheel: {}", file.name);
+                    pack_wheel(archive, target)
+                        .await
+                        .with_context(|| format!("Failed to repack `{}`", file.name))?;
+                    Ok::<_, anyhow::Error>(fs_err::tokio::metadata(target).await?.len())
+                })
+                .await?;
+            (target, Some(*size))
+        }
+    };
+
+    let reader = fs_err::tokio::File::open(path).await?;
+    let body = StreamBody::new(ReaderStream::new(reader).map_ok(Frame::data));
+    let mut response = Response::new(BodyExt::boxed(body));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/octet-stream"),
+    );
+    if let Some(size) = size {
+        response
+            .headers_mut()
+            .insert(CONTENT_LENGTH, HeaderValue::from(size));
+    }
+    Ok(response)
+}
+
+/// Build a response with the given content type and body.
+fn bytes(content_type: &'static str, body: Bytes) -> Response<Body> {
+    let mut response = Response::new(full(body));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+/// Build an empty response with the given status code.
+fn status(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(full(Bytes::new()));
+    *response.status_mut() = status;
+    response
+}
+
+fn full(body: Bytes) -> Body {
+    Full::new(body).map_err(|never| match never {}).boxed()
+}
+
+/// Render the opening of an HTML page, per PEP 503.
+fn html_header(api_version: &str, title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n  <head>\n    <meta name=\"pypi:repository-version\" content=\"{api_version}\">\n    <title>{title}</title>\n  </head>\n  <body>\n"
+    )
+}
+
+/// Escape a string for use in an HTML attribute.
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[derive(Serialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: &'static str,
+}
+
+/// The JSON project list, per PEP 691.
+#[derive(Serialize)]
+struct ProjectList<'a> {
+    meta: Meta,
+    projects: Vec<ProjectListEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct ProjectListEntry<'a> {
+    name: &'a PackageName,
+}
+
+/// The JSON project detail, per PEP 691 and PEP 700.
+#[derive(Serialize)]
+struct ProjectDetail<'a> {
+    meta: Meta,
+    name: &'a PackageName,
+    files: Vec<ProjectFile<'a>>,
+    versions: Vec<&'a Version>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ProjectFile<'a> {
+    filename: &'a str,
+    url: String,
+    hashes: BTreeMap<&'static str, &'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<&'a str>,
+    core_metadata: CoreMetadataField<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    yanked: bool,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum CoreMetadataField<'a> {
+    Available(bool),
+    Hashes(BTreeMap<&'static str, &'a str>),
+}
+
+/// Read the `METADATA` file of an unzipped wheel.
+///
+/// Returns `None` if the directory does not contain exactly one `.dist-info` directory.
+fn read_cached_metadata(archive: &Path) -> Result<Option<Vec<u8>>> {
+    let mut dist_infos = fs_err::read_dir(archive)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.path().is_dir()
+                && entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.ends_with(".dist-info"))
+        })
+        .map(|entry| entry.path());
+    let (Some(dist_info), None) = (dist_infos.next(), dist_infos.next()) else {
+        return Ok(None);
+    };
+    Ok(Some(fs_err::read(dist_info.join("METADATA"))?))
+}
+
+/// Write the contents of an unzipped wheel to a new archive, streaming each file from disk.
+///
+/// The archive is written to a temporary file, then moved into place, such that a failed write
+/// doesn't leave a partial archive behind.
+async fn pack_wheel(archive: &Path, target: &Path) -> Result<()> {
+    let directory = target.parent().expect("target is within a directory");
+    let temp_file = tempfile::NamedTempFile::new_in(directory)?;
+    let file = fs_err::tokio::File::create(temp_file.path()).await?;
+    let mut writer = ZipFileWriter::with_tokio(file);
+
+    for entry in WalkDir::new(archive).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(archive)?;
+
+        #[cfg(unix)]
+        let mode = {
+            use std::os::unix::fs::PermissionsExt;
+            if entry.metadata()?.permissions().mode() & 0o111 != 0 {
+                0o100_755
+            } else {
+                0o100_644
+            }
+        };
+        #[cfg(not(unix))]
+        let mode = 0o100_644;
+
+        let builder = ZipEntryBuilder::new(
+            PortablePath::from(relative).to_string().into(),
+            Compression::Deflate,
+        )
+        .unix_permissions(mode);
+        let mut reader = fs_err::tokio::File::open(entry.path()).await?.compat();
+        let mut entry_writer = writer.write_entry_stream(builder).await?;
+        futures::io::copy(&mut reader, &mut entry_writer).await?;
+        entry_writer.close().await?;
+    }
+
+    writer.close().await?;
+    temp_file.persist(target)?;
+    Ok(())
+}
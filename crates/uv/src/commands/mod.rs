@@ -18,11 +18,15 @@ pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_inspect::cache_inspect;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
 pub(crate) use help::help;
+pub(crate) use index_serve::index_serve;
+pub(crate) use pip::audit::pip_audit;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::download::pip_download;
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
@@ -30,19 +34,23 @@ pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
-pub(crate) use project::add::add;
-pub(crate) use project::audit::audit;
+pub(crate) use project::add::{add, bump_all};
+pub(crate) use project::audit::{AuditPolicy, audit};
 pub(crate) use project::check::check;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
+pub(crate) use project::lock_merge::lock_merge_driver;
+pub(crate) use project::outdated::outdated;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{ParsedRunCommand, RunCommand, run};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
-pub(crate) use project::upgrade::upgrade;
+pub(crate) use project::upgrade::{upgrade, upgrade_interactive};
 pub(crate) use project::version::{project_version, self_version};
+pub(crate) use project::watch::Watcher;
+pub(crate) use project::why::why;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
@@ -83,20 +91,24 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_inspect;
 mod cache_prune;
 mod cache_size;
 pub(crate) mod diagnostics;
 mod editable;
 mod help;
+mod index_serve;
 pub(crate) mod pip;
 mod project;
 mod publish;
 mod pylock;
 mod python;
 pub(crate) mod reporters;
+mod reproducible;
 #[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
+mod tree_graph;
 mod venv;
 mod workspace;
 
@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use tracing::debug;
+
+use uv_audit::{Dependency, VulnerabilityServiceFormat};
+use uv_cache::Cache;
+use uv_cli::AuditOutputFormat;
+use uv_client::BaseClientBuilder;
+use uv_configuration::Concurrency;
+use uv_distribution_types::Name;
+use uv_fs::{CWD, Simplified};
+use uv_installer::SitePackages;
+use uv_pep440::Operator;
+use uv_pep508::VersionOrUrl;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_requirements::RequirementsSource;
+use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement, SourceCache};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::project::audit::{
+    AuditPolicy, AuditResults, MatchedRules, artifact_uri, osv_dump_directory,
+    query_vulnerabilities,
+};
+use crate::commands::pylock::read_pylock_toml;
+use crate::printer::Printer;
+
+/// Audit the packages in an environment, or in requirements files, for known vulnerabilities.
+pub(crate) async fn pip_audit(
+    requirements: &[PathBuf],
+    python: Option<&str>,
+    system: bool,
+    output_format: AuditOutputFormat,
+    service: VulnerabilityServiceFormat,
+    service_url: Option<&str>,
+    policy: AuditPolicy,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: &Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::Audit) {
+        warn_user!(
+            "`uv pip audit` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Audit
+        );
+    }
+    if matches!(output_format, AuditOutputFormat::Json)
+        && !preview.is_enabled(PreviewFeature::JsonOutput)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    // Collect the packages to audit, along with the audited artifact.
+    let (mut dependencies, artifact_path) = if requirements.is_empty() {
+        let environment = PythonEnvironment::find(
+            &python.map(PythonRequest::parse).unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, false),
+            PythonPreference::default().with_system_flag(system),
+            cache,
+        )?;
+
+        report_target_environment(&environment, cache, printer)?;
+
+        // Editable installs are local, and have no package identity on an index.
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let dependencies = site_packages
+            .iter()
+            .filter(|dist| !dist.is_editable())
+            .map(|dist| Dependency::new(dist.name().clone(), dist.version().clone()))
+            .collect::<Vec<_>>();
+        (dependencies, environment.root().to_path_buf())
+    } else {
+        let mut dependencies = Vec::new();
+        for path in requirements {
+            dependencies.extend(read_dependencies(path, client_builder).await?);
+        }
+        (dependencies, requirements[0].clone())
+    };
+    dependencies.sort_by(|a, b| (a.name(), a.version()).cmp(&(b.name(), b.version())));
+    dependencies.dedup();
+
+    // Perform the audit.
+    let dump_directory = osv_dump_directory(service, service_url, cache);
+    let findings = query_vulnerabilities(
+        &dependencies,
+        service,
+        service_url,
+        &dump_directory,
+        client_builder.build()?,
+        concurrency,
+        cache,
+    )
+    .await?;
+
+    // Filter out ignored vulnerabilities, tracking which ignore rules actually matched.
+    let mut matched_rules = MatchedRules::default();
+    let (findings, ignored) = policy.filter(findings, &mut matched_rules);
+    policy.warn_unmatched(&matched_rules);
+
+    let display = AuditResults {
+        printer,
+        n_packages: dependencies.len(),
+        output_format,
+        findings,
+        ignored,
+        bom: None,
+        artifact_uri: artifact_uri(&artifact_path),
+        policy,
+    };
+    display.render()
+}
+
+/// Read the pinned packages from a `requirements.txt` or `pylock.toml` file.
+async fn read_dependencies(
+    path: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<Vec<Dependency>> {
+    match RequirementsSource::from_requirements_file(path.to_path_buf())? {
+        RequirementsSource::RequirementsTxt(path) | RequirementsSource::Extensionless(path) => {
+            let requirements_txt = RequirementsTxt::parse_with_cache(
+                &path,
+                &*CWD,
+                client_builder,
+                &mut SourceCache::default(),
+            )
+            .await?;
+            Ok(requirements_txt
+                .requirements
+                .iter()
+                .filter_map(|entry| {
+                    let RequirementsTxtRequirement::Named(requirement) = &entry.requirement else {
+                        debug!("Skipping unnamed requirement: {}", entry.requirement);
+                        return None;
+                    };
+                    // Only exact pins identify a single version to audit.
+                    let version = match &requirement.version_or_url {
+                        Some(VersionOrUrl::VersionSpecifier(specifiers))
+                            if specifiers.len() == 1
+                                && matches!(
+                                    specifiers[0].operator(),
+                                    Operator::Equal | Operator::ExactEqual
+                                ) =>
+                        {
+                            specifiers[0].version().clone()
+                        }
+                        _ => {
+                            warn_user!(
+                                "Skipping `{requirement}` in `{}`, which is not pinned to an exact version",
+                                path.user_display()
+                            );
+                            return None;
+                        }
+                    };
+                    Some(Dependency::new(requirement.name.clone(), version))
+                })
+                .collect())
+        }
+        RequirementsSource::PylockToml(path) => {
+            let (_, lock) = read_pylock_toml(&path, client_builder).await?;
+            // Packages without a version, e.g., direct URL and path dependencies, aren't audited.
+            Ok(lock
+                .packages
+                .into_iter()
+                .filter_map(|package| Some(Dependency::new(package.name, package.version?)))
+                .collect())
+        }
+        _ => bail!(
+            "`uv pip audit` only supports `requirements.txt` and `pylock.toml` files, but received: `{}`",
+            path.user_display()
+        ),
+    }
+}
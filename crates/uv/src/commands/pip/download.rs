@@ -0,0 +1,453 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, KeyringProviderType, NoSources, Override, Reinstall, TargetTriple, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution_types::{
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
+    ResolvedDist,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_installer::Preparer;
+use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::Conflicts;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
+};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
+    ResolutionMode, ResolverEnvironment,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{EmptyInstalledPackages, HashStrategy, SourceTreeEditablePolicy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+use uv_workspace::pyproject::ExtraBuildDependencies;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::reporters::{PrepareReporter, PythonDownloadReporter};
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+
+/// Download a set of requirements, and their dependencies, into a directory.
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    overrides_from_workspace: Vec<Override<Requirement>>,
+    build_constraints_from_workspace: Vec<Requirement>,
+    dest: &Path,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    dependency_mode: DependencyMode,
+    upgrade: Upgrade,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    hash_checking: Option<HashCheckingMode>,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_isolation: BuildIsolation,
+    extra_build_dependencies: &ExtraBuildDependencies,
+    extra_build_variables: &ExtraBuildVariables,
+    build_options: BuildOptions,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    python_downloads: PythonDownloads,
+    install_mirrors: PythonInstallMirrors,
+    exclude_newer: ExcludeNewer,
+    sources: NoSources,
+    link_mode: LinkMode,
+    python: Option<String>,
+    system: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::PipDownload) {
+        warn_user!(
+            "`uv pip download` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::PipDownload
+        );
+    }
+
+    let start = std::time::Instant::now();
+
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        mut override_dependencies,
+        excludes,
+        pylock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        extras: _,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        &[],
+        None,
+        &client_builder,
+    )
+    .await?;
+
+    override_dependencies.extend(overrides_from_workspace);
+
+    // Reject `pylock.toml` files, which are already fully resolved.
+    if pylock.is_some() {
+        return Err(anyhow::anyhow!(
+            "`pylock.toml` is not a supported input format for `uv pip download`"
+        ));
+    }
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    // Read build constraints.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder)
+            .await?
+            .into_iter()
+            .chain(
+                build_constraints_from_workspace
+                    .into_iter()
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect();
+
+    // Find an interpreter to use for building distributions. Unlike `uv pip install`, the
+    // interpreter isn't modified, so a virtual environment isn't required.
+    let environment_preference = EnvironmentPreference::from_system_flag(system, false);
+    let python_preference = python_preference.with_system_flag(system);
+    let reporter = PythonDownloadReporter::single(printer);
+    let request = if let Some(python) = python.as_deref() {
+        PythonRequest::parse(python)
+    } else if let Some(python_version) = python_version.as_ref() {
+        PythonRequest::Version(VersionRequest::from(python_version))
+    } else {
+        PythonRequest::default()
+    };
+    let interpreter = PythonInstallation::find_best(
+        &request,
+        environment_preference,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        &cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Determine the markers and tags to use for the resolution.
+    let marker_env = resolution_markers(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    );
+    let tags = resolution_tags(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        &interpreter,
+    )?;
+
+    // Determine the Python requirement, if the user requested a specific version.
+    let python_requirement = if let Some(python_version) = python_version.as_ref() {
+        PythonRequirement::from_python_version(&interpreter, python_version)
+    } else {
+        PythonRequirement::from_interpreter(&interpreter)
+    };
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build()?;
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.combine(no_binary, no_build);
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+    };
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let types_build_isolation = match build_isolation {
+        BuildIsolation::Isolate => uv_types::BuildIsolation::Isolated,
+        BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::Shared(&environment)
+        }
+        BuildIsolation::SharedPackage(ref packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Enforce (but never require) the build constraints, if `--require-hashes` or `--verify-hashes`
+    // is provided.
+    let build_hasher = if hash_checking.is_some() {
+        HashStrategy::from_requirements(
+            std::iter::empty(),
+            build_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            HashCheckingMode::Verify,
+        )?
+    } else {
+        HashStrategy::None
+    };
+    let build_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone()),
+    );
+
+    // Lower the extra build dependencies, if any.
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
+            .into_inner();
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        types_build_isolation,
+        &extra_build_requires,
+        extra_build_variables,
+        link_mode,
+        &build_options,
+        &build_hasher,
+        exclude_newer.clone(),
+        sources,
+        SourceTreeEditablePolicy::Project,
+        workspace_cache,
+        concurrency.clone(),
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .build();
+
+    // Resolve the requirements.
+    let (resolution, hasher) = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        override_dependencies,
+        excludes,
+        source_trees,
+        project,
+        BTreeSet::default(),
+        &ExtrasSpecification::default(),
+        &groups,
+        Vec::default(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &upgrade,
+        Some(&tags),
+        ResolverEnvironment::specific(marker_env),
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        state.index(),
+        &build_dispatch,
+        &concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok((graph, hasher)) => (Resolution::from(graph), hasher),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    let distributions = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(Arc::clone(dist)),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    fs_err::tokio::create_dir_all(dest).await?;
+
+    // Download the archives into the target directory.
+    let preparer = Preparer::new(
+        &cache,
+        &tags,
+        &hasher,
+        &build_options,
+        DistributionDatabase::new(
+            &client,
+            &build_dispatch,
+            concurrency.downloads_semaphore.clone(),
+        ),
+    )
+    .with_reporter(Arc::new(
+        PrepareReporter::from(printer).with_length(distributions.len() as u64),
+    ));
+
+    let archives = match preparer.download(distributions, dest, &resolution).await {
+        Ok(archives) => archives,
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(operations::Error::from(err))
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    let mut downloaded = 0;
+    for (dist, path) in &archives {
+        if path.is_some() {
+            downloaded += 1;
+        } else {
+            warn_user!(
+                "Skipping `{}`, which is not available as a wheel or source distribution archive",
+                dist.to_string().cyan()
+            );
+        }
+    }
+
+    let s = if downloaded == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Downloaded {} to {} {}",
+            format!("{downloaded} package{s}").bold(),
+            dest.user_display().cyan(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    // Notify the user of any resolution diagnostics.
+    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+
+    Ok(ExitStatus::Success)
+}
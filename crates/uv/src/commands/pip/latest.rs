@@ -7,6 +7,7 @@ use uv_distribution_types::{
     File, IndexCapabilities, IndexLocations, IndexMetadataRef, IndexUrl, RequiresPython,
 };
 use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
 use uv_platform_tags::Tags;
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
 use uv_warnings::warn_user_once;
@@ -107,19 +108,45 @@ impl LatestClient<'_> {
         debug!("Fetching latest version of: `{package}`");
 
         let mut latest: Option<DistFilename> = None;
+        self.for_each_candidate(package, index, download_concurrency, |candidate| {
+            update_latest(&mut latest, candidate);
+        })
+        .await?;
 
-        let mut update_latest = |candidate: DistFilename| {
-            // Prefer higher versions, and prefer wheels over sdists at parity.
-            if latest.as_ref().is_none_or(|current| {
-                candidate.version() > current.version()
-                    || (candidate.version() == current.version()
-                        && matches!(candidate, DistFilename::WheelFilename(_))
-                        && matches!(current, DistFilename::SourceDistFilename(_)))
-            }) {
-                latest = Some(candidate);
+        Ok(latest)
+    }
+
+    /// Find the latest version of a package from an index, along with the latest version that
+    /// satisfies the given specifiers.
+    pub(crate) async fn find_latest_compatible(
+        &self,
+        package: &PackageName,
+        index: Option<&IndexUrl>,
+        specifiers: &VersionSpecifiers,
+        download_concurrency: &Semaphore,
+    ) -> Result<LatestVersions, uv_client::Error> {
+        debug!("Fetching latest versions of: `{package}`");
+
+        let mut versions = LatestVersions::default();
+        self.for_each_candidate(package, index, download_concurrency, |candidate| {
+            if specifiers.contains(candidate.version()) {
+                update_latest(&mut versions.compatible, candidate.clone());
             }
-        };
+            update_latest(&mut versions.latest, candidate);
+        })
+        .await?;
+
+        Ok(versions)
+    }
 
+    /// Visit every distribution of a package that's eligible to be the latest version.
+    async fn for_each_candidate(
+        &self,
+        package: &PackageName,
+        index: Option<&IndexUrl>,
+        download_concurrency: &Semaphore,
+        mut visit: impl FnMut(DistFilename),
+    ) -> Result<(), uv_client::Error> {
         let archives = match self
             .client
             .simple_detail(
@@ -156,7 +183,7 @@ impl LatestClient<'_> {
 
                         for (filename, file) in files.all() {
                             if self.consider_candidate(&filename, &file, exclude_newer.as_ref()) {
-                                update_latest(filename);
+                                visit(filename);
                             }
                         }
                     }
@@ -165,7 +192,7 @@ impl LatestClient<'_> {
                     for entry in entries {
                         let (filename, file, _) = entry.into_parts();
                         if self.consider_candidate(&filename, &file, exclude_newer.as_ref()) {
-                            update_latest(filename);
+                            visit(filename);
                         }
                     }
                 }
@@ -181,11 +208,33 @@ impl LatestClient<'_> {
                 let (filename, file, index) = entry.into_parts();
                 let exclude_newer = self.effective_exclude_newer(package, &index);
                 if self.consider_candidate(&filename, &file, exclude_newer.as_ref()) {
-                    update_latest(filename);
+                    visit(filename);
                 }
             }
         }
 
-        Ok(latest)
+        Ok(())
+    }
+}
+
+/// The latest versions of a package, as returned by [`LatestClient::find_latest_compatible`].
+#[derive(Debug, Default)]
+pub(crate) struct LatestVersions {
+    /// The latest version of the package.
+    pub(crate) latest: Option<DistFilename>,
+    /// The latest version of the package that satisfies the requested specifiers.
+    pub(crate) compatible: Option<DistFilename>,
+}
+
+/// Replace the current latest distribution with the candidate, if the candidate is preferred.
+fn update_latest(latest: &mut Option<DistFilename>, candidate: DistFilename) {
+    // Prefer higher versions, and prefer wheels over sdists at parity.
+    if latest.as_ref().is_none_or(|current| {
+        candidate.version() > current.version()
+            || (candidate.version() == current.version()
+                && matches!(candidate, DistFilename::WheelFilename(_))
+                && matches!(current, DistFilename::SourceDistFilename(_)))
+    }) {
+        *latest = Some(candidate);
     }
 }
@@ -5,8 +5,10 @@ use uv_platform_tags::{Tags, TagsError, TagsOptions};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
+pub(crate) mod audit;
 pub(crate) mod check;
 pub(crate) mod compile;
+pub(crate) mod download;
 pub(crate) mod freeze;
 pub(crate) mod install;
 pub(crate) mod latest;
@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
 use std::fmt::Write;
 
 use anyhow::Result;
@@ -12,6 +13,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
 use uv_distribution_types::{
@@ -21,14 +23,17 @@ use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{Requirement, VersionOrUrl};
+use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ResolutionMetadata, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::latest::LatestClient;
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::tree_graph::{self, GraphReport};
 use crate::printer::Printer;
 
 /// Display the installed packages in the current environment as a dependency tree.
@@ -41,6 +46,7 @@ pub(crate) async fn pip_tree(
     no_dedupe: bool,
     invert: bool,
     outdated: bool,
+    output_format: TreeFormat,
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -54,7 +60,16 @@ pub(crate) async fn pip_tree(
     system: bool,
     cache: &Cache,
     printer: Printer,
+    preview: Preview,
 ) -> Result<ExitStatus> {
+    if matches!(output_format, TreeFormat::Json) && !preview.is_enabled(PreviewFeature::JsonOutput)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
         &python.map(PythonRequest::parse).unwrap_or_default(),
@@ -149,8 +164,7 @@ pub(crate) async fn pip_tree(
         FxHashMap::default()
     };
 
-    // Render the tree.
-    let rendered_tree = DisplayDependencyGraph::new(
+    let graph = DisplayDependencyGraph::new(
         depth.into(),
         prune,
         package,
@@ -160,21 +174,30 @@ pub(crate) async fn pip_tree(
         &markers,
         &packages,
         &latest,
-    )
-    .render()
-    .join("\n");
+    );
 
-    if !rendered_tree.is_empty() {
-        writeln!(printer.stdout(), "{rendered_tree}")?;
-    }
+    if matches!(output_format, TreeFormat::Text) {
+        // Render the tree.
+        let rendered_tree = graph.render().join("\n");
 
-    if rendered_tree.contains("(*)") {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
+        if !rendered_tree.is_empty() {
+            writeln!(printer.stdout(), "{rendered_tree}")?;
+        }
+
+        if rendered_tree.contains("(*)") {
+            let message = if no_dedupe {
+                "(*) Package tree is a cycle and cannot be shown".italic()
+            } else {
+                "(*) Package tree already displayed".italic()
+            };
+            writeln!(printer.stdout(), "{message}")?;
+        }
+    } else {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            graph.report().render(output_format)?
+        )?;
     }
 
     // Validate that the environment is consistent.
@@ -618,6 +641,94 @@ impl<'env> DisplayDependencyGraph<'env> {
         }
     }
 
+    /// Return the dependency graph, for display in a machine-readable format.
+    ///
+    /// Unlike the rendered tree, each package appears once. Packages beyond the maximum display
+    /// depth are omitted.
+    fn report(&self) -> GraphReport {
+        // Perform a breadth-first traversal from the roots, tracking the depth of each package.
+        let mut depths = self
+            .roots
+            .iter()
+            .map(|root| (*root, 0))
+            .collect::<FxHashMap<_, _>>();
+        let mut queue = self.roots.iter().copied().collect::<VecDeque<_>>();
+        let mut edges = Vec::new();
+        while let Some(node) = queue.pop_front() {
+            let depth = depths[&node];
+            if depth >= self.depth {
+                continue;
+            }
+            for edge in self.graph.edges_directed(node, Direction::Outgoing) {
+                edges.push(edge.id());
+                if let Entry::Vacant(entry) = depths.entry(edge.target()) {
+                    entry.insert(depth + 1);
+                    queue.push_back(edge.target());
+                }
+            }
+        }
+
+        // Sort the packages, such that the output is deterministic.
+        let mut nodes = depths.keys().copied().collect::<Vec<_>>();
+        nodes.sort_by_key(|index| {
+            let metadata = &self.graph[*index];
+            (&metadata.name, &metadata.version)
+        });
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, index)| (*index, position))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut roots = self
+            .roots
+            .iter()
+            .map(|root| positions[root])
+            .collect::<Vec<_>>();
+        roots.sort_unstable();
+
+        let mut edges = edges
+            .into_iter()
+            .map(|edge| {
+                let (source, target) = self
+                    .graph
+                    .edge_endpoints(edge)
+                    .expect("edge was collected from the graph");
+                let requirement = self.graph[edge];
+                tree_graph::Edge {
+                    from: Some(positions[&source]),
+                    to: positions[&target],
+                    extras: requirement.extras.iter().map(ToString::to_string).collect(),
+                    extra: None,
+                    group: None,
+                    specifier: requirement.version_or_url.as_ref().map(ToString::to_string),
+                    marker: requirement.marker.try_to_string(),
+                }
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by_key(|edge| (edge.from, edge.to));
+
+        let nodes = nodes
+            .into_iter()
+            .map(|index| {
+                let metadata = &self.graph[index];
+                tree_graph::Node {
+                    name: metadata.name.to_string(),
+                    version: Some(metadata.version.to_string()),
+                    source: None,
+                    latest: self
+                        .latest
+                        .get(&metadata.name)
+                        .filter(|&version| *version > metadata.version)
+                        .map(ToString::to_string),
+                    size: None,
+                }
+            })
+            .collect();
+
+        GraphReport::new(roots, nodes, edges)
+    }
+
     /// Depth-first traverse the nodes to render the tree.
     fn render(&self) -> Vec<String> {
         let mut path = Vec::new();
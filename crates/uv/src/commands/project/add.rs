@@ -29,6 +29,7 @@ use uv_distribution_types::{
 use uv_fs::{CWD, LockedFile, LockedFileError, Simplified};
 use uv_git::store_credentials;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, PackageName};
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::Preview;
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
@@ -804,6 +805,267 @@ pub(crate) async fn add(
     }
 }
 
+/// Rewrite the bounds of the existing dependencies across the workspace to match the lockfile.
+pub(crate) async fn bump_all(
+    project_dir: &Path,
+    active: Option<bool>,
+    bounds: Option<AddBoundsKind>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let bound_kind = bounds.unwrap_or_default();
+
+    // Find the workspace.
+    // No workspace caching since `uv add` changes the workspace definition.
+    let project = VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let workspace = project.workspace();
+
+    // Discover the interpreter.
+    let groups = DependencyGroupsWithDefaults::none();
+    let workspace_python = WorkspacePython::from_request(
+        python.as_deref().map(PythonRequest::parse),
+        Some(workspace),
+        &groups,
+        project_dir,
+        no_config,
+    )
+    .await?;
+    let interpreter = ProjectInterpreter::discover(
+        workspace,
+        &groups,
+        workspace_python,
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        active,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Lock the workspace, to determine the locked version of each dependency.
+    let state = UniversalState::default();
+    let lock = match Box::pin(
+        project::lock::LockOperation::new(
+            LockMode::Write(&interpreter),
+            &settings.resolver,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            &WorkspaceCache::default(),
+            printer,
+            preview,
+        )
+        .execute(workspace.into()),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Collect the locked versions of each registry package.
+    let mut locked_versions = FxHashMap::<&PackageName, Vec<&Version>>::default();
+    for package in lock.packages() {
+        let Some(version) = package.version() else {
+            continue;
+        };
+        if package.index(workspace.install_path())?.is_none() {
+            continue;
+        }
+        locked_versions
+            .entry(package.name())
+            .or_default()
+            .push(version);
+    }
+
+    // Visit every workspace member, along with the workspace root, if it isn't a member itself.
+    let mut pyprojects = workspace
+        .packages()
+        .values()
+        .map(|member| {
+            (
+                member.root().join("pyproject.toml"),
+                member.pyproject_toml(),
+            )
+        })
+        .collect::<Vec<_>>();
+    if !workspace
+        .packages()
+        .values()
+        .any(|member| member.root() == workspace.install_path())
+    {
+        pyprojects.push((
+            workspace.install_path().join("pyproject.toml"),
+            workspace.pyproject_toml(),
+        ));
+    }
+
+    let mut originals = Vec::new();
+    let mut bumped = Vec::new();
+    for (path, pyproject_toml) in pyprojects {
+        let mut toml =
+            PyProjectTomlMut::from_toml(&pyproject_toml.raw, DependencyTarget::PyProjectToml)?;
+        let member_sources = pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.sources.as_ref())
+            .map(ToolUvSources::inner);
+
+        let mut modified = false;
+        for (dependency_type, index, requirement) in toml.dependencies() {
+            // Only rewrite the bounds of registry requirements.
+            if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
+                continue;
+            }
+            if workspace.packages().contains_key(&requirement.name) {
+                continue;
+            }
+            let sources = member_sources
+                .and_then(|sources| sources.get(&requirement.name))
+                .or_else(|| workspace.sources().get(&requirement.name));
+            if sources.is_some_and(|sources| {
+                sources
+                    .iter()
+                    .any(|source| !matches!(source, Source::Registry { .. }))
+            }) {
+                continue;
+            }
+
+            // Use the lowest locked version that satisfies the existing requirement, since the
+            // lockfile may contain multiple versions across forks.
+            let Some(version) = locked_versions.get(&requirement.name).and_then(|versions| {
+                versions
+                    .iter()
+                    .filter(|version| match &requirement.version_or_url {
+                        Some(VersionOrUrl::VersionSpecifier(specifiers)) => {
+                            specifiers.contains(version)
+                        }
+                        _ => true,
+                    })
+                    .min()
+            }) else {
+                continue;
+            };
+
+            // Drop the local version identifier, which isn't permitted in `>=` constraints.
+            // For example, convert `1.2.3+local` to `1.2.3`.
+            let version = (*version).clone().without_local();
+
+            let specifiers = bound_kind.specifiers(version.clone());
+            if matches!(
+                &requirement.version_or_url,
+                Some(VersionOrUrl::VersionSpecifier(existing)) if *existing == specifiers
+            ) {
+                continue;
+            }
+
+            toml.set_dependency_bound(&dependency_type, index, version, bound_kind)?;
+            modified = true;
+
+            let mut updated = requirement.clone();
+            updated.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+            bumped.push((path.clone(), requirement, updated));
+        }
+
+        if modified {
+            fs_err::write(&path, toml.to_string())?;
+            originals.push((path, pyproject_toml.raw.clone()));
+        }
+    }
+
+    if bumped.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "All dependency bounds already match the lockfile"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Re-lock the workspace, to record the updated requirements in the lockfile.
+    let result = async {
+        let project = VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            &WorkspaceCache::default(),
+        )
+        .await?;
+        let state = UniversalState::default();
+        Box::pin(
+            project::lock::LockOperation::new(
+                LockMode::Write(&interpreter),
+                &settings.resolver,
+                &client_builder,
+                &state,
+                Box::new(SummaryResolveLogger),
+                &concurrency,
+                cache,
+                &WorkspaceCache::default(),
+                printer,
+                preview,
+            )
+            .execute(project.workspace().into()),
+        )
+        .await
+    }
+    .await;
+    if let Err(err) = result {
+        // Restore the original `pyproject.toml` files if the re-lock failed.
+        for (path, original) in &originals {
+            fs_err::write(path, original)?;
+        }
+        return match err {
+            ProjectError::Operation(err) => {
+                diagnostics::OperationDiagnostic::with_system_certs(client_builder.system_certs())
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+            }
+            err => Err(err.into()),
+        };
+    }
+
+    for (path, requirement, updated) in bumped {
+        writeln!(
+            printer.stderr(),
+            "Updated `{}` -> `{}` in `{}`",
+            requirement,
+            updated,
+            path.user_display()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 fn standard_library_hint(
     operation_error: &crate::commands::pip::operations::Error,
     edits: &[DependencyEdit],
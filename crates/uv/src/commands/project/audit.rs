@@ -1,14 +1,17 @@
 use itertools::Itertools as _;
 use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
 use std::fmt::Write as _;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::commands::ExitStatus;
 use crate::commands::diagnostics;
+use crate::commands::pip;
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
 use crate::commands::project::default_dependency_groups;
-use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockEvent, LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -18,27 +21,37 @@ use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
 
 use anyhow::Result;
+use cyclonedx_bom::prelude::Bom;
+use jiff::Zoned;
 use rustc_hash::FxHashSet;
-use tracing::trace;
+use tracing::{debug, trace};
 use uv_audit::{
-    AdverseStatus, Dependency, Finding, ProjectStatus, ProjectStatusAudit, Vulnerability,
-    VulnerabilityID, VulnerabilityServiceFormat, osv,
+    AdverseStatus, Dependency, Finding, ProjectStatus, ProjectStatusAudit, Severity, Vulnerability,
+    VulnerabilityID, VulnerabilityServiceFormat, osv, osv_dump,
 };
-use uv_cache::Cache;
+use uv_cache::{Cache, Refresh};
 use uv_cli::AuditOutputFormat;
-use uv_client::{BaseClientBuilder, CachedClient, RegistryClientBuilder};
-use uv_configuration::{Concurrency, DependencyGroups, ExtrasSpecification, TargetTriple};
-use uv_distribution_types::{IndexCapabilities, IndexUrl};
-use uv_fs::{CWD, find_git_repository_root, relative_to};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_client::{BaseClient, BaseClientBuilder, CachedClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
+    ExtrasSpecificationWithDefaults, InstallOptions, TargetTriple, Upgrade,
+};
+use uv_distribution_types::{IndexCapabilities, IndexUrl, Requirement, RequirementSource};
+use uv_fs::{CWD, Simplified, find_git_repository_root, relative_to};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_resolver::{Lock, cyclonedx_json};
 use uv_scripts::Pep723Script;
-use uv_settings::PythonInstallMirrors;
-use uv_warnings::warn_user;
+use uv_settings::{AuditSuppression, PythonInstallMirrors};
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
+mod cyclonedx;
 mod json;
+mod openvex;
 mod sarif;
 
 pub(crate) async fn audit(
@@ -63,8 +76,9 @@ pub(crate) async fn audit(
     output_format: AuditOutputFormat,
     service: VulnerabilityServiceFormat,
     service_url: Option<String>,
-    ignore: Vec<VulnerabilityID>,
-    ignore_until_fixed: Vec<VulnerabilityID>,
+    update_db: bool,
+    fix: bool,
+    policy: AuditPolicy,
 ) -> Result<ExitStatus> {
     // Check if the audit feature is in preview
     if !preview.is_enabled(PreviewFeature::Audit) {
@@ -82,6 +96,23 @@ pub(crate) async fn audit(
         );
     }
 
+    // The location of the local OSV database, for `--service-format osv-dump`.
+    let dump_directory = osv_dump_directory(service, service_url.as_deref(), &cache);
+
+    if update_db {
+        let client = client_builder.build()?;
+        let count = osv_dump::OsvDump::new(dump_directory.clone())
+            .update(&client, &osv_dump::DUMP_URL)
+            .await?;
+        writeln!(
+            printer.stderr(),
+            "Updated the OSV database with {} at: {}",
+            format!("{count} vulnerability records").bold(),
+            dump_directory.user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
     let workspace_cache = WorkspaceCache::default();
     let workspace;
     let target = if let Some(script) = script.as_ref() {
@@ -178,7 +209,7 @@ pub(crate) async fn audit(
     let state = UniversalState::default();
 
     // Update the lockfile, if necessary.
-    let lock = match Box::pin(
+    let mut lock = match Box::pin(
         LockOperation::new(
             mode,
             &settings,
@@ -215,13 +246,6 @@ pub(crate) async fn audit(
         )
     });
 
-    // Build the set of auditable packages by traversing the lockfile from workspace roots,
-    // respecting the user's extras and dependency-group filters. Workspace members are excluded
-    // (they are local and have no external package identity), as are packages without a version.
-    // The `Auditable` view offers per-version and per-project projections from a single walk.
-    let auditable = lock.auditable(&extras, &groups, |_| true);
-    let mut projects = auditable.projects(target.install_path())?;
-
     // Drop projects whose index is configured as flat, since we know we won't
     // find PEP 792 statuses.
     let flat_index_urls: FxHashSet<&IndexUrl> = settings
@@ -229,136 +253,624 @@ pub(crate) async fn audit(
         .flat_indexes()
         .map(|index| &index.url)
         .collect();
-    projects.retain(|(_, url)| !flat_index_urls.contains(url));
 
-    // Perform the audit.
-    let reporter = AuditReporter::from(printer);
-    let dependencies: Vec<Dependency> = auditable
-        .packages()
-        .map(|(name, version)| Dependency::new(name.clone(), version.clone()))
-        .collect();
     let base_client = client_builder.clone().build()?;
-
-    let registry_client = RegistryClientBuilder::new(client_builder, cache.clone())
+    let registry_client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
         .index_locations(settings.index_locations.clone())
         .keyring(settings.keyring_provider)
         .build()?;
     let capabilities = IndexCapabilities::default();
-    let status_audit =
-        ProjectStatusAudit::new(&registry_client, &capabilities, concurrency.clone());
-
-    let osv_future = async {
-        match service {
-            VulnerabilityServiceFormat::Osv => {
-                let osv_url = service_url
-                    .as_deref()
-                    .map(|url| url.parse().expect("invalid OSV service URL"))
-                    .unwrap_or_else(|| osv::API_BASE.clone());
-                let client = CachedClient::new(base_client);
-                let service = osv::Osv::new(client, Some(osv_url), concurrency, cache.clone());
-                trace!("Auditing {n} dependencies against OSV", n = auditable.len());
-                service.query_batch(&dependencies, osv::Filter::All).await
-            }
-        }
+
+    let auditor = Auditor {
+        extras: &extras,
+        groups: &groups,
+        install_path: target.install_path(),
+        flat_index_urls,
+        status_audit: ProjectStatusAudit::new(&registry_client, &capabilities, concurrency.clone()),
+        base_client,
+        service,
+        service_url: service_url.as_deref(),
+        dump_directory: &dump_directory,
+        concurrency: &concurrency,
+        cache: &cache,
+        printer,
     };
-    let status_future = async {
-        trace!(
-            "Auditing {n} projects for adverse status",
-            n = projects.len()
-        );
-        status_audit.query_batch(&projects).await
+
+    // Perform the audit.
+    let (mut n_packages, all_findings) = auditor.audit(&lock).await?;
+
+    // Filter out ignored vulnerabilities, tracking which ignore rules actually matched.
+    let mut matched_rules = MatchedRules::default();
+    let (mut all_findings, mut ignored) = policy.filter(all_findings, &mut matched_rules);
+
+    // Upgrade the vulnerable packages, and audit the updated lockfile.
+    if fix {
+        let fixed = Box::pin(fix_vulnerabilities(
+            &all_findings,
+            &lock,
+            mode,
+            target,
+            &settings,
+            &client_builder,
+            &concurrency,
+            &cache,
+            printer,
+            preview,
+        ))
+        .await?;
+        if let Some(fixed) = fixed {
+            let (fixed_n_packages, fixed_findings) = auditor.audit(&fixed).await?;
+            n_packages = fixed_n_packages;
+            (all_findings, ignored) = policy.filter(fixed_findings, &mut matched_rules);
+            lock = fixed;
+        }
+    }
+
+    // Warn about ignore rules that didn't match any vulnerability.
+    policy.warn_unmatched(&matched_rules);
+
+    let lock_path = target.lock_path();
+    // If we've run `uv audit --script`, we might only have an in-memory lockfile.
+    // In that case, use the script's own path as the artifact path.
+    let artifact_path = if let LockTarget::Script(script) = target
+        && !lock_path.is_file()
+    {
+        script.path.as_path()
+    } else {
+        lock_path.as_path()
     };
-    let (osv_findings, status_findings) = tokio::join!(osv_future, status_future);
-    let mut all_findings = osv_findings?;
-    all_findings.extend(status_findings);
-
-    reporter.on_audit_complete();
-
-    // Filter out ignored vulnerabilities, tracking how many were ignored
-    // and which ignore rules actually matched.
-    let mut matched_ignores: FxHashSet<&VulnerabilityID> = FxHashSet::default();
-    let all_findings: Vec<_> = all_findings
-        .into_iter()
-        .filter(|finding| match finding {
-            Finding::Vulnerability(vulnerability) => {
-                if let Some(id) = ignore.iter().find(|id| vulnerability.matches(id)) {
-                    matched_ignores.insert(id);
-                    return false;
+
+    // For `CycloneDX` reports, build an SBOM of the lockfile so that findings can reference its
+    // components.
+    let bom = if matches!(output_format, AuditOutputFormat::CycloneDX1_5) {
+        let install_target = match target {
+            LockTarget::Workspace(workspace) if workspace.is_non_project() => {
+                InstallTarget::NonProjectWorkspace {
+                    workspace,
+                    lock: &lock,
                 }
-                if let Some(id) = ignore_until_fixed
+            }
+            LockTarget::Workspace(workspace) => InstallTarget::Workspace {
+                workspace,
+                lock: &lock,
+            },
+            LockTarget::Script(script) => InstallTarget::Script {
+                script,
+                lock: &lock,
+            },
+        };
+        Some(cyclonedx_json::from_lock(
+            &install_target,
+            &[],
+            &extras,
+            &groups,
+            false,
+            &InstallOptions::default(),
+            true,
+        )?)
+    } else {
+        None
+    };
+
+    let display = AuditResults {
+        printer,
+        n_packages,
+        output_format,
+        findings: all_findings,
+        ignored,
+        bom,
+        artifact_uri: artifact_uri(artifact_path),
+        policy,
+    };
+    display.render()
+}
+
+/// The URI of the audited artifact (e.g., the lockfile) for SARIF reports.
+pub(crate) fn artifact_uri(artifact_path: &Path) -> String {
+    // SARIF consumers resolve artifact locations from the repository root, regardless of
+    // the directory from which uv was invoked. Fall back to the invocation directory for
+    // projects that aren't in a Git repository.
+    let artifact_path = if let Some(repository_root) = find_git_repository_root(artifact_path)
+        && let Ok(relative) = relative_to(artifact_path, repository_root)
+    {
+        relative
+    } else if let Ok(relative) = artifact_path.strip_prefix(&*CWD) {
+        relative.to_path_buf()
+    } else {
+        artifact_path.to_path_buf()
+    };
+    artifact_path.to_string_lossy().replace('\\', "/")
+}
+
+/// The services used to audit the packages in a lockfile.
+struct Auditor<'a> {
+    extras: &'a ExtrasSpecificationWithDefaults,
+    groups: &'a DependencyGroupsWithDefaults,
+    install_path: &'a Path,
+    flat_index_urls: FxHashSet<&'a IndexUrl>,
+    status_audit: ProjectStatusAudit<'a>,
+    base_client: BaseClient,
+    service: VulnerabilityServiceFormat,
+    service_url: Option<&'a str>,
+    dump_directory: &'a Path,
+    concurrency: &'a Concurrency,
+    cache: &'a Cache,
+    printer: Printer,
+}
+
+impl Auditor<'_> {
+    /// Audit the packages in the lockfile, returning the number of audited packages and the
+    /// findings.
+    async fn audit(&self, lock: &Lock) -> Result<(usize, Vec<Finding>)> {
+        // Build the set of auditable packages by traversing the lockfile from workspace roots,
+        // respecting the user's extras and dependency-group filters. Workspace members are excluded
+        // (they are local and have no external package identity), as are packages without a version.
+        // The `Auditable` view offers per-version and per-project projections from a single walk.
+        let auditable = lock.auditable(self.extras, self.groups, |_| true);
+        let mut projects = auditable.projects(self.install_path)?;
+        projects.retain(|(_, url)| !self.flat_index_urls.contains(url));
+
+        let reporter = AuditReporter::from(self.printer);
+        let dependencies: Vec<Dependency> = auditable
+            .packages()
+            .map(|(name, version)| Dependency::new(name.clone(), version.clone()))
+            .collect();
+
+        let osv_future = query_vulnerabilities(
+            &dependencies,
+            self.service,
+            self.service_url,
+            self.dump_directory,
+            self.base_client.clone(),
+            self.concurrency,
+            self.cache,
+        );
+        let status_future = async {
+            trace!(
+                "Auditing {n} projects for adverse status",
+                n = projects.len()
+            );
+            self.status_audit.query_batch(&projects).await
+        };
+        let (osv_findings, status_findings) = tokio::join!(osv_future, status_future);
+        let mut all_findings = osv_findings?;
+        all_findings.extend(status_findings);
+
+        reporter.on_audit_complete();
+
+        Ok((auditable.len(), all_findings))
+    }
+}
+
+/// The location of the local OSV database, for `--service-format osv-dump`.
+pub(crate) fn osv_dump_directory(
+    service: VulnerabilityServiceFormat,
+    service_url: Option<&str>,
+    cache: &Cache,
+) -> PathBuf {
+    match service {
+        VulnerabilityServiceFormat::OsvDump => service_url
+            .map(osv_dump::OsvDump::parse_location)
+            .unwrap_or_else(|| osv_dump::OsvDump::default_directory(cache)),
+        VulnerabilityServiceFormat::Osv => osv_dump::OsvDump::default_directory(cache),
+    }
+}
+
+/// Query the vulnerability service for known vulnerabilities in the given dependencies.
+pub(crate) async fn query_vulnerabilities(
+    dependencies: &[Dependency],
+    service: VulnerabilityServiceFormat,
+    service_url: Option<&str>,
+    dump_directory: &Path,
+    client: BaseClient,
+    concurrency: &Concurrency,
+    cache: &Cache,
+) -> Result<Vec<Finding>> {
+    match service {
+        VulnerabilityServiceFormat::Osv => {
+            let osv_url = service_url
+                .map(|url| url.parse().expect("invalid OSV service URL"))
+                .unwrap_or_else(|| osv::API_BASE.clone());
+            let client = CachedClient::new(client);
+            let service = osv::Osv::new(client, Some(osv_url), concurrency.clone(), cache.clone());
+            trace!(
+                "Auditing {n} dependencies against OSV",
+                n = dependencies.len()
+            );
+            Ok(service.query_batch(dependencies, osv::Filter::All).await?)
+        }
+        VulnerabilityServiceFormat::OsvDump => {
+            trace!(
+                "Auditing {n} dependencies against the OSV database at {}",
+                dump_directory.user_display(),
+                n = dependencies.len()
+            );
+            let service = osv_dump::OsvDump::new(dump_directory.to_path_buf());
+            Ok(service.query_batch(dependencies, osv::Filter::All).await?)
+        }
+    }
+}
+
+/// The policy for ignoring vulnerabilities, and for which findings fail the audit.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AuditPolicy {
+    /// The vulnerabilities to ignore.
+    pub(crate) ignore: Vec<VulnerabilityID>,
+    /// The vulnerabilities to ignore while no fix is available.
+    pub(crate) ignore_until_fixed: Vec<VulnerabilityID>,
+    /// The vulnerabilities to suppress, each with a reason, and an optional package and expiry.
+    pub(crate) suppressions: Vec<AuditSuppression>,
+    /// The minimum severity for a vulnerability to fail the audit.
+    pub(crate) severity_threshold: Option<Severity>,
+    /// The adverse project statuses that fail the audit.
+    pub(crate) fail_on_status: Vec<AdverseStatus>,
+}
+
+/// The ignore rules of an [`AuditPolicy`] that matched at least one vulnerability.
+#[derive(Debug, Default)]
+pub(crate) struct MatchedRules<'a> {
+    ignores: FxHashSet<&'a VulnerabilityID>,
+    /// The indices of the matched suppressions.
+    suppressions: FxHashSet<usize>,
+}
+
+impl AuditPolicy {
+    /// Separate ignored vulnerabilities from the findings, recording which ignore rules matched.
+    ///
+    /// Returns the remaining findings, along with the ignored vulnerabilities.
+    pub(crate) fn filter<'a>(
+        &'a self,
+        findings: Vec<Finding>,
+        matched: &mut MatchedRules<'a>,
+    ) -> (Vec<Finding>, Vec<IgnoredVulnerability>) {
+        let today = Zoned::now().date();
+
+        let mut remaining = Vec::with_capacity(findings.len());
+        let mut ignored = Vec::new();
+        for finding in findings {
+            let Finding::Vulnerability(vulnerability) = finding else {
+                remaining.push(finding);
+                continue;
+            };
+
+            if let Some(id) = self.ignore.iter().find(|id| vulnerability.matches(id)) {
+                matched.ignores.insert(id);
+                ignored.push(IgnoredVulnerability {
+                    vulnerability: *vulnerability,
+                    reason: None,
+                });
+                continue;
+            }
+
+            if let Some((index, suppression)) =
+                self.suppressions
                     .iter()
-                    .find(|id| vulnerability.matches(id))
-                {
-                    matched_ignores.insert(id);
-                    if vulnerability.fix_versions.is_empty() {
-                        return false;
+                    .enumerate()
+                    .find(|(_, suppression)| {
+                        vulnerability.matches(&VulnerabilityID::new(suppression.id.as_str()))
+                            && suppression
+                                .package
+                                .as_ref()
+                                .is_none_or(|package| package == vulnerability.dependency.name())
+                    })
+            {
+                matched.suppressions.insert(index);
+                match suppression.expires {
+                    Some(expires) if expires < today => {
+                        warn_user_once!(
+                            "The suppression of `{}` expired on {expires}, and is no longer applied",
+                            suppression.id
+                        );
+                    }
+                    _ => {
+                        ignored.push(IgnoredVulnerability {
+                            vulnerability: *vulnerability,
+                            reason: Some(suppression.reason.clone()),
+                        });
+                        continue;
                     }
                 }
-                true
             }
-            Finding::ProjectStatus(_) => true,
-        })
-        .collect();
 
-    // Warn about ignore rules that didn't match any vulnerability.
-    for id in ignore.iter().chain(ignore_until_fixed.iter()) {
-        if !matched_ignores.contains(id) {
-            warn_user!(
-                "Ignored vulnerability `{}` does not match any vulnerability in the project",
-                id.as_str()
-            );
+            if let Some(id) = self
+                .ignore_until_fixed
+                .iter()
+                .find(|id| vulnerability.matches(id))
+            {
+                matched.ignores.insert(id);
+                if vulnerability.fix_versions.is_empty() {
+                    ignored.push(IgnoredVulnerability {
+                        vulnerability: *vulnerability,
+                        reason: None,
+                    });
+                    continue;
+                }
+            }
+
+            remaining.push(Finding::Vulnerability(vulnerability));
         }
+        (remaining, ignored)
     }
 
-    let display = AuditResults {
+    /// Warn about ignore rules that didn't match any vulnerability.
+    pub(crate) fn warn_unmatched(&self, matched: &MatchedRules) {
+        for id in self.ignore.iter().chain(self.ignore_until_fixed.iter()) {
+            if !matched.ignores.contains(id) {
+                warn_user!(
+                    "Ignored vulnerability `{}` does not match any vulnerability in the project",
+                    id.as_str()
+                );
+            }
+        }
+        for (index, suppression) in self.suppressions.iter().enumerate() {
+            if !matched.suppressions.contains(&index) {
+                if let Some(package) = &suppression.package {
+                    warn_user!(
+                        "Suppressed vulnerability `{}` does not match any vulnerability in `{package}`",
+                        suppression.id
+                    );
+                } else {
+                    warn_user!(
+                        "Suppressed vulnerability `{}` does not match any vulnerability in the project",
+                        suppression.id
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the finding fails the audit.
+    fn fails(&self, finding: &Finding) -> bool {
+        match finding {
+            Finding::Vulnerability(vulnerability) => {
+                match (self.severity_threshold, vulnerability.cvss_score) {
+                    // Vulnerabilities without a score can't be compared against the threshold.
+                    (None, _) | (Some(_), None) => true,
+                    (Some(threshold), Some(_)) => vulnerability
+                        .severity()
+                        .is_some_and(|severity| severity >= threshold),
+                }
+            }
+            Finding::ProjectStatus(status) => self.fail_on_status.contains(&status.status),
+        }
+    }
+}
+
+/// A vulnerability that was ignored by the [`AuditPolicy`].
+#[derive(Debug)]
+pub(crate) struct IgnoredVulnerability {
+    pub(crate) vulnerability: Vulnerability,
+    /// The reason for ignoring the vulnerability, if it was suppressed.
+    pub(crate) reason: Option<String>,
+}
+
+/// A vulnerable package, and the lowest version that fixes all of its known vulnerabilities.
+struct Fix<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    ids: Vec<&'a VulnerabilityID>,
+}
+
+impl Fix<'_> {
+    /// The `--upgrade-package` requirement to upgrade the package to the fixed version, or higher.
+    fn requirement(&self) -> Requirement {
+        Requirement {
+            name: self.name.clone(),
+            extras: Box::new([]),
+            groups: Box::new([]),
+            marker: MarkerTree::TRUE,
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifiers::from(VersionSpecifier::greater_than_equal_version(
+                    self.version.clone(),
+                )),
+                index: None,
+                conflict: None,
+            },
+            origin: None,
+        }
+    }
+}
+
+/// Re-lock the project with each vulnerable package upgraded to the nearest fixed version, or
+/// higher, preferring the locked versions of all other packages.
+///
+/// If the packages can't be upgraded together, each package is upgraded on its own, reporting the
+/// resolver error for the vulnerabilities that can't be fixed.
+///
+/// Returns the updated lockfile, or `None` if no package was upgraded.
+async fn fix_vulnerabilities(
+    findings: &[Finding],
+    lock: &Lock,
+    mode: LockMode<'_>,
+    target: LockTarget<'_>,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: &Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<Option<Lock>> {
+    // Determine the nearest version that fixes all vulnerabilities of each package.
+    let mut fixes: BTreeMap<&PackageName, Fix> = BTreeMap::new();
+    for finding in findings {
+        let Finding::Vulnerability(vulnerability) = finding else {
+            continue;
+        };
+        let Some(version) = vulnerability.nearest_fix_version() else {
+            continue;
+        };
+        let name = vulnerability.dependency.name();
+        let fix = fixes.entry(name).or_insert_with(|| Fix {
+            name,
+            version,
+            ids: Vec::new(),
+        });
+        fix.version = fix.version.max(version);
+        fix.ids.push(vulnerability.best_id());
+    }
+    if fixes.is_empty() {
+        writeln!(printer.stderr(), "No fixable vulnerabilities found")?;
+        return Ok(None);
+    }
+    let fixes = fixes.into_values().collect::<Vec<_>>();
+
+    // Attempt to upgrade all packages at once, falling back to upgrading each package on its own.
+    let fixed = match relock(
+        &fixes.iter().collect::<Vec<_>>(),
+        mode,
+        target,
+        settings,
+        client_builder,
+        concurrency,
+        cache,
         printer,
-        n_packages: auditable.len(),
-        output_format,
-        findings: all_findings,
-        artifact_uri: {
-            let lock_path = target.lock_path();
-            // If we've run `uv audit --script`, we might only have an in-memory lockfile.
-            // In that case, use the script's own path as the artifact path.
-            let artifact_path = if let LockTarget::Script(script) = target
-                && !lock_path.is_file()
-            {
-                script.path.as_path()
-            } else {
-                lock_path.as_path()
-            };
-            // SARIF consumers resolve artifact locations from the repository root, regardless of
-            // the directory from which uv was invoked. Fall back to the invocation directory for
-            // projects that aren't in a Git repository.
-            let artifact_path = if let Some(repository_root) =
-                find_git_repository_root(artifact_path)
-                && let Ok(relative) = relative_to(artifact_path, repository_root)
-            {
-                relative
-            } else if let Ok(relative) = artifact_path.strip_prefix(&*CWD) {
-                relative.to_path_buf()
-            } else {
-                artifact_path.to_path_buf()
-            };
-            artifact_path.to_string_lossy().replace('\\', "/")
-        },
+        preview,
+    )
+    .await
+    {
+        Ok(fixed) => Some(fixed),
+        Err(ProjectError::Operation(err)) if fixes.len() == 1 => {
+            report_unfixable(&fixes[0], err, client_builder, printer)?;
+            None
+        }
+        Err(ProjectError::Operation(_)) => {
+            debug!("Failed to fix all vulnerabilities at once, fixing each package on its own");
+            let mut fixed = None;
+            let mut accepted = Vec::new();
+            for fix in &fixes {
+                let candidates = accepted.iter().copied().chain([fix]).collect::<Vec<_>>();
+                match relock(
+                    &candidates,
+                    mode,
+                    target,
+                    settings,
+                    client_builder,
+                    concurrency,
+                    cache,
+                    printer,
+                    preview,
+                )
+                .await
+                {
+                    Ok(lock) => {
+                        accepted.push(fix);
+                        fixed = Some(lock);
+                    }
+                    Err(ProjectError::Operation(err)) => {
+                        report_unfixable(fix, err, client_builder, printer)?;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            fixed
+        }
+        Err(err) => return Err(err.into()),
     };
-    display.render()
+
+    if let Some(fixed) = &fixed {
+        for event in LockEvent::detect_changes(Some(lock), fixed, DryRun::Disabled) {
+            writeln!(printer.stderr(), "{event}")?;
+        }
+    }
+
+    Ok(fixed)
 }
 
-struct AuditResults {
+/// Re-lock the project, upgrading the given packages to their fixed versions.
+async fn relock(
+    fixes: &[&Fix<'_>],
+    mode: LockMode<'_>,
+    target: LockTarget<'_>,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: &Concurrency,
+    cache: &Cache,
     printer: Printer,
-    n_packages: usize,
-    output_format: AuditOutputFormat,
-    findings: Vec<Finding>,
-    artifact_uri: String,
+    preview: Preview,
+) -> Result<Lock, ProjectError> {
+    let mut settings = settings.clone();
+    settings.upgrade = settings.upgrade.combine(
+        Upgrade::from_args(
+            None,
+            fixes.iter().map(|fix| fix.requirement()).collect(),
+            Vec::new(),
+        )
+        .unwrap_or_default(),
+    );
+    let refresh = Refresh::from(settings.upgrade.clone());
+    let state = UniversalState::default();
+    let result = Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            concurrency,
+            cache,
+            &WorkspaceCache::default(),
+            printer,
+            preview,
+        )
+        .with_refresh(&refresh)
+        .execute(target),
+    )
+    .await?;
+    Ok(result.into_lock())
+}
+
+/// Report the vulnerabilities of a package that can't be fixed, along with the resolver error.
+fn report_unfixable(
+    fix: &Fix,
+    err: pip::operations::Error,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<()> {
+    let hint = format!(
+        "`{}` could not be upgraded to {} to fix {}",
+        fix.name.cyan(),
+        format!(">={}", fix.version).cyan(),
+        fix.ids
+            .iter()
+            .map(|id| id.as_str().bold().to_string())
+            .join(", ")
+    );
+    if let Some(err) =
+        diagnostics::OperationDiagnostic::with_system_certs(client_builder.system_certs())
+            .with_hint(hint)
+            .report(err)
+    {
+        return Err(err.into());
+    }
+    // Terminate the hints, since the audit continues after the error.
+    writeln!(printer.stderr())?;
+    Ok(())
+}
+
+/// The results of an audit, rendered in the requested output format.
+pub(crate) struct AuditResults {
+    pub(crate) printer: Printer,
+    pub(crate) n_packages: usize,
+    pub(crate) output_format: AuditOutputFormat,
+    pub(crate) findings: Vec<Finding>,
+    /// The vulnerabilities that were ignored, reported as `not_affected` in VEX documents.
+    pub(crate) ignored: Vec<IgnoredVulnerability>,
+    /// An SBOM of the audited packages, whose components are referenced by `CycloneDX` reports.
+    pub(crate) bom: Option<Bom>,
+    /// The URI of the audited artifact, for SARIF reports.
+    pub(crate) artifact_uri: String,
+    /// The policy for which findings fail the audit.
+    pub(crate) policy: AuditPolicy,
 }
 
 impl AuditResults {
-    fn render(&self) -> Result<ExitStatus> {
+    pub(crate) fn render(&self) -> Result<ExitStatus> {
         match self.output_format {
             AuditOutputFormat::Text => self.render_text(),
             AuditOutputFormat::Json => self.render_json(),
             AuditOutputFormat::Sarif => self.render_sarif(),
+            AuditOutputFormat::CycloneDX1_5 => self.render_cyclonedx(),
+            AuditOutputFormat::OpenVex => self.render_openvex(),
         }
     }
 
@@ -372,13 +884,11 @@ impl AuditResults {
     }
 
     fn exit_status(&self) -> ExitStatus {
-        // NOTE: intentional: we don't currently fail if there are any adverse statuses,
-        // only when there are vulnerabilities. We will likely change this once we allow users
-        // to ignore adverse statuses and configure policies.
+        // By default, any vulnerability fails the audit, while adverse statuses don't.
         if self
             .findings
             .iter()
-            .any(|finding| matches!(finding, Finding::Vulnerability(_)))
+            .any(|finding| self.policy.fails(finding))
         {
             ExitStatus::Failure
         } else {
@@ -466,6 +976,15 @@ impl AuditResults {
                             .unwrap_or("No summary provided"),
                     )?;
 
+                    if let (Some(score), Some(severity)) =
+                        (vulnerability.cvss_score, vulnerability.severity())
+                    {
+                        writeln!(
+                            self.printer.stdout_important(),
+                            "\n  Severity: {severity} (CVSS {score:.1})"
+                        )?;
+                    }
+
                     if vulnerability.fix_versions.is_empty() {
                         writeln!(
                             self.printer.stdout_important(),
@@ -545,4 +1064,45 @@ impl AuditResults {
 
         Ok(self.exit_status())
     }
+
+    fn render_cyclonedx(&self) -> Result<ExitStatus> {
+        let (vulnerabilities, _) = self.split_findings();
+        let report = cyclonedx::report(self.bom.clone(), &vulnerabilities, &self.ignored);
+
+        let mut output = Vec::new();
+        report.output_as_json_v1_5(&mut output)?;
+        writeln!(
+            self.printer.stdout_important(),
+            "{}",
+            String::from_utf8(output)?
+        )?;
+
+        Ok(self.exit_status())
+    }
+
+    fn render_openvex(&self) -> Result<ExitStatus> {
+        let (vulnerabilities, _) = self.split_findings();
+        let report = openvex::Report::from_findings(&vulnerabilities, &self.ignored);
+
+        writeln!(
+            self.printer.stdout_important(),
+            "{}",
+            serde_json::to_string_pretty(&report)?
+        )?;
+
+        Ok(self.exit_status())
+    }
+}
+
+/// The package URL of an audited dependency, e.g., `pkg:pypi/requests@2.32.3`.
+fn package_url(dependency: &Dependency) -> String {
+    // Local version labels are introduced by a `+`, which must be percent-encoded.
+    format!(
+        "pkg:pypi/{}@{}",
+        dependency.name(),
+        dependency.version().to_string().replace('+', "%2B")
+    )
 }
+
+/// The explanation attached to ignored vulnerabilities in VEX documents.
+const IGNORED_DETAIL: &str = "The vulnerability is ignored in the audit configuration.";
@@ -0,0 +1,183 @@
+//! `CycloneDX` vulnerability exploitability exchange (VEX) reports for `uv audit`.
+//!
+//! Vulnerabilities are attached to the components of a `CycloneDX` v1.5 BOM, such that each
+//! finding references the `bom-ref` of the vulnerable component, as produced by
+//! `uv export --format cyclonedx1.5`.
+
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::tool::{Tool, Tools};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability as BomVulnerability};
+use cyclonedx_bom::models::vulnerability_analysis::{ImpactAnalysisState, VulnerabilityAnalysis};
+use cyclonedx_bom::models::vulnerability_reference::{
+    VulnerabilityReference, VulnerabilityReferences,
+};
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+use cyclonedx_bom::models::vulnerability_target::{
+    Version, Versions, VulnerabilityTarget, VulnerabilityTargets,
+};
+use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
+use jiff::Timestamp;
+use rustc_hash::FxHashMap;
+
+use uv_audit::Vulnerability;
+
+use super::{IGNORED_DETAIL, IgnoredVulnerability, package_url};
+
+/// Attach the vulnerabilities to the components of the given BOM.
+///
+/// Ignored vulnerabilities are reported with a `not_affected` analysis, detailing the reason for
+/// their suppression, if any. If no BOM is provided
+/// (e.g., when auditing an environment rather than a lockfile), or a vulnerable package is missing
+/// from the BOM, a component is created for it, identified by its package URL.
+pub(super) fn report(
+    bom: Option<Bom>,
+    vulnerabilities: &[&Vulnerability],
+    ignored: &[IgnoredVulnerability],
+) -> Bom {
+    let mut bom = bom.unwrap_or_else(|| Bom {
+        metadata: Some(Metadata {
+            timestamp: DateTime::now().ok(),
+            tools: Some(Tools::List(vec![Tool {
+                vendor: Some(NormalizedString::new("Astral Software Inc.")),
+                name: Some(NormalizedString::new("uv")),
+                version: Some(NormalizedString::new(uv_version::version())),
+                hashes: None,
+                external_references: None,
+            }])),
+            ..Metadata::default()
+        }),
+        ..Bom::default()
+    });
+
+    // Index the `bom-ref` of each component by its name and version.
+    let mut bom_refs: FxHashMap<(String, String), String> = bom
+        .metadata
+        .iter()
+        .filter_map(|metadata| metadata.component.as_ref())
+        .chain(bom.components.iter().flat_map(|components| &components.0))
+        .filter_map(|component| {
+            Some((
+                (
+                    component.name.to_string(),
+                    component.version.as_ref()?.to_string(),
+                ),
+                component.bom_ref.clone()?,
+            ))
+        })
+        .collect();
+
+    let mut findings = vulnerabilities
+        .iter()
+        .map(|vulnerability| (*vulnerability, None))
+        .chain(ignored.iter().map(|ignored| {
+            (
+                &ignored.vulnerability,
+                Some(ignored.reason.as_deref().unwrap_or(IGNORED_DETAIL)),
+            )
+        }))
+        .collect::<Vec<_>>();
+    findings.sort_by(|(first, _), (second, _)| {
+        first
+            .dependency
+            .name()
+            .cmp(second.dependency.name())
+            .then_with(|| first.dependency.version().cmp(second.dependency.version()))
+            .then_with(|| first.best_id().as_str().cmp(second.best_id().as_str()))
+    });
+
+    let mut entries = Vec::with_capacity(findings.len());
+    for (vulnerability, ignored_detail) in findings {
+        let name = vulnerability.dependency.name().to_string();
+        let version = vulnerability.dependency.version().to_string();
+        let bom_ref = bom_refs
+            .entry((name.clone(), version.clone()))
+            .or_insert_with(|| {
+                let bom_ref = package_url(&vulnerability.dependency);
+                let mut component = Component::new(
+                    Classification::Library,
+                    &name,
+                    &version,
+                    Some(bom_ref.clone()),
+                );
+                component.purl = bom_ref.parse().ok();
+                bom.components
+                    .get_or_insert_with(|| Components(Vec::new()))
+                    .0
+                    .push(component);
+                bom_ref
+            })
+            .clone();
+        entries.push(vulnerability_entry(vulnerability, bom_ref, ignored_detail));
+    }
+
+    bom.vulnerabilities = Some(Vulnerabilities(entries));
+    bom
+}
+
+/// Convert a vulnerability into a `CycloneDX` vulnerability affecting the given component.
+///
+/// If the vulnerability is ignored, `ignored_detail` explains why.
+fn vulnerability_entry(
+    vulnerability: &Vulnerability,
+    bom_ref: String,
+    ignored_detail: Option<&str>,
+) -> BomVulnerability {
+    let source = |link: Option<String>| VulnerabilitySource {
+        name: Some(NormalizedString::new("OSV")),
+        url: link.and_then(|link| Uri::try_from(link).ok()),
+    };
+    let datetime = |timestamp: Option<Timestamp>| DateTime::try_from(timestamp?.to_string()).ok();
+
+    // Aliases are listed as references, e.g., to the CVE for a PYSEC advisory.
+    let references = vulnerability
+        .aliases
+        .iter()
+        .map(|alias| VulnerabilityReference {
+            id: NormalizedString::new(alias.as_str()),
+            vulnerability_source: source(Some(format!(
+                "https://osv.dev/vulnerability/{}",
+                alias.as_str()
+            ))),
+        })
+        .collect::<Vec<_>>();
+
+    let (status, analysis, recommendation) = if let Some(detail) = ignored_detail {
+        // Suppressing a vulnerability doesn't establish why the package is unaffected, so the
+        // analysis carries the reason as its detail, without a justification.
+        let mut analysis =
+            VulnerabilityAnalysis::new(Some(ImpactAnalysisState::NotAffected), None, None);
+        analysis.detail = Some(detail.to_string());
+        ("unaffected", Some(analysis), None)
+    } else {
+        let recommendation = vulnerability.nearest_fix_version().map(|version| {
+            format!(
+                "Upgrade {} to {version} or later.",
+                vulnerability.dependency.name()
+            )
+        });
+        ("affected", None, recommendation)
+    };
+
+    let mut entry = BomVulnerability::new(None);
+    entry.id = Some(NormalizedString::new(vulnerability.id.as_str()));
+    entry.vulnerability_source = Some(source(vulnerability.link.as_ref().map(ToString::to_string)));
+    entry.vulnerability_references =
+        (!references.is_empty()).then_some(VulnerabilityReferences(references));
+    entry.description.clone_from(&vulnerability.summary);
+    entry.detail.clone_from(&vulnerability.description);
+    entry.recommendation = recommendation;
+    entry.published = datetime(vulnerability.published);
+    entry.updated = datetime(vulnerability.modified);
+    entry.vulnerability_analysis = analysis;
+    entry.vulnerability_targets = Some(VulnerabilityTargets(vec![VulnerabilityTarget {
+        bom_ref,
+        versions: Some(Versions(vec![Version::new(
+            &vulnerability.dependency.version().to_string(),
+            status,
+        )])),
+    }]));
+    entry
+}
@@ -0,0 +1,151 @@
+//! `OpenVEX` layout models for `uv audit`.
+//!
+//! Only the subset of `OpenVEX` 0.2.0 that `uv audit` emits is modeled here. Products are
+//! identified by their package URL.
+
+use jiff::Timestamp;
+use serde::Serialize;
+use uv_audit::Vulnerability;
+
+use super::{IGNORED_DETAIL, IgnoredVulnerability, package_url};
+
+/// Top-level `OpenVEX` document.
+#[derive(Debug, Serialize)]
+pub(crate) struct Report {
+    #[serde(rename = "@context")]
+    context: &'static str,
+    #[serde(rename = "@id")]
+    id: String,
+    author: &'static str,
+    timestamp: String,
+    version: u32,
+    tooling: String,
+    statements: Vec<Statement>,
+}
+
+impl Report {
+    pub(crate) fn from_findings(
+        vulnerabilities: &[&Vulnerability],
+        ignored: &[IgnoredVulnerability],
+    ) -> Self {
+        let mut statements = vulnerabilities
+            .iter()
+            .map(|vulnerability| Statement::affected(vulnerability))
+            .chain(ignored.iter().map(|ignored| {
+                Statement::not_affected(&ignored.vulnerability, ignored.reason.as_deref())
+            }))
+            .collect::<Vec<_>>();
+        statements.sort_by(|first, second| {
+            first.products[0]
+                .id
+                .cmp(&second.products[0].id)
+                .then_with(|| first.vulnerability.name.cmp(&second.vulnerability.name))
+        });
+
+        Self {
+            context: "https://openvex.dev/ns/v0.2.0",
+            id: format!("urn:uuid:{}", uuid::Uuid::new_v4()),
+            author: "uv",
+            timestamp: Timestamp::now().to_string(),
+            version: 1,
+            tooling: format!("uv {}", uv_version::version()),
+            statements,
+        }
+    }
+}
+
+/// A statement on the impact of a vulnerability on a product.
+#[derive(Debug, Serialize)]
+#[expect(clippy::struct_field_names)]
+struct Statement {
+    vulnerability: StatementVulnerability,
+    products: Vec<Product>,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    impact_statement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_statement: Option<String>,
+}
+
+impl Statement {
+    fn affected(vulnerability: &Vulnerability) -> Self {
+        // Statements with an `affected` status require an action statement.
+        let action_statement = match vulnerability.nearest_fix_version() {
+            Some(version) => format!(
+                "Upgrade {} to {version} or later.",
+                vulnerability.dependency.name()
+            ),
+            None => "No fixed version is available.".to_string(),
+        };
+        Self {
+            vulnerability: StatementVulnerability::from(vulnerability),
+            products: vec![Product::from(vulnerability)],
+            status: Status::Affected,
+            impact_statement: None,
+            action_statement: Some(action_statement),
+        }
+    }
+
+    fn not_affected(vulnerability: &Vulnerability, reason: Option<&str>) -> Self {
+        // Statements with a `not_affected` status require a justification or an impact statement.
+        // Suppressing a vulnerability doesn't establish why the product is unaffected, so only the
+        // impact statement is given.
+        Self {
+            vulnerability: StatementVulnerability::from(vulnerability),
+            products: vec![Product::from(vulnerability)],
+            status: Status::NotAffected,
+            impact_statement: Some(reason.unwrap_or(IGNORED_DETAIL).to_string()),
+            action_statement: None,
+        }
+    }
+}
+
+/// The vulnerability that a statement refers to.
+#[derive(Debug, Serialize)]
+struct StatementVulnerability {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+}
+
+impl From<&Vulnerability> for StatementVulnerability {
+    fn from(vulnerability: &Vulnerability) -> Self {
+        Self {
+            id: vulnerability.link.as_ref().map(ToString::to_string),
+            name: vulnerability.id.as_str().to_string(),
+            description: vulnerability.summary.clone(),
+            aliases: vulnerability
+                .aliases
+                .iter()
+                .map(|alias| alias.as_str().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// A software product, identified by its package URL.
+#[derive(Debug, Serialize)]
+struct Product {
+    #[serde(rename = "@id")]
+    id: String,
+}
+
+impl From<&Vulnerability> for Product {
+    fn from(vulnerability: &Vulnerability) -> Self {
+        Self {
+            id: package_url(&vulnerability.dependency),
+        }
+    }
+}
+
+/// The impact status of a vulnerability on a product.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Affected,
+    NotAffected,
+}
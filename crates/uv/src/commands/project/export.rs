@@ -13,13 +13,14 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
+    TargetTriple,
 };
 use uv_distribution_types::Verbatim;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{BundleExport, PylockToml, RequirementsTxtExport, cyclonedx_json};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
@@ -37,6 +38,8 @@ use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
 
+mod bundle;
+
 #[derive(Debug, Clone)]
 #[expect(clippy::large_enum_variant)]
 enum ExportTarget {
@@ -67,6 +70,7 @@ pub(crate) async fn export(
     hashes: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
+    python_platforms: Vec<TargetTriple>,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
     editable: Option<EditableMode>,
@@ -348,6 +352,24 @@ pub(crate) async fn export(
         }
     });
 
+    if matches!(format, ExportFormat::Bundle) {
+        if !preview.is_enabled(PreviewFeature::ExportBundle) {
+            warn_user!(
+                "`uv export --format bundle` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::ExportBundle
+            );
+        }
+        if output_file.is_none() {
+            return Err(anyhow!(
+                "`--format bundle` requires an output directory; provide one with `--output-file`"
+            ));
+        }
+    } else if !python_platforms.is_empty() {
+        return Err(anyhow!(
+            "`--python-platform` is only supported with `--format bundle`"
+        ));
+    }
+
     // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
     if !matches!(format, ExportFormat::CycloneDX1_5) {
         detect_conflicts(&target, &extras, &groups)?;
@@ -459,6 +481,13 @@ pub(crate) async fn export(
             write!(writer, "{}", export.to_toml()?)?;
         }
         ExportFormat::CycloneDX1_5 => {
+            if !preview.is_enabled(PreviewFeature::SbomExport) {
+                warn_user!(
+                    "`uv export --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                    PreviewFeature::SbomExport
+                );
+            }
+
             let export = cyclonedx_json::from_lock(
                 &target,
                 &prune,
@@ -466,12 +495,47 @@ pub(crate) async fn export(
                 &groups,
                 include_annotations,
                 &install_options,
-                preview,
                 all_packages,
             )?;
 
             export.output_as_json_v1_5(&mut writer)?;
         }
+        ExportFormat::Bundle => {
+            let directory = std::path::absolute(
+                output_file
+                    .as_deref()
+                    .expect("bundle exports require an output directory"),
+            )?;
+            let platforms = python_platforms
+                .iter()
+                .map(|platform| platform.platform_marker())
+                .collect::<Vec<_>>();
+            let export = BundleExport::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                include_annotations,
+                editable.as_ref(),
+                &install_options,
+                &platforms,
+                &directory,
+            )?;
+
+            bundle::write_bundle(
+                &export,
+                &directory,
+                &client_builder,
+                &settings,
+                &concurrency,
+                cache,
+                printer,
+            )
+            .await?;
+
+            // The bundle is written directly to the output directory.
+            return Ok(ExitStatus::Success);
+        }
     }
 
     writer.commit().await?;
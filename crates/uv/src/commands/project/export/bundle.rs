@@ -0,0 +1,116 @@
+//! Population of the bundle directory for `uv export --format bundle`.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::Concurrency;
+use uv_distribution::{ArchiveDownloader, RemoteArchive};
+use uv_distribution_types::HashPolicy;
+use uv_fs::Simplified;
+use uv_redacted::DisplaySafeUrl;
+use uv_resolver::{BundleArchive, BundleExport, BundleSource};
+
+use crate::commands::elapsed;
+use crate::commands::reporters::PrepareReporter;
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Write the archives and `pylock.toml` for a [`BundleExport`] to the given directory.
+pub(super) async fn write_bundle(
+    export: &BundleExport,
+    directory: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    settings: &ResolverSettings,
+    concurrency: &Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let start = std::time::Instant::now();
+
+    fs_err::tokio::create_dir_all(directory).await?;
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(
+        client_builder.clone().keyring(settings.keyring_provider),
+        cache.clone(),
+    )
+    .index_locations(settings.index_locations.clone())
+    .build()?;
+
+    // Retrieve each archive into the bundle directory, sharing the download path (and its
+    // concurrency limit) with `uv pip download`.
+    let reporter =
+        Arc::new(PrepareReporter::from(printer).with_length(export.archives().len() as u64));
+    let downloader = ArchiveDownloader::new(&client, concurrency.downloads_semaphore.clone())
+        .with_reporter(reporter.clone());
+    futures::stream::iter(export.archives())
+        .map(async |archive| {
+            retrieve(&downloader, archive, directory).await?;
+            reporter.on_bundled();
+            Ok::<_, anyhow::Error>(())
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+    uv_installer::PrepareReporter::on_complete(&*reporter);
+
+    // Write the `pylock.toml` that references the bundled archives.
+    fs_err::tokio::write(directory.join("pylock.toml"), export.pylock().to_toml()?).await?;
+
+    let count = export.archives().len();
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Bundled {} to {} in {}",
+            format!("{count} file{}", if count == 1 { "" } else { "s" }).bold(),
+            directory.user_display().cyan(),
+            elapsed(start.elapsed()),
+        )
+        .dimmed()
+    )?;
+
+    Ok(())
+}
+
+/// Retrieve a single archive into the bundle directory, validating its hashes.
+async fn retrieve(
+    downloader: &ArchiveDownloader<'_>,
+    archive: &BundleArchive,
+    directory: &Path,
+) -> Result<()> {
+    let url = match &archive.source {
+        BundleSource::Url(url) => url.clone(),
+        BundleSource::Path(path) => DisplaySafeUrl::from_file_path(path)
+            .map_err(|()| anyhow::anyhow!("Expected an absolute path: `{}`", path.display()))?,
+    };
+    let hashes = if archive.hashes.is_empty() {
+        HashPolicy::None
+    } else {
+        HashPolicy::Any(archive.hashes.as_slice())
+    };
+
+    downloader
+        .download(
+            &RemoteArchive {
+                name: &archive.name,
+                distribution: archive.filename.clone(),
+                url,
+                filename: archive.filename.clone(),
+                size: archive.size,
+            },
+            directory,
+            hashes,
+        )
+        .await
+        .with_context(|| format!("Failed to retrieve `{}`", archive.filename))?;
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cli::LockDiffFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExcludeDependency,
@@ -30,7 +31,7 @@ use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::{ExtrasResolver, LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
+    FlatIndex, InMemoryIndex, Lock, LockDiff, Options, OptionsBuilder, Package, PythonRequirement,
     ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
@@ -44,6 +45,7 @@ use uv_workspace::{
 };
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::lock_diff;
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
@@ -86,6 +88,8 @@ pub(crate) async fn lock(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    diff: Option<String>,
+    diff_format: LockDiffFormat,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -101,6 +105,13 @@ pub(crate) async fn lock(
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
+    if diff.is_some() && !preview.is_enabled(PreviewFeature::LockDiff) {
+        warn_user!(
+            "The `--diff` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::LockDiff
+        );
+    }
+
     // If necessary, initialize the PEP 723 script.
     let script = match script {
         Some(ScriptPath::Path(path)) => {
@@ -139,6 +150,13 @@ pub(crate) async fn lock(
         LockTarget::Workspace(workspace.workspace())
     };
 
+    // Read the lockfile to compare against, if requested.
+    let base_lock = if let Some(base) = diff.as_deref() {
+        Some(lock_diff::read_base_lock(base, &target.lock_path()).await?)
+    } else {
+        None
+    };
+
     // Determine the lock mode.
     let interpreter;
     let mode = if let Some(frozen_source) = frozen {
@@ -265,6 +283,16 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Report the changes relative to the requested lockfile.
+            if let Some(base_lock) = base_lock.as_ref() {
+                let diff = LockDiff::new(base_lock, lock.lock());
+                write!(
+                    printer.stdout(),
+                    "{}",
+                    lock_diff::render(&diff, diff_format)?
+                )?;
+            }
+
             Ok(ExitStatus::Success)
         }
         // Lock mismatches from `--check`/`--locked` are expected validation failures.
@@ -294,10 +322,20 @@ pub(crate) enum LockMode<'env> {
     Frozen(MissingLockfileSource),
 }
 
+/// The lowered requirements attached to a [`LockTarget`] directly, as opposed to any of its
+/// members.
+#[derive(Debug, Default)]
+pub(crate) struct TargetRequirements {
+    pub(crate) requirements: Vec<Requirement>,
+    pub(crate) dependency_groups: BTreeMap<GroupName, Vec<Requirement>>,
+}
+
 /// A lock operation.
 pub(crate) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    requirements: Option<TargetRequirements>,
+    preferences: Option<LockedRequirements>,
     refresh: Option<&'env Refresh>,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
@@ -327,6 +365,8 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            requirements: None,
+            preferences: None,
             refresh: None,
             settings,
             client_builder,
@@ -350,6 +390,24 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the requirements attached to the target directly, along with its dependency groups.
+    ///
+    /// If set, the requirements are used in lieu of those declared by the target.
+    #[must_use]
+    pub(crate) fn with_requirements(mut self, requirements: TargetRequirements) -> Self {
+        self.requirements = Some(requirements);
+        self
+    }
+
+    /// Set the preferred versions for the [`LockOperation`].
+    ///
+    /// If set, the preferences are used in lieu of the existing lockfile, which is ignored.
+    #[must_use]
+    pub(crate) fn with_preferences(mut self, preferences: LockedRequirements) -> Self {
+        self.preferences = Some(preferences);
+        self
+    }
+
     /// Set the refresh strategy for the [`LockOperation`].
     #[must_use]
     pub(crate) fn with_refresh(mut self, refresh: &'env Refresh) -> Self {
@@ -394,6 +452,8 @@ impl<'env> LockOperation<'env> {
                     target,
                     interpreter,
                     Some(existing),
+                    self.requirements,
+                    self.preferences,
                     self.constraints,
                     self.refresh,
                     self.settings,
@@ -420,17 +480,21 @@ impl<'env> LockOperation<'env> {
                 Ok(result)
             }
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
-                // Read the existing lockfile.
-                let existing = match target.read().await {
-                    Ok(Some(existing)) => Some(existing),
-                    Ok(None) => None,
-                    Err(ProjectError::Lock(err)) => {
-                        warn_user!(
-                            "Failed to read existing lockfile; ignoring locked requirements: {err}"
-                        );
-                        None
+                // Read the existing lockfile, unless preferences were provided explicitly.
+                let existing = if self.preferences.is_some() {
+                    None
+                } else {
+                    match target.read().await {
+                        Ok(Some(existing)) => Some(existing),
+                        Ok(None) => None,
+                        Err(ProjectError::Lock(err)) => {
+                            warn_user!(
+                                "Failed to read existing lockfile; ignoring locked requirements: {err}"
+                            );
+                            None
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
                 };
 
                 // Perform the lock operation.
@@ -438,6 +502,8 @@ impl<'env> LockOperation<'env> {
                     target,
                     interpreter,
                     existing,
+                    self.requirements,
+                    self.preferences,
                     self.constraints,
                     self.refresh,
                     self.settings,
@@ -470,6 +536,8 @@ async fn do_lock(
     target: LockTarget<'_>,
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
+    declared: Option<TargetRequirements>,
+    preferences: Option<LockedRequirements>,
     external: Vec<NameRequirementSpecification>,
     refresh: Option<&Refresh>,
     settings: &ResolverSettings,
@@ -586,6 +654,15 @@ async fn do_lock(
         })
         .collect::<Result<BTreeMap<_, _>, ProjectError>>()?;
 
+    // If provided, use the given requirements in lieu of those declared by the target.
+    let (requirements, dependency_groups) = match declared {
+        Some(TargetRequirements {
+            requirements,
+            dependency_groups,
+        }) => (requirements, dependency_groups),
+        None => (requirements, dependency_groups),
+    };
+
     // Collect the conflicts.
     let mut conflicts = target.conflicts()?;
     if let LockTarget::Workspace(workspace) = target {
@@ -914,10 +991,13 @@ async fn do_lock(
             });
 
             // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
-                .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
-                .transpose()?
-                .unwrap_or_default();
+            let LockedRequirements { preferences, git } = match preferences {
+                Some(preferences) => preferences,
+                None => versions_lock
+                    .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
+                    .transpose()?
+                    .unwrap_or_default(),
+            };
 
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
@@ -0,0 +1,448 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::LockDiffFormat;
+use uv_fs::Simplified;
+use uv_git::GIT;
+use uv_resolver::{Lock, LockDiff, LockedVersion, PackageChange, PackageDiff};
+use uv_static::EnvVars;
+
+/// Read the lockfile to compare against.
+///
+/// If `base` refers to an existing file, it's read as a lockfile; if it refers to a directory, the
+/// lockfile with the same name as `lock_path` is read from within it. Otherwise, `base` is treated
+/// as a Git revision, and the lockfile is read from that revision.
+pub(crate) async fn read_base_lock(base: &str, lock_path: &Path) -> Result<Lock> {
+    let file_name = lock_path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid lockfile path: `{}`", lock_path.user_display()))?;
+
+    let path = Path::new(base);
+    let (contents, source) = if path.is_file() {
+        (
+            fs_err::tokio::read_to_string(path).await?,
+            format!("`{}`", path.user_display()),
+        )
+    } else if path.is_dir() {
+        let path = path.join(file_name);
+        (
+            fs_err::tokio::read_to_string(&path).await?,
+            format!("`{}`", path.user_display()),
+        )
+    } else {
+        let Ok(git) = GIT.as_ref() else {
+            bail!(
+                "`{base}` is not a file, and `git` was not found in PATH to resolve it as a revision"
+            )
+        };
+        let parent = lock_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let output = git
+            .build_command()
+            .arg("show")
+            .arg(format!("{base}:./{}", file_name.to_string_lossy()))
+            .env(EnvVars::LC_ALL, "C")
+            .current_dir(parent)
+            .output()
+            .context("Failed to run `git show`")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to read `{}` at Git revision `{base}`: {}",
+                file_name.to_string_lossy(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        (
+            String::from_utf8(output.stdout)?,
+            format!("Git revision `{base}`"),
+        )
+    };
+
+    toml::from_str::<Lock>(&contents)
+        .with_context(|| format!("Failed to parse lockfile at {source}"))
+}
+
+/// Render a [`LockDiff`] in the given format.
+pub(crate) fn render(diff: &LockDiff, format: LockDiffFormat) -> Result<String> {
+    match format {
+        LockDiffFormat::Text => Ok(render_text(diff)?),
+        LockDiffFormat::Markdown => Ok(render_markdown(diff)?),
+        LockDiffFormat::Json => {
+            let report = Report::from(diff);
+            Ok(format!("{}\n", serde_json::to_string_pretty(&report)?))
+        }
+    }
+}
+
+/// Render a [`LockDiff`] as human-readable text.
+fn render_text(diff: &LockDiff) -> Result<String, std::fmt::Error> {
+    let mut output = String::new();
+
+    if let Some((old, new)) = diff.requires_python() {
+        writeln!(
+            output,
+            "{} requires-python {old} -> {new}",
+            "Changed".yellow().bold()
+        )?;
+    }
+    for fork in diff.forks_added() {
+        writeln!(output, "{} fork {}", "Added".green().bold(), fork.cyan())?;
+    }
+    for fork in diff.forks_removed() {
+        writeln!(output, "{} fork {}", "Removed".red().bold(), fork.cyan())?;
+    }
+
+    for package in diff.packages() {
+        let name = package.name();
+        let old = versions(package.before());
+        let new = versions(package.after());
+        match package.change() {
+            PackageChange::Added => {
+                writeln!(output, "{} {name} {new}", "Added".green().bold())?;
+            }
+            PackageChange::Removed => {
+                writeln!(output, "{} {name} {old}", "Removed".red().bold())?;
+            }
+            PackageChange::Upgraded => {
+                writeln!(
+                    output,
+                    "{} {name} {old} -> {new}",
+                    "Upgraded".green().bold()
+                )?;
+            }
+            PackageChange::Downgraded => {
+                writeln!(
+                    output,
+                    "{} {name} {old} -> {new}",
+                    "Downgraded".yellow().bold()
+                )?;
+            }
+            PackageChange::Changed => {
+                writeln!(
+                    output,
+                    "{} {name} {old} -> {new}",
+                    "Changed".yellow().bold()
+                )?;
+            }
+            PackageChange::Source => {
+                writeln!(
+                    output,
+                    "{} source of {name} {new}: {} -> {}",
+                    "Changed".yellow().bold(),
+                    sources(package.before()),
+                    sources(package.after())
+                )?;
+            }
+            PackageChange::Hashes => {
+                writeln!(
+                    output,
+                    "{} hashes of {name} {new}",
+                    "Changed".yellow().bold()
+                )?;
+            }
+            PackageChange::Markers => {
+                writeln!(
+                    output,
+                    "{} resolution markers of {name} {new}",
+                    "Changed".yellow().bold()
+                )?;
+            }
+        }
+    }
+
+    if output.is_empty() {
+        writeln!(output, "{}", "No lockfile changes detected".bold())?;
+    }
+
+    Ok(output)
+}
+
+/// Render a [`LockDiff`] as a Markdown table, e.g., for use in a pull request comment.
+fn render_markdown(diff: &LockDiff) -> Result<String, std::fmt::Error> {
+    let mut output = String::new();
+
+    if diff.is_empty() {
+        writeln!(output, "No lockfile changes detected.")?;
+        return Ok(output);
+    }
+
+    if let Some((old, new)) = diff.requires_python() {
+        writeln!(output, "**requires-python**: `{old}` → `{new}`")?;
+        writeln!(output)?;
+    }
+
+    if !diff.forks_added().is_empty() || !diff.forks_removed().is_empty() {
+        writeln!(output, "**Forks**")?;
+        writeln!(output)?;
+        for fork in diff.forks_added() {
+            writeln!(output, "- Added `{fork}`")?;
+        }
+        for fork in diff.forks_removed() {
+            writeln!(output, "- Removed `{fork}`")?;
+        }
+        writeln!(output)?;
+    }
+
+    if !diff.packages().is_empty() {
+        writeln!(output, "| Package | Change | Old | New |")?;
+        writeln!(output, "|---------|--------|-----|-----|")?;
+        for package in diff.packages() {
+            let (old, new) = if package.change() == PackageChange::Source {
+                (sources(package.before()), sources(package.after()))
+            } else {
+                (versions(package.before()), versions(package.after()))
+            };
+            writeln!(
+                output,
+                "| `{}` | {} | {} | {} |",
+                package.name(),
+                package.change().as_str(),
+                markdown_cell(&old),
+                markdown_cell(&new),
+            )?;
+        }
+    }
+
+    Ok(output)
+}
+
+/// Wrap a non-empty table cell in backticks.
+fn markdown_cell(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!("`{value}`")
+    }
+}
+
+/// Format the versions of a set of locked entries, e.g., `v1.0.0, v2.0.0`.
+fn versions(entries: &[LockedVersion]) -> String {
+    let mut versions = entries
+        .iter()
+        .map(|entry| match entry.version() {
+            Some(version) => format!("v{version}"),
+            None => "(dynamic)".to_string(),
+        })
+        .collect::<Vec<_>>();
+    versions.dedup();
+    versions.join(", ")
+}
+
+/// Format the sources of a set of locked entries.
+fn sources(entries: &[LockedVersion]) -> String {
+    let mut sources = entries
+        .iter()
+        .map(LockedVersion::source)
+        .collect::<Vec<_>>();
+    sources.sort_unstable();
+    sources.dedup();
+    sources.join(", ")
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    schema: Schema,
+    requires_python: Option<RequiresPythonChange>,
+    forks: ForkChanges,
+    packages: Vec<PackageReport>,
+}
+
+impl From<&LockDiff<'_>> for Report {
+    fn from(diff: &LockDiff<'_>) -> Self {
+        Self {
+            schema: Schema::default(),
+            requires_python: diff
+                .requires_python()
+                .map(|(old, new)| RequiresPythonChange {
+                    old: old.to_string(),
+                    new: new.to_string(),
+                }),
+            forks: ForkChanges {
+                added: diff.forks_added().to_vec(),
+                removed: diff.forks_removed().to_vec(),
+            },
+            packages: diff.packages().iter().map(PackageReport::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+struct Schema {
+    version: SchemaVersion,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    #[default]
+    Preview,
+}
+
+#[derive(Debug, Serialize)]
+struct RequiresPythonChange {
+    old: String,
+    new: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ForkChanges {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageReport {
+    name: String,
+    change: &'static str,
+    old: Vec<Entry>,
+    new: Vec<Entry>,
+}
+
+impl From<&PackageDiff<'_>> for PackageReport {
+    fn from(package: &PackageDiff<'_>) -> Self {
+        Self {
+            name: package.name().to_string(),
+            change: package.change().as_str(),
+            old: package.before().iter().map(Entry::from).collect(),
+            new: package.after().iter().map(Entry::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Entry {
+    version: Option<String>,
+    source: String,
+}
+
+impl From<&LockedVersion<'_>> for Entry {
+    fn from(entry: &LockedVersion<'_>) -> Self {
+        Self {
+            version: entry.version().map(ToString::to_string),
+            source: entry.source().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_cli::LockDiffFormat;
+    use uv_resolver::{Lock, LockDiff};
+
+    use super::render;
+
+    #[test]
+    fn render_json() {
+        let old: Lock = toml::from_str(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "bar"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/bar-1.0.0-py3-none-any.whl" },
+]
+
+[[package]]
+name = "foo"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/foo-1.0.0-py3-none-any.whl" },
+]
+"#,
+        )
+        .unwrap();
+        let new: Lock = toml::from_str(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.13"
+
+[[package]]
+name = "baz"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/baz-1.0.0-py3-none-any.whl" },
+]
+
+[[package]]
+name = "foo"
+version = "2.0.0"
+source = { registry = "https://example.org/simple" }
+wheels = [
+    { url = "https://example.org/packages/foo-2.0.0-py3-none-any.whl" },
+]
+"#,
+        )
+        .unwrap();
+
+        let diff = LockDiff::new(&old, &new);
+        insta::assert_snapshot!(render(&diff, LockDiffFormat::Json).unwrap(), @r#"
+        {
+          "schema": {
+            "version": "preview"
+          },
+          "requires_python": {
+            "old": ">=3.12",
+            "new": ">=3.13"
+          },
+          "forks": {
+            "added": [],
+            "removed": []
+          },
+          "packages": [
+            {
+              "name": "bar",
+              "change": "removed",
+              "old": [
+                {
+                  "version": "1.0.0",
+                  "source": "registry+https://pypi.org/simple"
+                }
+              ],
+              "new": []
+            },
+            {
+              "name": "baz",
+              "change": "added",
+              "old": [],
+              "new": [
+                {
+                  "version": "1.0.0",
+                  "source": "registry+https://pypi.org/simple"
+                }
+              ]
+            },
+            {
+              "name": "foo",
+              "change": "upgraded",
+              "old": [
+                {
+                  "version": "1.0.0",
+                  "source": "registry+https://pypi.org/simple"
+                }
+              ],
+              "new": [
+                {
+                  "version": "2.0.0",
+                  "source": "registry+https://example.org/simple"
+                }
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, anyhow};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults};
+use uv_distribution::{ArchiveMetadata, Metadata};
+use uv_distribution_types::{Identifier, Requirement};
+use uv_fs::Simplified;
+use uv_normalize::{GroupName, PackageName};
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::LockedRequirements;
+use uv_resolver::{Lock, MetadataResponse, Package, Preference};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation, TargetRequirements};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, ProjectInterpreter, UniversalState, WorkspacePython};
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Merge two versions of the project lockfile, for use as a Git merge driver.
+///
+/// The merged lockfile is written to `current`.
+pub(crate) async fn lock_merge_driver(
+    project_dir: &Path,
+    base: &Path,
+    current: &Path,
+    other: &Path,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::LockMergeDriver) {
+        warn_user!(
+            "`uv lock merge-driver` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::LockMergeDriver
+        );
+    }
+
+    // Read each version of the lockfile. Git passes an empty file for a side on which the
+    // lockfile doesn't exist (e.g., for the base, if the lockfile was added on both branches).
+    let base_lock = read_lock(base).await?;
+    let current_lock = read_lock(current).await?;
+    let other_lock = read_lock(other).await?;
+
+    // Merge the requirements recorded in each lockfile. During a merge, the `pyproject.toml` files
+    // in the working tree may only reflect the current branch, so the merged requirements are used
+    // in lieu of those declared by the workspace.
+    let requirements = match merge_requirements(
+        &declared_requirements(base_lock.as_ref()),
+        &declared_requirements(current_lock.as_ref()),
+        &declared_requirements(other_lock.as_ref()),
+    ) {
+        Ok(requirements) => requirements,
+        Err(name) => {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "The requirement on `{name}` was changed on both sides of the merge; resolve the conflict in `pyproject.toml`, then run `uv lock`"
+                )
+                .bold()
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    };
+
+    // Find the workspace members.
+    let project = match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await
+    {
+        Ok(project) => project,
+        Err(err) => {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Failed to read the workspace; resolve any conflicts in `pyproject.toml`, then run `uv lock`: {err}"
+                )
+                .bold()
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    };
+    let workspace = project.workspace();
+    let target = LockTarget::Workspace(workspace);
+
+    // Find an interpreter for the project.
+    let groups = DependencyGroupsWithDefaults::none();
+    let workspace_python = WorkspacePython::from_request(
+        python.as_deref().map(PythonRequest::parse),
+        Some(workspace),
+        &groups,
+        project_dir,
+        no_config,
+    )
+    .await?;
+    let interpreter = ProjectInterpreter::discover(
+        workspace,
+        &groups,
+        workspace_python,
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        Some(false),
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Use the merged package versions as preferences for the resolution.
+    let packages = merge_packages(
+        base_lock.as_ref(),
+        current_lock.as_ref(),
+        other_lock.as_ref(),
+    );
+    let mut preferences = LockedRequirements::default();
+    for package in &packages {
+        if let Some(preference) = Preference::from_lock(package, workspace.install_path())? {
+            preferences.preferences.push(preference);
+        }
+        if let Some(git_ref) = package.as_git_ref()? {
+            preferences.git.push(git_ref);
+        }
+    }
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Seed the metadata for each workspace member with its merged requirements, such that the
+    // resolver doesn't read them from `pyproject.toml`.
+    for (name, member) in workspace.packages() {
+        let Some(version) = packages
+            .iter()
+            .filter(|package| package.name() == name)
+            .find_map(|package| package.version())
+        else {
+            debug!(
+                "Reading the requirements for dynamic workspace member `{name}` from `pyproject.toml`"
+            );
+            continue;
+        };
+
+        let mut requires_dist = Vec::new();
+        let mut dependency_groups: BTreeMap<GroupName, Vec<Requirement>> = BTreeMap::new();
+        for (.., group, requirement) in requirements
+            .iter()
+            .filter(|(member, ..)| *member == Some(name))
+        {
+            let requirement = (*requirement).clone().to_absolute(workspace.install_path());
+            match group {
+                Some(group) => dependency_groups
+                    .entry((*group).clone())
+                    .or_default()
+                    .push(requirement),
+                None => requires_dist.push(requirement),
+            }
+        }
+
+        let provides_extra = [current_lock.as_ref(), other_lock.as_ref()]
+            .into_iter()
+            .flatten()
+            .flat_map(Lock::packages)
+            .filter(|package| package.name() == name)
+            .flat_map(Package::provides_extras)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        let metadata = Metadata {
+            name: name.clone(),
+            version: version.clone(),
+            requires_dist: requires_dist.into_boxed_slice(),
+            requires_python: None,
+            provides_extra: provides_extra.into_iter().collect(),
+            dependency_groups: dependency_groups
+                .into_iter()
+                .map(|(group, requirements)| (group, requirements.into_boxed_slice()))
+                .collect(),
+            dynamic: false,
+        };
+        let distribution_id = DisplaySafeUrl::from_file_path(member.root())
+            .map_err(|()| anyhow!("Workspace member root is not a valid file URL"))?
+            .distribution_id();
+        state.index().distributions().done(
+            distribution_id,
+            Arc::new(MetadataResponse::Found(ArchiveMetadata::from(metadata))),
+        );
+    }
+
+    // Collect the merged requirements attached to the workspace itself.
+    let mut target_requirements = TargetRequirements::default();
+    for (.., group, requirement) in requirements.iter().filter(|(member, ..)| member.is_none()) {
+        let requirement = (*requirement).clone().to_absolute(workspace.install_path());
+        match group {
+            Some(group) => target_requirements
+                .dependency_groups
+                .entry((*group).clone())
+                .or_default()
+                .push(requirement),
+            None => target_requirements.requirements.push(requirement),
+        }
+    }
+
+    // Re-resolve the project, without touching the lockfile in the working tree.
+    let lock = match Box::pin(
+        LockOperation::new(
+            LockMode::DryRun(&interpreter),
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        )
+        .with_requirements(target_requirements)
+        .with_preferences(preferences)
+        .execute(target),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Compare against the lockfile as written, such that the requirements are normalized in the
+    // same way as those read from each side of the merge.
+    let contents = lock.to_toml()?;
+    let lock = toml::from_str::<Lock>(&contents)?;
+    if declared_requirements(Some(&lock)) != requirements {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "The resolved requirements don't match the merged requirements of the lockfiles; merge `pyproject.toml`, then run `uv lock`".bold()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    fs_err::tokio::write(current, contents).await?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read a lockfile, returning `None` if the file is empty.
+async fn read_lock(path: &Path) -> Result<Option<Lock>> {
+    let contents = fs_err::tokio::read_to_string(path).await?;
+    if contents.trim().is_empty() {
+        return Ok(None);
+    }
+    let lock = toml::from_str::<Lock>(&contents)
+        .with_context(|| format!("Failed to parse lockfile at `{}`", path.user_display()))?;
+    Ok(Some(lock))
+}
+
+/// A requirement, along with the workspace member and dependency group that declare it.
+type DeclaredRequirement<'lock> = (
+    Option<&'lock PackageName>,
+    Option<&'lock GroupName>,
+    &'lock Requirement,
+);
+
+/// Returns the requirements declared by the workspace in a lockfile.
+fn declared_requirements(lock: Option<&Lock>) -> BTreeSet<DeclaredRequirement<'_>> {
+    lock.into_iter()
+        .flat_map(Lock::declared_requirements)
+        .collect()
+}
+
+/// Perform a three-way merge of the requirements recorded in two lockfiles against their common
+/// ancestor.
+///
+/// A requirement is retained if it's present on both sides, or was added on either side; it's
+/// dropped if it was removed on either side. If both sides changed the requirements on the same
+/// package differently, returns the name of that package.
+fn merge_requirements<'lock>(
+    base: &BTreeSet<DeclaredRequirement<'lock>>,
+    current: &BTreeSet<DeclaredRequirement<'lock>>,
+    other: &BTreeSet<DeclaredRequirement<'lock>>,
+) -> Result<BTreeSet<DeclaredRequirement<'lock>>, PackageName> {
+    // Group the changes on each side by the member, group, and package they apply to.
+    let changes = |side: &BTreeSet<DeclaredRequirement<'lock>>| {
+        let mut changes: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for requirement @ (member, group, inner) in side.symmetric_difference(base) {
+            changes
+                .entry((*member, *group, &inner.name))
+                .or_default()
+                .insert(*requirement);
+        }
+        changes
+    };
+    let current_changes = changes(current);
+    let other_changes = changes(other);
+    for (key, changes) in &current_changes {
+        if other_changes
+            .get(key)
+            .is_some_and(|other_changes| other_changes != changes)
+        {
+            let (.., name) = key;
+            return Err((*name).clone());
+        }
+    }
+
+    Ok(current
+        .union(other)
+        .filter(|requirement| {
+            (current.contains(*requirement) && other.contains(*requirement))
+                || !base.contains(*requirement)
+        })
+        .copied()
+        .collect())
+}
+
+/// Perform a package-level three-way merge of two lockfiles against their common ancestor.
+///
+/// For each package name, the entries from a side are retained if only that side changed them
+/// relative to the base. If both sides changed a package, the entries from the side with the
+/// highest version are retained, preferring the current side, such that the resolver is given a
+/// single preference for each package.
+fn merge_packages<'lock>(
+    base: Option<&'lock Lock>,
+    current: Option<&'lock Lock>,
+    other: Option<&'lock Lock>,
+) -> Vec<&'lock Package> {
+    let mut by_name: BTreeMap<&PackageName, [Vec<&Package>; 3]> = BTreeMap::new();
+    for (index, lock) in [base, current, other].into_iter().enumerate() {
+        for package in lock.iter().flat_map(|lock| lock.packages()) {
+            by_name.entry(package.name()).or_default()[index].push(package);
+        }
+    }
+
+    let mut merged = Vec::new();
+    for (name, [base, current, other]) in by_name {
+        if current == other || other == base {
+            merged.extend(current);
+        } else if current == base {
+            merged.extend(other);
+        } else {
+            debug!("Package `{name}` was changed on both sides of the merge");
+            let highest = |packages: &[&Package]| {
+                packages
+                    .iter()
+                    .filter_map(|package| package.version())
+                    .max()
+                    .cloned()
+            };
+            if highest(&other) > highest(&current) {
+                merged.extend(other);
+            } else {
+                merged.extend(current);
+            }
+        }
+    }
+    merged
+}
@@ -76,7 +76,10 @@ pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod install_target;
 pub(crate) mod lock;
+pub(crate) mod lock_diff;
+pub(crate) mod lock_merge;
 pub(crate) mod lock_target;
+pub(crate) mod outdated;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
@@ -84,6 +87,8 @@ pub(crate) mod toolchain;
 pub(crate) mod tree;
 pub(crate) mod upgrade;
 pub(crate) mod version;
+pub(crate) mod watch;
+pub(crate) mod why;
 
 /// The source of a missing lockfile error.
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,579 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use futures::StreamExt;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::OutdatedFormat;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::{Concurrency, DependencyGroups};
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::IndexCapabilities;
+use uv_git::GitResolver;
+use uv_normalize::{DefaultGroups, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_scripts::Pep723Script;
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{
+    ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, WorkspacePython,
+    default_dependency_groups,
+};
+use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
+
+/// Display the latest versions of the packages in the lockfile.
+pub(crate) async fn outdated(
+    project_dir: &Path,
+    output_format: OutdatedFormat,
+    direct: bool,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    script: Option<Pep723Script>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::Outdated) {
+        warn_user!(
+            "`uv outdated` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Outdated
+        );
+    }
+    if matches!(output_format, OutdatedFormat::Json)
+        && !preview.is_enabled(PreviewFeature::JsonOutput)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    // Find the project requirements.
+    let workspace_cache = WorkspaceCache::default();
+    let workspace;
+    let target = if let Some(script) = script.as_ref() {
+        LockTarget::Script(script)
+    } else {
+        workspace = Workspace::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            &workspace_cache,
+        )
+        .await?;
+        LockTarget::Workspace(&workspace)
+    };
+
+    // Determine the groups to include when discovering an interpreter.
+    let default_groups = match target {
+        LockTarget::Workspace(workspace) => default_dependency_groups(workspace.pyproject_toml())?,
+        LockTarget::Script(_) => DefaultGroups::default(),
+    };
+    let groups = DependencyGroups::default().with_defaults(default_groups);
+
+    // Find an interpreter for the project, unless `--frozen` is set.
+    let interpreter = if frozen.is_some() {
+        None
+    } else {
+        Some(match target {
+            LockTarget::Script(script) => ScriptInterpreter::discover(
+                script.into(),
+                python.as_deref().map(PythonRequest::parse),
+                client_builder,
+                python_preference,
+                python_downloads,
+                &install_mirrors,
+                false,
+                no_config,
+                Some(false),
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter(),
+            LockTarget::Workspace(workspace) => {
+                let workspace_python = WorkspacePython::from_request(
+                    python.as_deref().map(PythonRequest::parse),
+                    Some(workspace),
+                    &groups,
+                    project_dir,
+                    no_config,
+                )
+                .await?;
+                ProjectInterpreter::discover(
+                    workspace,
+                    &groups,
+                    workspace_python,
+                    client_builder,
+                    python_preference,
+                    python_downloads,
+                    &install_mirrors,
+                    false,
+                    Some(false),
+                    cache,
+                    printer,
+                )
+                .await?
+                .into_interpreter()
+            }
+        })
+    };
+
+    // Determine the lock mode.
+    let mode = if let Some(frozen_source) = frozen {
+        LockMode::Frozen(frozen_source.into())
+    } else if let LockCheck::Enabled(lock_check) = lock_check {
+        LockMode::Locked(interpreter.as_ref().unwrap(), lock_check)
+    } else if matches!(target, LockTarget::Script(_)) && !target.lock_path().is_file() {
+        // If we're locking a script, avoid creating a lockfile if it doesn't already exist.
+        LockMode::DryRun(interpreter.as_ref().unwrap())
+    } else {
+        LockMode::Write(interpreter.as_ref().unwrap())
+    };
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(target),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Identify the direct dependencies of the workspace.
+    let direct_dependencies = lock
+        .direct_requirements()
+        .map(|requirement| &requirement.name)
+        .collect::<FxHashSet<_>>();
+
+    // Filter to packages that are derived from a registry.
+    let packages = lock
+        .packages()
+        .iter()
+        .filter(|package| !direct || direct_dependencies.contains(package.name()))
+        .filter_map(|package| {
+            let version = package.version()?;
+            let index = match package.index(target.install_path()) {
+                Ok(Some(index)) => index,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+            Some(Ok((package, version, index)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rows = Vec::new();
+    if !packages.is_empty() {
+        let ResolverSettings {
+            index_locations,
+            keyring_provider,
+            ..
+        } = &settings;
+
+        let capabilities = IndexCapabilities::default();
+        let download_concurrency = concurrency.downloads_semaphore.clone();
+
+        // The lockfile doesn't record the requirements of registry packages, so fetch the metadata
+        // of any registry package that depends on one of the listed packages, to determine the
+        // specifiers that it places on them.
+        let names = packages
+            .iter()
+            .map(|(package, ..)| package.name())
+            .collect::<FxHashSet<_>>();
+        let dependents = lock
+            .packages()
+            .iter()
+            .filter(|package| {
+                package
+                    .dependencies()
+                    .iter()
+                    .chain(package.optional_dependencies().values().flatten())
+                    .any(|dep| names.contains(dep.package_name()))
+            })
+            .filter_map(|package| {
+                let version = package.version()?;
+                match package.registry_wheel(target.install_path()) {
+                    Ok(Some(wheel)) => Some(Ok((package.name(), version, wheel))),
+                    Ok(None) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let requires_dist = if dependents.is_empty() {
+            FxHashMap::default()
+        } else {
+            let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+                .index_locations(index_locations.clone())
+                .keyring(*keyring_provider)
+                .build()?;
+            let git = GitResolver::default();
+            let (client, git, capabilities) = (&client, &git, &capabilities);
+            futures::stream::iter(dependents)
+                .map(async |(name, version, wheel)| {
+                    match client.wheel_metadata(&wheel, git, capabilities, None).await {
+                        Ok(metadata) => Some(((name, version), metadata.requires_dist)),
+                        Err(err) => {
+                            debug!("Failed to fetch metadata for `{name}=={version}`: {err}");
+                            None
+                        }
+                    }
+                })
+                .buffer_unordered(concurrency.downloads)
+                .filter_map(std::future::ready)
+                .collect::<FxHashMap<_, _>>()
+                .await
+        };
+
+        // Initialize the registry client, bypassing the cache to ensure that the latest versions
+        // are fetched.
+        let client = RegistryClientBuilder::new(
+            client_builder.clone(),
+            cache.clone().with_refresh(Refresh::All(Timestamp::now())),
+        )
+        .index_locations(index_locations.clone())
+        .keyring(*keyring_provider)
+        .build()?;
+
+        let exclude_newer = lock.exclude_newer();
+
+        // Initialize the client to fetch the latest version of each package.
+        let client = LatestClient {
+            client: &client,
+            capabilities: &capabilities,
+            prerelease: lock.prerelease_mode(),
+            exclude_newer: &exclude_newer,
+            index_locations,
+            requires_python: Some(lock.requires_python()),
+            tags: None,
+        };
+
+        let reporter = LatestVersionReporter::from(printer).with_length(packages.len() as u64);
+
+        // Fetch the latest versions of each package, along with the latest version that satisfies
+        // the specifiers of its dependents.
+        let download_concurrency = &download_concurrency;
+        let direct_dependencies = &direct_dependencies;
+        let lock = &lock;
+        let requires_dist = &requires_dist;
+        let client = &client;
+        let mut fetches = futures::stream::iter(packages)
+            .map(async |(package, version, index)| {
+                let specifiers = lock.dependent_specifiers(package, |dependent| {
+                    requires_dist
+                        .get(&(dependent.name(), dependent.version()?))
+                        .map(AsRef::as_ref)
+                });
+                let versions = client
+                    .find_latest_compatible(
+                        package.name(),
+                        Some(&index),
+                        specifiers.as_ref().unwrap_or(&VersionSpecifiers::empty()),
+                        download_concurrency,
+                    )
+                    .await?;
+                Ok::<_, Error>((package.name(), version, specifiers.is_some(), versions))
+            })
+            .buffer_unordered(concurrency.downloads);
+
+        while let Some((name, version, known, versions)) = fetches.next().await.transpose()? {
+            let latest = versions.latest.map(DistFilename::into_version);
+            match &latest {
+                Some(latest) => reporter.on_fetch_version(name, latest),
+                None => reporter.on_fetch_progress(),
+            }
+            rows.push(LockedPackage {
+                name,
+                version,
+                // If the requirements of any dependent are unknown, so is the compatible version.
+                compatible: versions
+                    .compatible
+                    .filter(|_| known)
+                    .map(DistFilename::into_version),
+                update: latest
+                    .as_ref()
+                    .filter(|latest| *latest > version)
+                    .map(|latest| UpdateKind::new(version, latest)),
+                latest,
+                direct: direct_dependencies.contains(name),
+            });
+        }
+        reporter.on_fetch_complete();
+    }
+
+    rows.sort_by(|a, b| a.name.cmp(b.name).then_with(|| a.version.cmp(b.version)));
+
+    match output_format {
+        OutdatedFormat::Text if rows.is_empty() => {
+            writeln!(
+                printer.stderr(),
+                "No packages from a registry found in the lockfile"
+            )?;
+        }
+        OutdatedFormat::Text => {
+            let columns = [
+                Column {
+                    header: String::from("Package"),
+                    rows: rows
+                        .iter()
+                        .map(|package| package.name.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Version"),
+                    rows: rows
+                        .iter()
+                        .map(|package| package.version.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Compatible"),
+                    rows: rows
+                        .iter()
+                        .map(|package| {
+                            package
+                                .compatible
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Latest"),
+                    rows: rows
+                        .iter()
+                        .map(|package| {
+                            package
+                                .latest
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Update"),
+                    rows: rows
+                        .iter()
+                        .map(|package| {
+                            package
+                                .update
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Dependency"),
+                    rows: rows
+                        .iter()
+                        .map(|package| {
+                            if package.direct {
+                                "direct".to_string()
+                            } else {
+                                "transitive".to_string()
+                            }
+                        })
+                        .collect_vec(),
+                },
+            ];
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout_important(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+        OutdatedFormat::Json => {
+            let report = Report::new(&rows);
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A locked package, along with the latest versions available.
+#[derive(Debug)]
+struct LockedPackage<'lock> {
+    name: &'lock PackageName,
+    version: &'lock Version,
+    /// The latest version that satisfies the requirements of the package's dependents, if known.
+    compatible: Option<Version>,
+    /// The latest version overall, if any.
+    latest: Option<Version>,
+    /// The kind of update required to reach the latest version, if the package is outdated.
+    update: Option<UpdateKind>,
+    direct: bool,
+}
+
+/// The kind of update required to move from one version to another.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum UpdateKind {
+    Major,
+    Minor,
+    Patch,
+    Prerelease,
+}
+
+impl UpdateKind {
+    /// Classify the update from `current` to `target`.
+    ///
+    /// Any update to a pre-release or development version is classified as a pre-release;
+    /// otherwise, the update is classified by the first release segment that differs, with a
+    /// change in epoch treated as a major update.
+    pub(crate) fn new(current: &Version, target: &Version) -> Self {
+        if target.any_prerelease() {
+            return Self::Prerelease;
+        }
+        let segment = |version: &Version, index: usize| -> u64 {
+            version.release().get(index).copied().unwrap_or(0)
+        };
+        if current.epoch() != target.epoch() || segment(current, 0) != segment(target, 0) {
+            Self::Major
+        } else if segment(current, 1) != segment(target, 1) {
+            Self::Minor
+        } else {
+            Self::Patch
+        }
+    }
+}
+
+impl std::fmt::Display for UpdateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Major => write!(f, "major"),
+            Self::Minor => write!(f, "minor"),
+            Self::Patch => write!(f, "patch"),
+            Self::Prerelease => write!(f, "prerelease"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    schema: Schema,
+    packages: Vec<ReportPackage>,
+}
+
+impl Report {
+    fn new(packages: &[LockedPackage]) -> Self {
+        Self {
+            schema: Schema::default(),
+            packages: packages
+                .iter()
+                .map(|package| ReportPackage {
+                    name: package.name.to_string(),
+                    version: package.version.to_string(),
+                    compatible: package.compatible.as_ref().map(ToString::to_string),
+                    latest: package.latest.as_ref().map(ToString::to_string),
+                    update: package.update,
+                    direct: package.direct,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+struct Schema {
+    version: SchemaVersion,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    #[default]
+    Preview,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportPackage {
+    name: String,
+    version: String,
+    compatible: Option<String>,
+    latest: Option<String>,
+    update: Option<UpdateKind>,
+    direct: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::UpdateKind;
+
+    #[test]
+    fn update_kind() {
+        let kind = |current: &str, target: &str| {
+            UpdateKind::new(
+                &Version::from_str(current).unwrap(),
+                &Version::from_str(target).unwrap(),
+            )
+        };
+        assert_eq!(kind("1.2.3", "1.2.4"), UpdateKind::Patch);
+        assert_eq!(kind("1.2.3", "1.2.3.post1"), UpdateKind::Patch);
+        assert_eq!(kind("1.2", "1.2.1"), UpdateKind::Patch);
+        assert_eq!(kind("1.2.3", "1.3.0"), UpdateKind::Minor);
+        assert_eq!(kind("1", "1.1"), UpdateKind::Minor);
+        assert_eq!(kind("1.2.3", "2.0.0"), UpdateKind::Major);
+        assert_eq!(kind("2024.1", "1!1.0"), UpdateKind::Major);
+        assert_eq!(kind("1.2.3", "2.0.0rc1"), UpdateKind::Prerelease);
+        assert_eq!(kind("1.2.3", "1.2.4.dev0"), UpdateKind::Prerelease);
+    }
+}
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::ffi::OsString;
 use std::fmt::Write;
@@ -8,9 +9,11 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
+use futures::stream::FuturesUnordered;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use thiserror::Error;
+use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
 use tracing::{debug, trace, warn};
 use url::Url;
@@ -27,8 +30,8 @@ use uv_distribution_types::Requirement;
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_preview::Preview;
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName, PackageName};
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -44,7 +47,8 @@ use uv_settings::{
 use uv_shell::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_types::SourceTreeEditablePolicy;
-use uv_warnings::warn_user;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::TaskError;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
 use crate::child::run_to_completion;
@@ -67,6 +71,7 @@ use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironm
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::watch::{Change, ProcessGroups, Watcher};
 use crate::commands::project::{
     EnvironmentSpecification, LinkErrorReporting, PreferenceLocation, ProjectEnvironment,
     ProjectError, ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -95,6 +100,8 @@ pub(crate) async fn run(
     no_sync: bool,
     isolated: bool,
     all_packages: bool,
+    fail_fast: bool,
+    watch: Option<&Watcher>,
     package: Option<PackageName>,
     no_project: bool,
     no_config: bool,
@@ -152,6 +159,25 @@ pub(crate) async fn run(
         }
     }
 
+    if watch.is_some() {
+        if !preview.is_enabled(PreviewFeature::RunWatch) {
+            warn_user_once!(
+                "`uv run --watch` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::RunWatch
+            );
+        }
+        if matches!(
+            command,
+            Some(
+                RunCommand::PythonStdin(..)
+                    | RunCommand::PythonGuiStdin(..)
+                    | RunCommand::PythonRemote(..)
+            )
+        ) {
+            bail!("`--watch` cannot be used with scripts provided via stdin or a remote URL");
+        }
+    }
+
     // Fail early if stdin is used for multiple purposes.
     if matches!(
         command,
@@ -182,6 +208,11 @@ pub(crate) async fn run(
                     "Reading inline script metadata from `{}`",
                     script.path.user_display()
                 );
+
+                // Sync the environment when the script's metadata changes.
+                if let Some(watcher) = watch {
+                    watcher.set_project_files(vec![script.path.clone()]);
+                }
             }
             Pep723Item::Stdin(..) => {
                 if requirements_from_stdin {
@@ -496,6 +527,19 @@ pub(crate) async fn run(
         None
     };
 
+    // The commands to run for a task, if any.
+    let mut task_steps = Vec::new();
+
+    // The workspace members in which to run the command, if any.
+    let mut members = None;
+
+    if fail_fast && !preview.is_enabled(PreviewFeature::RunAllPackages) {
+        warn_user!(
+            "`--fail-fast` has no effect unless the `{}` preview feature is enabled",
+            PreviewFeature::RunAllPackages
+        );
+    }
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -619,6 +663,15 @@ pub(crate) async fn run(
             }
         }
 
+        if let Some(RunCommand::Task(name, _)) = &command {
+            if no_project {
+                bail!("Task `{name}` cannot be run with `--no-project`");
+            }
+            if project.is_none() {
+                bail!("Task `{name}` cannot be run outside of a project");
+            }
+        }
+
         if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -634,6 +687,46 @@ pub(crate) async fn run(
             // Determine the groups and extras to include.
             let default_groups = default_dependency_groups(project.pyproject_toml())?;
             let default_extras = DefaultExtras::default();
+
+            // Sync the environment when any of the files that define the workspace change.
+            if let Some(watcher) = watch {
+                let workspace = project.workspace();
+                watcher.set_project_files(
+                    std::iter::once(workspace.install_path().join("pyproject.toml"))
+                        .chain(std::iter::once(workspace.install_path().join("uv.lock")))
+                        .chain(
+                            workspace
+                                .packages()
+                                .values()
+                                .map(|member| member.root().join("pyproject.toml")),
+                        )
+                        .unique()
+                        .collect(),
+                );
+            }
+
+            // In preview, `--all-packages` runs the command in each workspace member.
+            if all_packages && preview.is_enabled(PreviewFeature::RunAllPackages) {
+                if matches!(command, Some(RunCommand::Task(..))) {
+                    bail!("`--task` cannot be used with `--all-packages`");
+                }
+                members = Some(
+                    project
+                        .workspace()
+                        .packages()
+                        .iter()
+                        .map(|(name, member)| (name.clone(), member.root().clone()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+
+            let groups = if let Some(RunCommand::Task(name, args)) = &command {
+                let (steps, task_groups) = resolve_task(&project, name, args, preview)?;
+                task_steps = steps;
+                groups.with_groups(task_groups)
+            } else {
+                groups
+            };
             let groups = groups.with_defaults(default_groups);
             let extras = extras.with_defaults(default_extras);
 
@@ -1263,10 +1356,6 @@ pub(crate) async fn run(
         return Ok(ExitStatus::Error);
     };
 
-    debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
-    process.envs(env_file_environment);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -1291,27 +1380,392 @@ pub(crate) async fn run(
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env(EnvVars::PATH, new_path);
 
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
+    // Configure a process to run a command in the environment.
+    let configure = |command: &RunCommand, env: BTreeMap<String, String>| {
+        let mut process = command.as_command(interpreter);
+        process.envs(env_file_environment.iter().map(|(key, value)| (key, value)));
+        process.envs(env);
+        process.env(EnvVars::PATH, &new_path);
+
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
+
+        // Ensure `VIRTUAL_ENV` is set.
+        if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+
+        // In watch mode, the command is terminated when a change is detected. On Unix, it's
+        // spawned in its own process group, such that any processes it spawns are terminated
+        // along with it.
+        if watch.is_some() {
+            process.kill_on_drop(true);
+            #[cfg(unix)]
+            process.process_group(0);
+        }
+
+        process
+    };
+
+    // A task runs as a sequence of commands, one for each of the tasks it depends on.
+    let steps = if matches!(command, RunCommand::Task(..)) {
+        task_steps
+    } else {
+        vec![TaskStep {
+            command,
+            env: BTreeMap::new(),
+        }]
+    };
 
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+    let groups = watch.map(|_| ProcessGroups::default());
+    let (steps, members, configure, groups) = (&steps, &members, &configure, groups.as_ref());
+    let execute = || async move {
+        if let Some(members) = members {
+            // Tasks can't be combined with `--all-packages`, so there's a single command to run.
+            let command = &steps[0].command;
+            let processes = members
+                .iter()
+                .map(|(name, root)| {
+                    let mut process = configure(command, BTreeMap::new());
+                    process.current_dir(root);
+                    (name.clone(), process)
+                })
+                .collect();
+            return run_in_members(processes, concurrency.runs, fail_fast, groups, printer).await;
+        }
+
+        let mut status = ExitStatus::Success;
+        for TaskStep { command, env } in steps {
+            debug!("Running `{command}`");
+            let mut process = configure(command, env.clone());
+
+            // Spawn and wait for completion
+            // Standard input, output, and error streams are all inherited
+            // TODO(zanieb): Throw a nicer error message if the command is not found
+            let handle = process
+                .spawn()
+                .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
+            if let Some(groups) = groups {
+                groups.register(&handle);
+            }
+
+            status = run_to_completion(handle).await?;
+
+            // Stop at the first command that fails.
+            if !matches!(status, ExitStatus::Success | ExitStatus::External(0)) {
+                break;
+            }
+        }
+
+        Ok(status)
+    };
+
+    let (Some(watcher), Some(groups)) = (watch, groups) else {
+        return execute().await;
+    };
+
+    // Restart the command whenever a watched file changes. If a project file changes, return,
+    // such that the environment is synced before the command is restarted.
+    let mut snapshot = watcher.snapshot_since_sync();
+    loop {
+        let mut running = std::pin::pin!(execute());
+        let change = tokio::select! {
+            status = &mut running => {
+                status?;
+                groups.clear();
+                writeln!(printer.stderr(), "{}", "Waiting for changes...".dimmed())?;
+                None
+            }
+            change = watcher.changed(&mut snapshot) => Some(change),
+        };
+        let change = match change {
+            Some(change) => {
+                groups.terminate(running).await;
+                change
+            }
+            None => watcher.changed(&mut snapshot).await,
+        };
+        match change {
+            Change::Source(path) => {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "Detected change in `{}`, restarting...",
+                        path.user_display()
+                    )
+                    .dimmed()
+                )?;
+            }
+            Change::Project(path) => {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!("Detected change in `{}`, syncing...", path.user_display()).dimmed()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+        }
+    }
+}
+
+/// The outcome of running a command in a workspace member.
+#[derive(Debug)]
+enum MemberOutcome {
+    /// The command exited with the given status.
+    Exited(std::process::ExitStatus),
+    /// The command could not be spawned.
+    Failed(anyhow::Error),
+    /// The command was terminated after another member failed.
+    Terminated,
+    /// The command was not started after another member failed.
+    Skipped,
+}
+
+impl MemberOutcome {
+    fn is_success(&self) -> bool {
+        matches!(self, Self::Exited(status) if status.success())
     }
+}
+
+impl std::fmt::Display for MemberOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exited(status) => match status.code() {
+                Some(code) if status.success() => {
+                    write!(f, "{}", format!("exit code {code}").green())
+                }
+                Some(code) => write!(f, "{}", format!("exit code {code}").red()),
+                None => write!(f, "{}", "terminated by signal".red()),
+            },
+            Self::Failed(err) => write!(f, "{}", format!("failed to spawn: {err}").red()),
+            Self::Terminated => write!(f, "{}", "terminated".yellow()),
+            Self::Skipped => write!(f, "{}", "skipped".dimmed()),
+        }
+    }
+}
 
-    // Spawn and wait for completion
-    // Standard input, output, and error streams are all inherited
-    // TODO(zanieb): Throw a nicer error message if the command is not found
-    let handle = process
+/// Run a command in each workspace member, with a bounded number of members running at once.
+///
+/// The output of each command is prefixed with the name of the member, and a summary of the
+/// outcome in each member is displayed once all commands have completed.
+async fn run_in_members(
+    processes: Vec<(PackageName, Command)>,
+    limit: usize,
+    fail_fast: bool,
+    groups: Option<&ProcessGroups>,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let names = processes
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let width = names
+        .iter()
+        .map(|name| name.as_str().len())
+        .max()
+        .unwrap_or_default();
+
+    let mut outcomes = BTreeMap::new();
+    let mut pending = processes.into_iter();
+    let mut running = FuturesUnordered::new();
+    let mut failed = false;
+    loop {
+        while running.len() < limit && !(fail_fast && failed) {
+            let Some((name, process)) = pending.next() else {
+                break;
+            };
+            debug!("Running command in `{name}`");
+            running.push(async move {
+                let outcome = match run_in_member(&name, process, width, groups).await {
+                    Ok(status) => MemberOutcome::Exited(status),
+                    Err(err) => MemberOutcome::Failed(err),
+                };
+                (name, outcome)
+            });
+        }
+
+        let Some((name, outcome)) = running.next().await else {
+            break;
+        };
+        failed |= !outcome.is_success();
+        outcomes.insert(name, outcome);
+
+        if fail_fast && failed {
+            break;
+        }
+    }
+
+    // Dropping any commands that are still running terminates them.
+    drop(running);
+
+    writeln!(printer.stderr())?;
+    writeln!(printer.stderr(), "{}", "Summary:".bold())?;
+    for name in names {
+        let outcome = outcomes.remove(&name).unwrap_or_else(|| {
+            if pending
+                .as_slice()
+                .iter()
+                .any(|(pending, _)| *pending == name)
+            {
+                MemberOutcome::Skipped
+            } else {
+                MemberOutcome::Terminated
+            }
+        });
+        writeln!(
+            printer.stderr(),
+            "  {}  {outcome}",
+            format!("{:width$}", name.as_str()).bold()
+        )?;
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Run a command in a workspace member, prefixing each line of its output with the member name.
+async fn run_in_member(
+    name: &PackageName,
+    mut process: Command,
+    width: usize,
+    groups: Option<&ProcessGroups>,
+) -> anyhow::Result<std::process::ExitStatus> {
+    process
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+    let mut child = process
         .spawn()
-        .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
+        .with_context(|| format!("Failed to spawn command in `{name}`"))?;
+    if let Some(groups) = groups {
+        groups.register(&child);
+    }
+
+    let prefix = format!("{} | ", format!("{:width$}", name.as_str()).cyan());
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stderr = child.stderr.take().expect("stderr is piped");
+    let (status, (), ()) = tokio::try_join!(
+        child.wait(),
+        forward_lines(stdout, &prefix, anstream::stdout),
+        forward_lines(stderr, &prefix, anstream::stderr),
+    )?;
+    Ok(status)
+}
 
-    run_to_completion(handle).await
+/// Copy each line from the reader to the writer, with the given prefix.
+async fn forward_lines<W: io::Write>(
+    reader: impl tokio::io::AsyncRead + Unpin,
+    prefix: &str,
+    writer: fn() -> W,
+) -> io::Result<()> {
+    let mut reader = tokio::io::BufReader::new(reader);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line).await? == 0 {
+            return Ok(());
+        }
+        if !line.ends_with(b"\n") {
+            line.push(b'\n');
+        }
+
+        // Write the prefix and line at once, to avoid interleaving with other members.
+        let mut buffer = Vec::with_capacity(prefix.len() + line.len());
+        buffer.extend_from_slice(prefix.as_bytes());
+        buffer.extend_from_slice(&line);
+        writer().write_all(&buffer)?;
+    }
+}
+
+/// A command to run as part of a task in `tool.uv.tasks`.
+#[derive(Debug)]
+struct TaskStep {
+    command: RunCommand,
+    /// The environment variables to set for the command.
+    env: BTreeMap<String, String>,
+}
+
+/// Resolve a task in `tool.uv.tasks` into the commands to run (i.e., those of the task and the
+/// tasks it depends on), along with the dependency groups required by each.
+///
+/// Tasks are read from the current project, falling back to the workspace root.
+fn resolve_task(
+    project: &VirtualProject,
+    name: &str,
+    args: &[OsString],
+    preview: Preview,
+) -> anyhow::Result<(Vec<TaskStep>, Vec<GroupName>)> {
+    if !preview.is_enabled(PreviewFeature::Tasks) {
+        warn_user!(
+            "`uv run --task` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Tasks
+        );
+    }
+
+    let Some(tasks) = [
+        project.pyproject_toml(),
+        project.workspace().pyproject_toml(),
+    ]
+    .into_iter()
+    .filter_map(|pyproject_toml| {
+        pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.tasks.as_ref())
+    })
+    .find(|tasks| tasks.inner().contains_key(name)) else {
+        return Err(TaskError::NotFound(name.to_string()).into());
+    };
+
+    let mut steps = Vec::new();
+    let mut groups = Vec::new();
+    for (task_name, task) in tasks.resolve(name)? {
+        groups.extend(task.groups.iter().cloned());
+
+        let Some(cmd) = task.cmd.as_deref() else {
+            if task_name == name && !args.is_empty() {
+                bail!("Task `{name}` does not define a command, so it cannot accept arguments");
+            }
+            continue;
+        };
+
+        // Split the command as a shell would, but run it directly rather than through a shell.
+        let Some(words) = shlex::split(cmd) else {
+            bail!("Failed to parse the command for task `{task_name}`: `{cmd}`");
+        };
+        let mut words = words.into_iter().map(OsString::from);
+        let Some(program) = words.next() else {
+            bail!("Task `{task_name}` has an empty command");
+        };
+        let mut task_args = words.collect::<Vec<_>>();
+
+        // Additional arguments are only forwarded to the requested task.
+        if task_name == name {
+            task_args.extend(args.iter().cloned());
+        }
+
+        let command = if program == "python" {
+            RunCommand::Python(task_args)
+        } else {
+            RunCommand::External(program, task_args)
+        };
+        steps.push(TaskStep {
+            command,
+            env: task.env.clone(),
+        });
+    }
+
+    Ok((steps, groups))
 }
 
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
@@ -1417,6 +1871,8 @@ pub(crate) enum RunCommand {
     PythonRemote(tempfile::NamedTempFile, Vec<OsString>),
     /// Execute an external command.
     External(OsString, Vec<OsString>),
+    /// Execute a task defined in `tool.uv.tasks`, along with the tasks it depends on.
+    Task(String, Vec<OsString>),
     /// Execute an empty command (in practice, `python` with no arguments).
     Empty,
 }
@@ -1503,17 +1959,32 @@ impl ParsedRunCommand {
     }
 
     /// Determine the [`ParsedRunCommand`] for a given set of arguments.
+    #[expect(clippy::fn_params_excessive_bools)]
     pub(crate) fn from_args(
         command: &ExternalCommand,
         module: bool,
         script: bool,
         gui_script: bool,
+        task: bool,
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
         let Some(target) = target else {
             return Ok(Self::Ready(RunCommand::Empty));
         };
 
+        if task {
+            let Some(name) = target.to_str() else {
+                bail!(
+                    "Task name must be valid UTF-8: `{}`",
+                    target.to_string_lossy()
+                );
+            };
+            return Ok(Self::Ready(RunCommand::Task(
+                name.to_string(),
+                args.to_vec(),
+            )));
+        }
+
         if target.eq_ignore_ascii_case("-") {
             let mut buf = Vec::with_capacity(1024);
             std::io::stdin().read_to_end(&mut buf)?;
@@ -1653,8 +2124,31 @@ impl ParsedRunCommand {
 }
 
 impl RunCommand {
+    /// Clone the command, unless it's a script that was read from `stdin` or downloaded from a
+    /// remote URL.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(match self {
+            Self::Python(args) => Self::Python(args.clone()),
+            Self::PythonScript(script, args) => Self::PythonScript(script.clone(), args.clone()),
+            Self::PythonModule(module, args) => Self::PythonModule(module.clone(), args.clone()),
+            Self::PythonGuiScript(script, args) => {
+                Self::PythonGuiScript(script.clone(), args.clone())
+            }
+            Self::PythonPackage(target, path, args) => {
+                Self::PythonPackage(target.clone(), path.clone(), args.clone())
+            }
+            Self::PythonZipapp(path, args) => Self::PythonZipapp(path.clone(), args.clone()),
+            Self::PythonStdin(..) | Self::PythonGuiStdin(..) | Self::PythonRemote(..) => {
+                return None;
+            }
+            Self::External(executable, args) => Self::External(executable.clone(), args.clone()),
+            Self::Task(name, args) => Self::Task(name.clone(), args.clone()),
+            Self::Empty => Self::Empty,
+        })
+    }
+
     /// Read any inline PEP 723 metadata associated with this command target.
-    async fn read_pep723_item(&self) -> Result<Option<Pep723Item>, Pep723Error> {
+    pub(crate) async fn read_pep723_item(&self) -> Result<Option<Pep723Item>, Pep723Error> {
         match self {
             Self::PythonScript(script, _) | Self::PythonGuiScript(script, _) => {
                 match Pep723Script::read(script).await {
@@ -1675,6 +2169,7 @@ impl RunCommand {
             | Self::PythonModule(..)
             | Self::PythonRemote(..)
             | Self::External(..)
+            | Self::Task(..)
             | Self::Empty => Ok(None),
         }
     }
@@ -1707,6 +2202,7 @@ impl RunCommand {
                 }
             }
             Self::External(executable, _) => executable.to_string_lossy(),
+            Self::Task(name, _) => Cow::Borrowed(name),
         }
     }
 
@@ -1833,6 +2329,7 @@ impl RunCommand {
                 process.args(args);
                 process
             }
+            Self::Task(..) => unreachable!("Tasks are resolved into commands before execution"),
             Self::Empty => Command::new(interpreter.sys_executable()),
         }
     }
@@ -1850,6 +2347,7 @@ impl RunCommand {
             | Self::PythonGuiStdin(..)
             | Self::PythonRemote(..)
             | Self::External(..)
+            | Self::Task(..)
             | Self::Empty => None,
         };
         // The parent is `Some("")` for bare filenames.
@@ -1911,6 +2409,13 @@ impl std::fmt::Display for RunCommand {
                 }
                 Ok(())
             }
+            Self::Task(name, args) => {
+                write!(f, "{name}")?;
+                for arg in args {
+                    write!(f, " {}", arg.to_string_lossy())?;
+                }
+                Ok(())
+            }
             Self::Empty => {
                 write!(f, "python")?;
                 Ok(())
@@ -5,17 +5,21 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use anyhow::{Context, Result, anyhow, bail};
+use console::Term;
+use futures::StreamExt;
 use uv_cache::{Cache, Refresh};
-use uv_client::BaseClientBuilder;
-use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, DryRun};
+use uv_cache_info::Timestamp;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, DryRun, Upgrade};
 use uv_distribution::{ArchiveMetadata, Metadata};
-use uv_distribution_types::Identifier;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{Identifier, IndexCapabilities, IndexUrl};
 use uv_normalize::PackageName;
 use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerTree, Requirement, VerbatimUrl, VersionOrUrl};
 use uv_preview::Preview;
 use uv_pypi_types::{PyProjectToml, ResolutionMetadata, VerbatimParsedUrl};
-use uv_python::{PythonDownloads, PythonPreference};
+use uv_python::{Interpreter, PythonDownloads, PythonPreference};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::MetadataResponse;
 use uv_settings::PythonInstallMirrors;
@@ -25,10 +29,13 @@ use uv_workspace::{
     DiscoveryOptions, ProjectWorkspace, VirtualProject, WorkspaceCache, WorkspaceErrorKind,
 };
 
+use crate::commands::pip::latest::LatestClient;
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::project::lock::{LockEvent, LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::outdated::UpdateKind;
 use crate::commands::project::{ProjectError, ProjectInterpreter, UniversalState, WorkspacePython};
+use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 use crate::settings::ResolverSettings;
@@ -48,28 +55,7 @@ pub(crate) async fn upgrade(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
-    let project = match VirtualProject::discover(
-        project_dir,
-        &DiscoveryOptions::default(),
-        cache,
-        workspace_cache,
-    )
-    .await
-    {
-        Ok(VirtualProject::Project(project)) => project,
-        Ok(VirtualProject::NonProject(_)) => {
-            bail!("`uv upgrade` requires a project with a `[project]` table")
-        }
-        Err(err)
-            if matches!(
-                err.as_ref(),
-                WorkspaceErrorKind::MissingPyprojectToml | WorkspaceErrorKind::MissingProject(_)
-            ) =>
-        {
-            bail!("`uv upgrade` requires a project with a `[project]` table");
-        }
-        Err(err) => return Err(err.into()),
-    };
+    let project = discover_project(project_dir, cache, workspace_cache).await?;
     let (requirement_text, requirement) = select_requirement(&project, &package)?;
 
     let relaxed_requirement = into_verbatim_requirement(relax_requirement(&requirement), &package)?;
@@ -111,30 +97,18 @@ pub(crate) async fn upgrade(
     )
     .await?;
 
-    let groups = DependencyGroupsWithDefaults::none();
-    let workspace_python = WorkspacePython::from_request(
-        None,
-        Some(project.workspace()),
-        &groups,
+    let interpreter = discover_interpreter(
+        &project,
         project_dir,
-        no_config,
-    )
-    .await?;
-    let interpreter = ProjectInterpreter::discover(
-        project.workspace(),
-        &groups,
-        workspace_python,
         &client_builder,
         python_preference,
         python_downloads,
         &install_mirrors,
-        false,
-        Some(false),
+        no_config,
         cache,
         printer,
     )
-    .await?
-    .into_interpreter();
+    .await?;
 
     let state = UniversalState::default();
     let distribution_id = DisplaySafeUrl::from_file_path(project.project_root())
@@ -232,15 +206,385 @@ pub(crate) async fn upgrade(
     Ok(ExitStatus::Success)
 }
 
-/// Select the single production dependency declaration targeted by `uv upgrade`.
-fn select_requirement(
-    project: &ProjectWorkspace,
-    package: &PackageName,
-) -> Result<(String, Requirement<VerbatimParsedUrl>)> {
+/// Interactively select outdated dependencies to upgrade, then upgrade them with a single lock.
+pub(crate) async fn upgrade_interactive(
+    project_dir: &Path,
+    install_mirrors: PythonInstallMirrors,
+    mut settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        bail!("`uv upgrade --interactive` requires an interactive terminal");
+    }
+
+    let project = discover_project(project_dir, cache, workspace_cache).await?;
+
+    // Collect the dependency declarations that can be upgraded, skipping any that are declared
+    // multiple times.
+    let requirements = production_requirements(&project)?;
+    let requirements = requirements
+        .iter()
+        .filter(|(_, requirement)| {
+            requirements
+                .iter()
+                .filter(|(_, other)| other.name == requirement.name)
+                .count()
+                == 1
+        })
+        .filter(|(_, requirement)| validate_requirement(&project, requirement).is_ok())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let interpreter = discover_interpreter(
+        &project,
+        project_dir,
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        no_config,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Lock the project to determine the current version of each dependency.
+    let state = UniversalState::default();
+    let lock = match Box::pin(
+        LockOperation::new(
+            LockMode::Write(&interpreter),
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(LockTarget::Workspace(project.workspace())),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Identify the locked version of each dependency, along with the index it was resolved from.
+    let mut locked = Vec::new();
+    for (requirement_text, requirement) in requirements {
+        let mut current: Option<(&Version, IndexUrl)> = None;
+        for package in lock.packages() {
+            if package.name() != &requirement.name
+                || !package.is_included_by_marker(requirement.marker)
+            {
+                continue;
+            }
+            let (Some(version), Some(index)) = (
+                package.version(),
+                package.index(project.workspace().install_path())?,
+            ) else {
+                continue;
+            };
+            if current
+                .as_ref()
+                .is_none_or(|(current, _)| version > *current)
+            {
+                current = Some((version, index));
+            }
+        }
+        if let Some((version, index)) = current {
+            locked.push((requirement_text, requirement, version, index));
+        }
+    }
+
+    // Fetch the latest version of each dependency.
+    let mut outdated = Vec::new();
+    if !locked.is_empty() {
+        let capabilities = IndexCapabilities::default();
+        let client = RegistryClientBuilder::new(
+            client_builder.clone(),
+            cache.clone().with_refresh(Refresh::All(Timestamp::now())),
+        )
+        .index_locations(settings.index_locations.clone())
+        .keyring(settings.keyring_provider)
+        .build()?;
+        let download_concurrency = concurrency.downloads_semaphore.clone();
+        let exclude_newer = lock.exclude_newer();
+        let client = LatestClient {
+            client: &client,
+            capabilities: &capabilities,
+            prerelease: lock.prerelease_mode(),
+            exclude_newer: &exclude_newer,
+            index_locations: &settings.index_locations,
+            requires_python: Some(lock.requires_python()),
+            tags: None,
+        };
+
+        let reporter = LatestVersionReporter::from(printer).with_length(locked.len() as u64);
+
+        let download_concurrency = &download_concurrency;
+        let client = &client;
+        let mut fetches = futures::stream::iter(locked)
+            .map(async |(requirement_text, requirement, version, index)| {
+                let latest = client
+                    .find_latest(&requirement.name, Some(&index), download_concurrency)
+                    .await?;
+                Ok::<_, anyhow::Error>((requirement_text, requirement, version, latest))
+            })
+            .buffer_unordered(concurrency.downloads);
+
+        while let Some((requirement_text, requirement, version, latest)) =
+            fetches.next().await.transpose()?
+        {
+            let Some(latest) = latest.map(DistFilename::into_version) else {
+                reporter.on_fetch_progress();
+                continue;
+            };
+            reporter.on_fetch_version(&requirement.name, &latest);
+            if latest > *version {
+                outdated.push(OutdatedRequirement {
+                    requirement_text,
+                    requirement,
+                    update: UpdateKind::new(version, &latest),
+                    current: version.clone(),
+                    latest,
+                });
+            }
+        }
+        reporter.on_fetch_complete();
+    }
+
+    if outdated.is_empty() {
+        writeln!(printer.stderr(), "All dependencies are up to date")?;
+        return Ok(ExitStatus::Success);
+    }
+    outdated.sort_by(|a, b| a.requirement.name.cmp(&b.requirement.name));
+
+    // Prompt for the dependencies to upgrade.
+    let items = outdated
+        .iter()
+        .map(|outdated| {
+            format!(
+                "{} {} -> {} ({})",
+                outdated.requirement.name, outdated.current, outdated.latest, outdated.update
+            )
+        })
+        .collect::<Vec<_>>();
+    let selection = uv_console::multi_select("Select the dependencies to upgrade", &items, &term)?;
+    if selection.is_empty() {
+        writeln!(printer.stderr(), "No dependencies selected")?;
+        return Ok(ExitStatus::Success);
+    }
+    let selected = selection
+        .into_iter()
+        .map(|index| &outdated[index])
+        .collect::<Vec<_>>();
+    let raise_lower_bounds = uv_console::confirm(
+        "Raise the lower bounds in `pyproject.toml` to the new versions?",
+        &term,
+        false,
+    )?;
+
+    // Update the requirements in the `pyproject.toml`, such that they admit the latest versions.
+    let pyproject_path = project.project_root().join("pyproject.toml");
+    let original = project.current_project().pyproject_toml().raw.clone();
+    let mut pyproject = PyProjectTomlMut::from_toml(&original, DependencyTarget::PyProjectToml)?;
+    let mut updated_requirements = Vec::new();
+    for outdated in &selected {
+        let package = &outdated.requirement.name;
+        let mut proposed = propose_requirement(
+            &outdated.requirement,
+            &BTreeSet::from([outdated.latest.clone()]),
+        )?;
+        if raise_lower_bounds {
+            proposed = raise_lower_bound(&proposed, &outdated.latest);
+        }
+        if proposed == outdated.requirement {
+            continue;
+        }
+        let proposed = into_verbatim_requirement(proposed, package)?;
+        if pyproject.replace_dependency(&proposed, false)?.is_none() {
+            bail!("Dependency `{package}` was not found in `project.dependencies`");
+        }
+        updated_requirements.push((outdated.requirement_text.clone(), proposed.to_string()));
+    }
+    if !updated_requirements.is_empty() {
+        fs_err::write(&pyproject_path, pyproject.to_string())?;
+    }
+
+    // Re-lock the project, upgrading the selected dependencies.
+    let project = match discover_project(project_dir, cache, &WorkspaceCache::default()).await {
+        Ok(project) => project,
+        Err(err) => {
+            fs_err::write(&pyproject_path, &original)?;
+            return Err(err);
+        }
+    };
+    settings.upgrade = selected
+        .iter()
+        .map(|outdated| Upgrade::package(outdated.requirement.name.clone()))
+        .fold(Upgrade::default(), Upgrade::combine);
+    let refresh = Refresh::from(settings.upgrade.clone());
+    let state = UniversalState::default();
+    let result = match Box::pin(
+        LockOperation::new(
+            LockMode::Write(&interpreter),
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        )
+        .with_refresh(&refresh)
+        .execute(LockTarget::Workspace(project.workspace())),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(err) => {
+            // Restore the original `pyproject.toml` if the upgrade failed.
+            fs_err::write(&pyproject_path, &original)?;
+            return match err {
+                ProjectError::Operation(err) => {
+                    diagnostics::OperationDiagnostic::with_system_certs(
+                        client_builder.system_certs(),
+                    )
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                }
+                err => Err(err.into()),
+            };
+        }
+    };
+
+    if let LockResult::Changed(previous, lock) = &result {
+        for event in LockEvent::detect_changes(previous.as_ref(), lock, DryRun::Disabled) {
+            writeln!(printer.stderr(), "{event}")?;
+        }
+    }
+    for (requirement_text, proposed_text) in updated_requirements {
+        writeln!(
+            printer.stderr(),
+            "Updated requirement: `{requirement_text}` -> `{proposed_text}`"
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// An outdated production dependency, as listed by `uv upgrade --interactive`.
+#[derive(Debug)]
+struct OutdatedRequirement {
+    /// The dependency declaration, as written in the `pyproject.toml`.
+    requirement_text: String,
+    requirement: Requirement<VerbatimParsedUrl>,
+    /// The locked version of the dependency.
+    current: Version,
+    /// The latest version of the dependency.
+    latest: Version,
+    update: UpdateKind,
+}
+
+/// Discover the project targeted by `uv upgrade`.
+async fn discover_project(
+    project_dir: &Path,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+) -> Result<ProjectWorkspace> {
+    let project = match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await
+    {
+        Ok(VirtualProject::Project(project)) => project,
+        Ok(VirtualProject::NonProject(_)) => {
+            bail!("`uv upgrade` requires a project with a `[project]` table")
+        }
+        Err(err)
+            if matches!(
+                err.as_ref(),
+                WorkspaceErrorKind::MissingPyprojectToml | WorkspaceErrorKind::MissingProject(_)
+            ) =>
+        {
+            bail!("`uv upgrade` requires a project with a `[project]` table");
+        }
+        Err(err) => return Err(err.into()),
+    };
+
     if project.workspace().packages().len() != 1 {
         bail!("`uv upgrade` does not support workspaces with multiple members yet");
     }
 
+    Ok(project)
+}
+
+/// Discover the interpreter to use when resolving the project.
+async fn discover_interpreter(
+    project: &ProjectWorkspace,
+    project_dir: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    install_mirrors: &PythonInstallMirrors,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Interpreter> {
+    let groups = DependencyGroupsWithDefaults::none();
+    let workspace_python = WorkspacePython::from_request(
+        None,
+        Some(project.workspace()),
+        &groups,
+        project_dir,
+        no_config,
+    )
+    .await?;
+    Ok(ProjectInterpreter::discover(
+        project.workspace(),
+        &groups,
+        workspace_python,
+        client_builder,
+        python_preference,
+        python_downloads,
+        install_mirrors,
+        false,
+        Some(false),
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter())
+}
+
+/// Parse the production dependency declarations of the project.
+fn production_requirements(
+    project: &ProjectWorkspace,
+) -> Result<Vec<(String, Requirement<VerbatimParsedUrl>)>> {
     let dependencies = project
         .current_project()
         .project()
@@ -248,19 +592,30 @@ fn select_requirement(
         .as_deref()
         .unwrap_or_default();
     let pyproject_path = project.project_root().join("pyproject.toml");
-    let mut matching = Vec::new();
-    for dependency in dependencies {
-        let requirement =
-            Requirement::<VerbatimParsedUrl>::from_str(dependency).with_context(|| {
-                format!(
-                    "Failed to parse dependency `{dependency}` from `project.dependencies` in `{}`",
-                    pyproject_path.display()
-                )
-            })?;
-        if requirement.name == *package {
-            matching.push((dependency.clone(), requirement));
-        }
-    }
+    dependencies
+        .iter()
+        .map(|dependency| {
+            let requirement =
+                Requirement::<VerbatimParsedUrl>::from_str(dependency).with_context(|| {
+                    format!(
+                        "Failed to parse dependency `{dependency}` from `project.dependencies` in `{}`",
+                        pyproject_path.display()
+                    )
+                })?;
+            Ok((dependency.clone(), requirement))
+        })
+        .collect()
+}
+
+/// Select the single production dependency declaration targeted by `uv upgrade`.
+fn select_requirement(
+    project: &ProjectWorkspace,
+    package: &PackageName,
+) -> Result<(String, Requirement<VerbatimParsedUrl>)> {
+    let matching = production_requirements(project)?
+        .into_iter()
+        .filter(|(_, requirement)| requirement.name == *package)
+        .collect::<Vec<_>>();
 
     let (requirement_text, requirement) = match matching.as_slice() {
         [] => bail!("Dependency `{package}` was not found in `project.dependencies`"),
@@ -268,6 +623,18 @@ fn select_requirement(
         _ => bail!("Dependency `{package}` is declared multiple times in `project.dependencies`"),
     };
 
+    validate_requirement(project, &requirement)?;
+
+    Ok((requirement_text, requirement))
+}
+
+/// Validate that a production dependency declaration can be upgraded.
+fn validate_requirement(
+    project: &ProjectWorkspace,
+    requirement: &Requirement<VerbatimParsedUrl>,
+) -> Result<()> {
+    let package = &requirement.name;
+
     if matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) {
         bail!("Dependency `{package}` is a direct URL requirement and cannot be upgraded");
     }
@@ -306,7 +673,7 @@ fn select_requirement(
         );
     }
 
-    Ok((requirement_text, requirement))
+    Ok(())
 }
 
 /// Return whether a source applies to the selected requirement declaration.
@@ -532,6 +899,49 @@ fn relax_requirement(
     relaxed
 }
 
+/// Raise the lower bound of a requirement to the given version.
+///
+/// Replaces any `>` and `>=` specifiers with a single `>=` specifier. Requirements that are
+/// already bounded below by an `==`, `===`, or `~=` specifier are returned unchanged.
+fn raise_lower_bound(
+    requirement: &Requirement<VerbatimParsedUrl>,
+    version: &Version,
+) -> Requirement<VerbatimParsedUrl> {
+    let specifiers = match &requirement.version_or_url {
+        Some(VersionOrUrl::VersionSpecifier(specifiers)) => specifiers.clone(),
+        Some(VersionOrUrl::Url(_)) => return requirement.clone(),
+        None => VersionSpecifiers::empty(),
+    };
+    if specifiers.iter().any(|specifier| {
+        matches!(
+            specifier.operator(),
+            Operator::Equal | Operator::EqualStar | Operator::ExactEqual | Operator::TildeEqual
+        )
+    }) {
+        return requirement.clone();
+    }
+
+    let specifiers = std::iter::once(VersionSpecifier::greater_than_equal_version(
+        version.clone().without_local(),
+    ))
+    .chain(
+        specifiers
+            .iter()
+            .filter(|specifier| {
+                !matches!(
+                    specifier.operator(),
+                    Operator::GreaterThan | Operator::GreaterThanEqual
+                )
+            })
+            .cloned(),
+    )
+    .collect::<VersionSpecifiers>();
+
+    let mut raised = requirement.clone();
+    raised.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+    raised
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeSet;
@@ -541,7 +951,9 @@ mod tests {
     use uv_pep508::Requirement;
     use uv_pypi_types::VerbatimParsedUrl;
 
-    use super::{increment_version_at_precision, propose_requirement, relax_requirement};
+    use super::{
+        increment_version_at_precision, propose_requirement, raise_lower_bound, relax_requirement,
+    };
 
     fn resolved_versions(versions: &[&str]) -> BTreeSet<Version> {
         versions
@@ -768,4 +1180,37 @@ mod tests {
             "requests-plus[security,tests]>=2.32 ; python_full_version >= '3.12'"
         );
     }
+
+    #[test]
+    fn raise_lower_bound_replaces_lower_bounds() {
+        for (requirement, expected) in [
+            ("requests", "requests>=2.4.0"),
+            ("requests>=1.2", "requests>=2.4.0"),
+            ("requests>1,>=1.2,!=2.3,<3", "requests!=2.3,>=2.4.0,<3"),
+            (
+                "Requests_Plus[security]<3 ; python_version >= '3.12'",
+                "requests-plus[security]>=2.4.0,<3 ; python_full_version >= '3.12'",
+            ),
+        ] {
+            let requirement =
+                Requirement::<VerbatimParsedUrl>::from_str(requirement).expect("valid requirement");
+
+            let raised =
+                raise_lower_bound(&requirement, &Version::from_str("2.4.0+local").unwrap());
+
+            assert_eq!(raised.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn raise_lower_bound_preserves_pinned_requirements() {
+        for requirement in ["requests==2.4.0", "requests~=2.4", "requests==2.*"] {
+            let requirement =
+                Requirement::<VerbatimParsedUrl>::from_str(requirement).expect("valid requirement");
+
+            let raised = raise_lower_bound(&requirement, &Version::from_str("2.4.0").unwrap());
+
+            assert_eq!(raised, requirement);
+        }
+    }
 }
@@ -0,0 +1,399 @@
+//! Watch files for changes in `uv run --watch`.
+//!
+//! Changes are detected by polling the modification times of the watched files, which avoids
+//! relying on platform-specific file system notifications (e.g., which are unavailable on some
+//! network file systems).
+//!
+//! To keep polling cheap, each poll only reads the modification times of the files and directories
+//! found by the last walk of the watched paths. The watched paths are only walked again when a
+//! directory changed (i.e., a file was added or removed), and periodically in case a file system
+//! doesn't update the modification times of directories.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use tracing::{debug, trace};
+
+/// The interval at which to poll for changes to the known files and directories.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The interval at which to walk the watched paths, even if no directory changed.
+const RESCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The delay after a change before reporting it, such that a burst of writes (e.g., from an
+/// editor saving multiple files) is reported as a single change.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// The time to wait for a command to exit after asking it to terminate, before killing it.
+const TERMINATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A change to a watched file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Change {
+    /// A file in one of the watched paths changed, such that the command should be restarted.
+    Source(PathBuf),
+    /// A project file (e.g., a `pyproject.toml` or `uv.lock`) changed, such that the environment
+    /// should be synced before restarting the command.
+    Project(PathBuf),
+}
+
+/// The modification times of a set of files or directories, keyed by path.
+type ModificationTimes = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The modification times of the watched files, keyed by path.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Snapshot {
+    sources: ModificationTimes,
+    /// The directories in the watched paths, whose modification times change when a file is added
+    /// or removed.
+    directories: ModificationTimes,
+    project: ModificationTimes,
+}
+
+impl Snapshot {
+    /// Return the first change between this snapshot and a newer one, preferring changes to
+    /// project files.
+    fn diff(&self, newer: &Self) -> Option<Change> {
+        if let Some(path) = changed(&self.project, &newer.project) {
+            return Some(Change::Project(path));
+        }
+        changed(&self.sources, &newer.sources).map(Change::Source)
+    }
+}
+
+/// Return the first path that was added, removed, or modified between two sets of files.
+fn changed(older: &ModificationTimes, newer: &ModificationTimes) -> Option<PathBuf> {
+    newer
+        .iter()
+        .find(|(path, modified)| older.get(*path) != Some(modified))
+        .or_else(|| older.iter().find(|(path, _)| !newer.contains_key(*path)))
+        .map(|(path, _)| path.clone())
+}
+
+/// Watches the paths provided via `--watch`, along with the files that define the project.
+#[derive(Debug)]
+pub(crate) struct Watcher {
+    /// The files and directories to watch for changes to the command's sources.
+    sources: Vec<PathBuf>,
+    /// The files that require the environment to be synced when changed.
+    project: Mutex<Vec<PathBuf>>,
+    /// The modification times of the command's sources and the directories containing them before
+    /// the environment was last synced.
+    sources_before_sync: Mutex<Option<(ModificationTimes, ModificationTimes)>>,
+}
+
+impl Watcher {
+    /// Create a [`Watcher`] for the given paths.
+    ///
+    /// Until the project is discovered, the `pyproject.toml` and `uv.lock` in the project
+    /// directory are watched as project files.
+    pub(crate) fn new(sources: Vec<PathBuf>, project_dir: &Path) -> Self {
+        Self {
+            sources,
+            project: Mutex::new(vec![
+                project_dir.join("pyproject.toml"),
+                project_dir.join("uv.lock"),
+            ]),
+            sources_before_sync: Mutex::new(None),
+        }
+    }
+
+    /// Set the files that require the environment to be synced when changed.
+    pub(crate) fn set_project_files(&self, files: Vec<PathBuf>) {
+        *self.project.lock().unwrap() = files;
+    }
+
+    /// Record the modification times of the command's sources before the environment is synced.
+    ///
+    /// Changes made while the environment is syncing are then reported by the snapshot returned
+    /// from [`Watcher::snapshot_since_sync`].
+    pub(crate) fn before_sync(&self) {
+        *self.sources_before_sync.lock().unwrap() = Some(self.sources());
+    }
+
+    /// Take a snapshot from which to detect changes once the environment is synced.
+    ///
+    /// The command's sources are compared against their state before the sync started. Project
+    /// files are read afresh, since syncing may write them (e.g., `uv.lock`) and the set of project
+    /// files is only known once the project is discovered.
+    pub(crate) fn snapshot_since_sync(&self) -> Snapshot {
+        let (sources, directories) = self
+            .sources_before_sync
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| self.sources());
+        Snapshot {
+            sources,
+            directories,
+            project: self.project(),
+        }
+    }
+
+    /// Take a snapshot of the modification times of all watched files, walking the watched
+    /// paths.
+    fn snapshot(&self) -> Snapshot {
+        let (sources, directories) = self.sources();
+        Snapshot {
+            sources,
+            directories,
+            project: self.project(),
+        }
+    }
+
+    /// Re-read the modification times of the files and directories in the given snapshot,
+    /// without walking the watched paths.
+    fn restat(&self, snapshot: &Snapshot) -> Snapshot {
+        let restat = |paths: &ModificationTimes| {
+            paths
+                .keys()
+                .map(|path| (path.clone(), modified(path)))
+                .collect()
+        };
+        Snapshot {
+            sources: restat(&snapshot.sources),
+            directories: restat(&snapshot.directories),
+            project: self.project(),
+        }
+    }
+
+    /// Return the modification times of the command's sources and of the directories containing
+    /// them.
+    fn sources(&self) -> (ModificationTimes, ModificationTimes) {
+        let mut sources = BTreeMap::new();
+        let mut directories = BTreeMap::new();
+        for path in &self.sources {
+            let mut walker = ignore::WalkBuilder::new(path);
+            walker
+                // Respect `.gitignore` files in projects without `.git`.
+                .require_git(false)
+                .filter_entry(|entry| {
+                    entry.file_name() != "__pycache__" && entry.file_name() != ".venv"
+                });
+            for entry in walker.build() {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        trace!("Failed to walk watched path: {err}");
+                        continue;
+                    }
+                };
+                let Some(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_file() {
+                    let modified = modified(entry.path());
+                    sources.insert(entry.into_path(), modified);
+                } else if file_type.is_dir() {
+                    let modified = modified(entry.path());
+                    directories.insert(entry.into_path(), modified);
+                }
+            }
+        }
+        (sources, directories)
+    }
+
+    /// Return the modification times of the project files.
+    fn project(&self) -> ModificationTimes {
+        self.project
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|path| (path.clone(), modified(path)))
+            .collect()
+    }
+
+    /// Wait for a change to the watched files, relative to the given snapshot.
+    ///
+    /// Once a change is detected, the snapshot is updated to reflect it.
+    pub(crate) async fn changed(&self, snapshot: &mut Snapshot) -> Change {
+        let mut last_walk = Instant::now();
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            // Only walk the watched paths if a file may have been added.
+            let mut newer = self.restat(snapshot);
+            if newer.directories != snapshot.directories || last_walk.elapsed() >= RESCAN_INTERVAL {
+                newer = self.snapshot();
+                last_walk = Instant::now();
+            }
+            let Some(change) = snapshot.diff(&newer) else {
+                // A directory may have changed without affecting the watched files (e.g., when
+                // Python writes a `__pycache__` directory).
+                *snapshot = newer;
+                continue;
+            };
+            debug!("Detected change: {change:?}");
+
+            // Wait for any related writes to settle before taking the new snapshot.
+            tokio::time::sleep(DEBOUNCE).await;
+            *snapshot = self.snapshot();
+            return change;
+        }
+    }
+}
+
+/// The process groups of the commands spawned in watch mode.
+///
+/// On Unix, each command is spawned in its own process group, such that the processes it spawns
+/// (e.g., the workers of a server that reloads on changes) are terminated along with it.
+#[derive(Debug, Default)]
+pub(crate) struct ProcessGroups(Mutex<Vec<u32>>);
+
+impl ProcessGroups {
+    /// Track the process group of a spawned command.
+    pub(crate) fn register(&self, child: &tokio::process::Child) {
+        if let Some(id) = child.id() {
+            self.0.lock().unwrap().push(id);
+        }
+    }
+
+    /// Forget the process groups of commands that have already exited.
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    /// Terminate the commands driven by the given future.
+    ///
+    /// On Unix, `SIGTERM` is sent to each process group, and the commands are given up to
+    /// [`TERMINATE_TIMEOUT`] to exit before any remaining processes in the groups are killed.
+    /// Elsewhere, the commands are killed immediately.
+    pub(crate) async fn terminate(&self, running: impl Future) {
+        let groups = std::mem::take(&mut *self.0.lock().unwrap());
+
+        #[cfg(unix)]
+        {
+            use nix::sys::signal::{Signal, killpg};
+            use nix::unistd::Pid;
+
+            let groups = groups
+                .into_iter()
+                .filter_map(|id| i32::try_from(id).ok())
+                .map(Pid::from_raw)
+                .collect::<Vec<_>>();
+            for group in &groups {
+                debug!("Sending SIGTERM to process group {group}");
+                let _ = killpg(*group, Signal::SIGTERM);
+            }
+
+            if tokio::time::timeout(TERMINATE_TIMEOUT, running)
+                .await
+                .is_err()
+            {
+                debug!(
+                    "Command did not exit within {}s of SIGTERM",
+                    TERMINATE_TIMEOUT.as_secs()
+                );
+            }
+
+            // Kill any processes left in the groups, e.g., those that ignored `SIGTERM` or were
+            // orphaned when the command exited.
+            for group in &groups {
+                let _ = killpg(*group, Signal::SIGKILL);
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            // Dropping the future kills the commands, which are spawned with `kill_on_drop`.
+            drop(groups);
+            drop(running);
+        }
+    }
+}
+
+/// Return the modification time of a file, or `None` if it doesn't exist.
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs_err::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use super::{Change, Snapshot, Watcher};
+
+    #[test]
+    fn diff() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+        let snapshot =
+            |sources: &[(&str, SystemTime)], project: &[(&str, Option<SystemTime>)]| Snapshot {
+                sources: sources
+                    .iter()
+                    .map(|(path, modified)| (PathBuf::from(path), Some(*modified)))
+                    .collect(),
+                directories: BTreeMap::new(),
+                project: project
+                    .iter()
+                    .map(|(path, modified)| (PathBuf::from(path), *modified))
+                    .collect::<BTreeMap<_, _>>(),
+            };
+
+        let older = snapshot(&[("src/app.py", now)], &[("uv.lock", Some(now))]);
+        assert_eq!(older.diff(&older.clone()), None);
+
+        // A modified source file.
+        let newer = snapshot(&[("src/app.py", later)], &[("uv.lock", Some(now))]);
+        assert_eq!(
+            older.diff(&newer),
+            Some(Change::Source(PathBuf::from("src/app.py")))
+        );
+
+        // A removed source file.
+        let newer = snapshot(&[], &[("uv.lock", Some(now))]);
+        assert_eq!(
+            older.diff(&newer),
+            Some(Change::Source(PathBuf::from("src/app.py")))
+        );
+
+        // Changes to project files take precedence.
+        let newer = snapshot(
+            &[("src/app.py", later), ("src/new.py", later)],
+            &[("uv.lock", None)],
+        );
+        assert_eq!(
+            older.diff(&newer),
+            Some(Change::Project(PathBuf::from("uv.lock")))
+        );
+    }
+
+    #[test]
+    fn restat() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let src = temp_dir.path().join("src");
+        fs_err::create_dir(&src).unwrap();
+        fs_err::write(src.join("app.py"), "").unwrap();
+
+        let watcher = Watcher::new(vec![src.clone()], temp_dir.path());
+        let snapshot = watcher.snapshot();
+        assert_eq!(snapshot.sources.len(), 1);
+        assert_eq!(watcher.restat(&snapshot), snapshot);
+
+        // Removing a known file is detected without walking the watched paths.
+        fs_err::remove_file(src.join("app.py")).unwrap();
+        assert_eq!(
+            snapshot.diff(&watcher.restat(&snapshot)),
+            Some(Change::Source(src.join("app.py")))
+        );
+
+        // Adding a file changes its directory, which prompts a walk that finds the file.
+        fs_err::write(src.join("app.py"), "").unwrap();
+        let snapshot = watcher.snapshot();
+        fs_err::write(src.join("new.py"), "").unwrap();
+        let newer = watcher.restat(&snapshot);
+        assert!(snapshot.diff(&newer).is_none());
+        assert_ne!(newer.directories, snapshot.directories);
+        assert_eq!(
+            snapshot.diff(&watcher.snapshot()),
+            Some(Change::Source(src.join("new.py")))
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::WhyFormat;
+use uv_client::{BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::{Concurrency, DependencyGroups};
+use uv_distribution_types::IndexCapabilities;
+use uv_git::GitResolver;
+use uv_normalize::{DefaultGroups, PackageName};
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{DependencyPath, WhyDisplay};
+use uv_scripts::Pep723Script;
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{
+    ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, WorkspacePython,
+    default_dependency_groups,
+};
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
+
+/// Explain why a package is included in the lockfile.
+pub(crate) async fn why(
+    project_dir: &Path,
+    package: PackageName,
+    output_format: WhyFormat,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    script: Option<Pep723Script>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::Why) {
+        warn_user!(
+            "`uv why` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Why
+        );
+    }
+    if matches!(output_format, WhyFormat::Json) && !preview.is_enabled(PreviewFeature::JsonOutput) {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    // Find the project requirements.
+    let workspace_cache = WorkspaceCache::default();
+    let workspace;
+    let target = if let Some(script) = script.as_ref() {
+        LockTarget::Script(script)
+    } else {
+        workspace = Workspace::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            &workspace_cache,
+        )
+        .await?;
+        LockTarget::Workspace(&workspace)
+    };
+
+    // Determine the groups to include when discovering an interpreter.
+    let default_groups = match target {
+        LockTarget::Workspace(workspace) => default_dependency_groups(workspace.pyproject_toml())?,
+        LockTarget::Script(_) => DefaultGroups::default(),
+    };
+    let groups = DependencyGroups::default().with_defaults(default_groups);
+
+    // Find an interpreter for the project, unless `--frozen` is set.
+    let interpreter = if frozen.is_some() {
+        None
+    } else {
+        Some(match target {
+            LockTarget::Script(script) => ScriptInterpreter::discover(
+                script.into(),
+                python.as_deref().map(PythonRequest::parse),
+                client_builder,
+                python_preference,
+                python_downloads,
+                &install_mirrors,
+                false,
+                no_config,
+                Some(false),
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter(),
+            LockTarget::Workspace(workspace) => {
+                let workspace_python = WorkspacePython::from_request(
+                    python.as_deref().map(PythonRequest::parse),
+                    Some(workspace),
+                    &groups,
+                    project_dir,
+                    no_config,
+                )
+                .await?;
+                ProjectInterpreter::discover(
+                    workspace,
+                    &groups,
+                    workspace_python,
+                    client_builder,
+                    python_preference,
+                    python_downloads,
+                    &install_mirrors,
+                    false,
+                    Some(false),
+                    cache,
+                    printer,
+                )
+                .await?
+                .into_interpreter()
+            }
+        })
+    };
+
+    // Determine the lock mode.
+    let mode = if let Some(frozen_source) = frozen {
+        LockMode::Frozen(frozen_source.into())
+    } else if let LockCheck::Enabled(lock_check) = lock_check {
+        LockMode::Locked(interpreter.as_ref().unwrap(), lock_check)
+    } else if matches!(target, LockTarget::Script(_)) && !target.lock_path().is_file() {
+        // If we're locking a script, avoid creating a lockfile if it doesn't already exist.
+        LockMode::DryRun(interpreter.as_ref().unwrap())
+    } else {
+        LockMode::Write(interpreter.as_ref().unwrap())
+    };
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Update the lockfile, if necessary.
+    let lock = match Box::pin(
+        LockOperation::new(
+            mode,
+            &settings,
+            client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            &concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(target),
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    if !lock
+        .packages()
+        .iter()
+        .any(|locked| locked.name() == &package)
+    {
+        bail!("Package `{}` was not found in the lockfile", package.cyan());
+    }
+
+    let why = WhyDisplay::new(&lock, &package);
+
+    // The lockfile doesn't record the requirements of registry packages, so fetch the metadata of
+    // any registry package along the paths, to determine the specifiers on its edges.
+    let parents = why
+        .immutable_parents()
+        .into_iter()
+        .filter_map(|parent| {
+            let version = parent.version()?;
+            match parent.registry_wheel(target.install_path()) {
+                Ok(Some(wheel)) => Some(Ok((parent.name(), version, wheel))),
+                Ok(None) => None,
+                Err(err) => Some(Err(err)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let requires_dist = if parents.is_empty() {
+        FxHashMap::default()
+    } else {
+        let ResolverSettings {
+            index_locations,
+            keyring_provider,
+            ..
+        } = &settings;
+        let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+            .index_locations(index_locations.clone())
+            .keyring(*keyring_provider)
+            .build()?;
+        let git = GitResolver::default();
+        let capabilities = IndexCapabilities::default();
+        let (client, git, capabilities) = (&client, &git, &capabilities);
+        futures::stream::iter(parents)
+            .map(async |(name, version, wheel)| {
+                match client.wheel_metadata(&wheel, git, capabilities, None).await {
+                    Ok(metadata) => Some(((name, version), metadata.requires_dist)),
+                    Err(err) => {
+                        debug!("Failed to fetch metadata for `{name}=={version}`: {err}");
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(concurrency.downloads)
+            .filter_map(std::future::ready)
+            .collect::<FxHashMap<_, _>>()
+            .await
+    };
+    let why = why.with_requires_dist(|parent| {
+        requires_dist
+            .get(&(parent.name(), parent.version()?))
+            .map(AsRef::as_ref)
+    });
+
+    match output_format {
+        WhyFormat::Text => {
+            if why.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No dependency paths lead to `{}`",
+                    package.cyan()
+                )?;
+            } else {
+                write!(printer.stdout(), "{why}")?;
+            }
+        }
+        WhyFormat::Json => {
+            let report = Report::new(&package, &why);
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    schema: Schema,
+    package: String,
+    versions: Vec<String>,
+    forks: Vec<Fork>,
+}
+
+impl Report {
+    fn new(package: &PackageName, why: &WhyDisplay) -> Self {
+        Self {
+            schema: Schema::default(),
+            package: package.to_string(),
+            versions: why.versions().map(ToString::to_string).collect(),
+            forks: why
+                .forks()
+                .iter()
+                .map(|fork| Fork {
+                    marker: fork.marker().map(ToString::to_string),
+                    paths: fork
+                        .paths()
+                        .iter()
+                        .map(|path| PackagePath::from(*path))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+struct Schema {
+    version: SchemaVersion,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    #[default]
+    Preview,
+}
+
+#[derive(Debug, Serialize)]
+struct Fork {
+    marker: Option<String>,
+    paths: Vec<PackagePath>,
+}
+
+#[derive(Debug, Serialize)]
+struct PackagePath {
+    root: Option<Node>,
+    steps: Vec<Step>,
+}
+
+impl From<&DependencyPath<'_>> for PackagePath {
+    fn from(path: &DependencyPath<'_>) -> Self {
+        Self {
+            root: path.root().map(|(name, version)| Node {
+                name: name.to_string(),
+                version: version.map(ToString::to_string),
+            }),
+            steps: path
+                .steps()
+                .iter()
+                .map(|step| Step {
+                    name: step.name().to_string(),
+                    version: step.version().map(ToString::to_string),
+                    extras: step.extras().iter().map(ToString::to_string).collect(),
+                    extra: step.extra().map(ToString::to_string),
+                    group: step.group().map(ToString::to_string),
+                    specifier: step.specifier().map(ToString::to_string),
+                    marker: step.marker().map(ToString::to_string),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Node {
+    name: String,
+    version: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Step {
+    name: String,
+    version: Option<String>,
+    extras: Vec<String>,
+    extra: Option<String>,
+    group: Option<String>,
+    specifier: Option<String>,
+    marker: Option<String>,
+}
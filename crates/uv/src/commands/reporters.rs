@@ -14,7 +14,7 @@ use crate::printer::Printer;
 use uv_cache::Removal;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    BuildableSource, CachedDist, DistributionMetadata, Name, SourceDist, VersionOrUrlRef,
+    BuildableSource, CachedDist, Dist, DistributionMetadata, Name, SourceDist, VersionOrUrlRef,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -466,6 +466,11 @@ impl PrepareReporter {
         self.reporter.root.set_length(length);
         self
     }
+
+    /// Callback to invoke when an archive is written to a bundle directory.
+    pub(crate) fn on_bundled(&self) {
+        self.reporter.root.inc(1);
+    }
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
@@ -473,6 +478,10 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         self.reporter.root.inc(1);
     }
 
+    fn on_archive(&self, _dist: &Dist) {
+        self.reporter.root.inc(1);
+    }
+
     fn on_complete(&self) {
         // Need an extra call to `set_message` here to fully clear avoid leaving ghost output
         // in Jupyter notebooks.
@@ -509,6 +518,36 @@ impl uv_installer::PrepareReporter for PrepareReporter {
     }
 }
 
+impl uv_distribution::Reporter for PrepareReporter {
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_start(source)
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_complete(source, id);
+    }
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        self.reporter.on_download_start(name.to_string(), size)
+    }
+
+    fn on_download_progress(&self, id: usize, bytes: u64) {
+        self.reporter.on_download_progress(id, bytes);
+    }
+
+    fn on_download_complete(&self, _name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(id);
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        self.reporter.on_checkout_start(url, rev)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        self.reporter.on_checkout_complete(url, rev, id);
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ResolverReporter {
     reporter: ProgressReporter,
@@ -0,0 +1,259 @@
+//! Compare the archives of two builds of the same distribution.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::path::Path;
+
+use futures::StreamExt;
+use jiff::Timestamp;
+use tokio::io::AsyncReadExt;
+
+use uv_distribution_filename::SourceDistExtension;
+
+/// The `SOURCE_DATE_EPOCH` to use when verifying reproducibility and the user didn't set one.
+///
+/// 1980-01-01 is the earliest timestamp a zip archive can represent.
+pub(crate) const DEFAULT_SOURCE_DATE_EPOCH: u64 = 315_532_800;
+
+/// A file in a wheel or source distribution archive.
+struct ArchiveEntry {
+    /// The path of the file in the archive.
+    path: String,
+    /// The modification time, as stored by the archive format.
+    modified: String,
+    /// The Unix permissions, if stored.
+    mode: Option<u32>,
+    contents: Vec<u8>,
+}
+
+/// A difference between two builds of the same archive.
+#[derive(Debug)]
+pub(crate) enum Difference {
+    /// The file is only present in one of the builds.
+    Missing { path: String, first: bool },
+    /// The file contents differ.
+    Contents { path: String },
+    /// The file modification times differ.
+    Timestamp {
+        path: String,
+        first: String,
+        second: String,
+    },
+    /// The file permissions differ.
+    Permissions {
+        path: String,
+        first: Option<u32>,
+        second: Option<u32>,
+    },
+    /// The archives contain the same files, but in a different order.
+    Order,
+    /// The archives contain the same files, but are not byte-for-byte identical, e.g., due to
+    /// compression settings or archive metadata.
+    Archive,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { path, first } => {
+                let (present, missing) = if *first {
+                    ("first", "second")
+                } else {
+                    ("second", "first")
+                };
+                write!(
+                    f,
+                    "`{path}` is present in the {present} build, but missing in the {missing} build"
+                )
+            }
+            Self::Contents { path } => write!(f, "`{path}` has different contents"),
+            Self::Timestamp {
+                path,
+                first,
+                second,
+            } => write!(f, "`{path}` has different timestamps: {first} and {second}"),
+            Self::Permissions {
+                path,
+                first,
+                second,
+            } => write!(
+                f,
+                "`{path}` has different permissions: {} and {}",
+                DisplayMode(*first),
+                DisplayMode(*second)
+            ),
+            Self::Order => write!(f, "The files are stored in a different order"),
+            Self::Archive => write!(
+                f,
+                "The archives contain the same files, but are not byte-for-byte identical"
+            ),
+        }
+    }
+}
+
+struct DisplayMode(Option<u32>);
+
+impl fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(mode) => write!(f, "{:o}", mode & 0o7777),
+            None => write!(f, "(none)"),
+        }
+    }
+}
+
+/// Compare two builds of the same wheel or source distribution.
+///
+/// Returns an empty list if the archives are byte-for-byte identical.
+pub(crate) async fn compare_archives(first: &Path, second: &Path) -> io::Result<Vec<Difference>> {
+    if fs_err::tokio::read(first).await? == fs_err::tokio::read(second).await? {
+        return Ok(Vec::new());
+    }
+
+    let (Some(first), Some(second)) = (read_archive(first).await?, read_archive(second).await?)
+    else {
+        // We can't inspect other archive formats.
+        return Ok(vec![Difference::Archive]);
+    };
+
+    let mut differences = Vec::new();
+    for first_entry in &first {
+        let Some(second_entry) = second
+            .iter()
+            .find(|second_entry| second_entry.path == first_entry.path)
+        else {
+            differences.push(Difference::Missing {
+                path: first_entry.path.clone(),
+                first: true,
+            });
+            continue;
+        };
+        if first_entry.contents != second_entry.contents {
+            differences.push(Difference::Contents {
+                path: first_entry.path.clone(),
+            });
+        }
+        if first_entry.modified != second_entry.modified {
+            differences.push(Difference::Timestamp {
+                path: first_entry.path.clone(),
+                first: first_entry.modified.clone(),
+                second: second_entry.modified.clone(),
+            });
+        }
+        if first_entry.mode != second_entry.mode {
+            differences.push(Difference::Permissions {
+                path: first_entry.path.clone(),
+                first: first_entry.mode,
+                second: second_entry.mode,
+            });
+        }
+    }
+    for second_entry in &second {
+        if !first
+            .iter()
+            .any(|first_entry| first_entry.path == second_entry.path)
+        {
+            differences.push(Difference::Missing {
+                path: second_entry.path.clone(),
+                first: false,
+            });
+        }
+    }
+
+    if differences.is_empty() {
+        if first
+            .iter()
+            .map(|entry| &entry.path)
+            .ne(second.iter().map(|entry| &entry.path))
+        {
+            differences.push(Difference::Order);
+        } else {
+            differences.push(Difference::Archive);
+        }
+    }
+
+    Ok(differences)
+}
+
+/// Read the entries of a wheel or a `.tar.gz` or `.zip` source distribution.
+///
+/// Returns `None` for other archive formats.
+async fn read_archive(path: &Path) -> io::Result<Option<Vec<ArchiveEntry>>> {
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("whl"))
+    {
+        return read_zip(path).await.map(Some);
+    }
+    match SourceDistExtension::from_path(path) {
+        Ok(SourceDistExtension::Zip) => read_zip(path).await.map(Some),
+        Ok(SourceDistExtension::TarGz) => read_tar_gz(path).await.map(Some),
+        _ => Ok(None),
+    }
+}
+
+async fn read_zip(path: &Path) -> io::Result<Vec<ArchiveEntry>> {
+    let file = fs_err::tokio::File::open(path).await?;
+    let mut reader =
+        async_zip::base::read::seek::ZipFileReader::with_tokio(tokio::io::BufReader::new(file))
+            .await
+            .map_err(io::Error::other)?;
+    let mut entries = Vec::new();
+    for index in 0..reader.file().entries().len() {
+        let mut contents = Vec::new();
+        reader
+            .reader_with_entry(index)
+            .await
+            .map_err(io::Error::other)?
+            .read_to_end_checked(&mut contents)
+            .await
+            .map_err(io::Error::other)?;
+        let entry = &reader.file().entries()[index];
+        let date = entry.last_modification_date();
+        entries.push(ArchiveEntry {
+            path: entry
+                .filename()
+                .as_str()
+                .map_err(io::Error::other)?
+                .to_string(),
+            modified: format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second()
+            ),
+            mode: entry.unix_permissions().map(u32::from),
+            contents,
+        });
+    }
+    Ok(entries)
+}
+
+async fn read_tar_gz(path: &Path) -> io::Result<Vec<ArchiveEntry>> {
+    let compressed = fs_err::tokio::read(path).await?;
+    let mut tar = Vec::new();
+    flate2::read::GzDecoder::new(compressed.as_slice()).read_to_end(&mut tar)?;
+
+    let mut archive = tokio_tar::Archive::new(tar.as_slice());
+    let mut stream = archive.entries()?;
+    let mut entries = Vec::new();
+    while let Some(entry) = stream.next().await {
+        let mut entry = entry?;
+        let mut contents = Vec::new();
+        entry.read_to_end(&mut contents).await?;
+        let mtime = entry.header().mtime()?;
+        entries.push(ArchiveEntry {
+            path: entry.path()?.to_string_lossy().into_owned(),
+            modified: i64::try_from(mtime)
+                .ok()
+                .and_then(|mtime| Timestamp::from_second(mtime).ok())
+                .map_or_else(|| mtime.to_string(), |timestamp| timestamp.to_string()),
+            mode: Some(entry.header().mode()?),
+            contents,
+        });
+    }
+    Ok(entries)
+}
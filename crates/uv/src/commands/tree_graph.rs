@@ -0,0 +1,220 @@
+//! Display the dependency graph of `uv tree` and `uv pip tree` in a machine-readable format.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use uv_cli::TreeFormat;
+use uv_resolver::TreeGraph;
+
+/// A dependency graph, as displayed by `--output-format json`, `dot`, or `mermaid`.
+#[derive(Debug, Serialize)]
+pub(crate) struct GraphReport {
+    schema: Schema,
+    /// The indices of the packages at the root of the graph.
+    roots: Vec<usize>,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Serialize, Default)]
+struct Schema {
+    version: SchemaVersion,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    #[default]
+    Preview,
+}
+
+/// A package in the dependency graph.
+#[derive(Debug, Serialize)]
+pub(crate) struct Node {
+    pub(crate) name: String,
+    pub(crate) version: Option<String>,
+    pub(crate) source: Option<String>,
+    pub(crate) latest: Option<String>,
+    pub(crate) size: Option<u64>,
+}
+
+/// An edge from a package to one of its dependencies (or, in `--invert` mode, its dependents).
+#[derive(Debug, Serialize)]
+pub(crate) struct Edge {
+    /// The index of the package that the edge starts from, or `None` for dependencies of the
+    /// project itself (e.g., for the dependencies of a PEP 723 script).
+    pub(crate) from: Option<usize>,
+    /// The index of the package that the edge leads to.
+    pub(crate) to: usize,
+    pub(crate) extras: Vec<String>,
+    pub(crate) extra: Option<String>,
+    pub(crate) group: Option<String>,
+    pub(crate) specifier: Option<String>,
+    pub(crate) marker: Option<String>,
+}
+
+impl Edge {
+    /// A short description of the edge, for use as a label in DOT and Mermaid output.
+    fn label(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.extras.is_empty() {
+            parts.push(format!("[{}]", self.extras.join(", ")));
+        }
+        if let Some(specifier) = &self.specifier {
+            parts.push(specifier.clone());
+        }
+        if let Some(extra) = &self.extra {
+            parts.push(format!("extra: {extra}"));
+        }
+        if let Some(group) = &self.group {
+            parts.push(format!("group: {group}"));
+        }
+        if let Some(marker) = &self.marker {
+            parts.push(format!("; {marker}"));
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" "))
+        }
+    }
+}
+
+impl GraphReport {
+    pub(crate) fn new(roots: Vec<usize>, nodes: Vec<Node>, edges: Vec<Edge>) -> Self {
+        Self {
+            schema: Schema::default(),
+            roots,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Render the graph in the given format.
+    pub(crate) fn render(&self, format: TreeFormat) -> anyhow::Result<String> {
+        match format {
+            TreeFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            TreeFormat::Dot => Ok(self.to_dot()),
+            TreeFormat::Mermaid => Ok(self.to_mermaid()),
+            TreeFormat::Text => unreachable!("text output is rendered as a tree"),
+        }
+    }
+
+    /// A short description of the package at the given index.
+    fn label(&self, index: usize) -> String {
+        let node = &self.nodes[index];
+        let mut label = node.name.clone();
+        if let Some(version) = &node.version {
+            let _ = write!(label, " v{version}");
+        }
+        if let Some(latest) = &node.latest {
+            let _ = write!(label, " (latest: v{latest})");
+        }
+        if let Some(size) = node.size {
+            let (bytes, unit) = uv_console::human_readable_bytes(size);
+            let _ = write!(label, " ({bytes:.1}{unit})");
+        }
+        label
+    }
+
+    /// Render the graph in the Graphviz DOT format.
+    ///
+    /// Edges from the project itself are omitted, since the project isn't a node in the graph.
+    fn to_dot(&self) -> String {
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut output = String::from("digraph {\n");
+        for index in 0..self.nodes.len() {
+            let _ = writeln!(
+                output,
+                "    n{index} [label=\"{}\"];",
+                escape(&self.label(index))
+            );
+        }
+        for edge in &self.edges {
+            let Some(from) = edge.from else {
+                continue;
+            };
+            match edge.label() {
+                Some(label) => {
+                    let _ = writeln!(
+                        output,
+                        "    n{from} -> n{} [label=\"{}\"];",
+                        edge.to,
+                        escape(&label)
+                    );
+                }
+                None => {
+                    let _ = writeln!(output, "    n{from} -> n{};", edge.to);
+                }
+            }
+        }
+        output.push('}');
+        output
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    ///
+    /// Edges from the project itself are omitted, since the project isn't a node in the graph.
+    fn to_mermaid(&self) -> String {
+        let escape = |value: &str| value.replace('"', "#quot;");
+
+        let mut output = String::from("flowchart TD\n");
+        for index in 0..self.nodes.len() {
+            let _ = writeln!(output, "    n{index}[\"{}\"]", escape(&self.label(index)));
+        }
+        for edge in &self.edges {
+            let Some(from) = edge.from else {
+                continue;
+            };
+            match edge.label() {
+                Some(label) => {
+                    let _ = writeln!(
+                        output,
+                        "    n{from} -->|\"{}\"| n{}",
+                        escape(&label),
+                        edge.to
+                    );
+                }
+                None => {
+                    let _ = writeln!(output, "    n{from} --> n{}", edge.to);
+                }
+            }
+        }
+        output.truncate(output.trim_end().len());
+        output
+    }
+}
+
+impl From<&TreeGraph<'_>> for GraphReport {
+    fn from(graph: &TreeGraph<'_>) -> Self {
+        Self::new(
+            graph.roots().to_vec(),
+            graph
+                .nodes()
+                .iter()
+                .map(|node| Node {
+                    name: node.name().to_string(),
+                    version: node.version().map(ToString::to_string),
+                    source: Some(node.source()),
+                    latest: node.latest().map(ToString::to_string),
+                    size: node.size(),
+                })
+                .collect(),
+            graph
+                .edges()
+                .iter()
+                .map(|edge| Edge {
+                    from: edge.source(),
+                    to: edge.target(),
+                    extras: edge.extras().iter().map(ToString::to_string).collect(),
+                    extra: edge.extra().map(ToString::to_string),
+                    group: edge.group().map(ToString::to_string),
+                    specifier: edge.specifier().map(ToString::to_string),
+                    marker: edge.marker(),
+                })
+                .collect(),
+        )
+    }
+}
@@ -10,16 +10,18 @@ use uv_fs::{CWD, Simplified, is_virtualenv_base, normalize_path};
 use uv_preview::{Preview, PreviewFeature};
 use uv_scripts::Pep723Metadata;
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::{PyProjectToml, ToolUvTasks};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
-/// List workspace members or PEP 723 scripts.
+/// List workspace members, PEP 723 scripts, or tasks.
 pub(crate) async fn list(
     project_dir: &Path,
     paths: bool,
     scripts: bool,
+    tasks: bool,
     cache: &Cache,
     workspace_cache: &WorkspaceCache,
     printer: Printer,
@@ -31,6 +33,12 @@ pub(crate) async fn list(
             PreviewFeature::WorkspaceListScripts
         );
     }
+    if tasks && !preview.is_enabled(PreviewFeature::Tasks) {
+        warn_user!(
+            "The `--tasks` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::Tasks
+        );
+    }
 
     let workspace = Workspace::discover(
         project_dir,
@@ -50,6 +58,34 @@ pub(crate) async fn list(
         return Ok(ExitStatus::Success);
     }
 
+    if tasks {
+        // Tasks defined in a virtual workspace root aren't associated with any member.
+        if workspace.pyproject_toml().project.is_none() {
+            if let Some(tasks) = tool_uv_tasks(workspace.pyproject_toml()) {
+                for (task, definition) in tasks.inner() {
+                    write_task(
+                        printer,
+                        &task.cyan().to_string(),
+                        definition.description.as_deref(),
+                    )?;
+                }
+            }
+        }
+        for (name, member) in workspace.packages() {
+            let Some(tasks) = tool_uv_tasks(member.pyproject_toml()) else {
+                continue;
+            };
+            for (task, definition) in tasks.inner() {
+                write_task(
+                    printer,
+                    &format!("{}:{}", name.cyan(), task.cyan()),
+                    definition.description.as_deref(),
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     for (name, member) in workspace.packages() {
         if paths {
             writeln!(
@@ -65,6 +101,25 @@ pub(crate) async fn list(
     Ok(ExitStatus::Success)
 }
 
+/// Return the tasks defined in `tool.uv.tasks`, if any.
+fn tool_uv_tasks(pyproject_toml: &PyProjectToml) -> Option<&ToolUvTasks> {
+    pyproject_toml
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.tasks.as_ref())
+}
+
+/// Write a task, along with its description (if any).
+fn write_task(printer: Printer, task: &str, description: Option<&str>) -> Result<()> {
+    if let Some(description) = description {
+        writeln!(printer.stdout(), "{task}  {}", description.dimmed())?;
+    } else {
+        writeln!(printer.stdout(), "{task}")?;
+    }
+    Ok(())
+}
+
 /// Find PEP 723 scripts under a workspace root.
 ///
 /// Respects ignore files and excludes repository internals, virtual environments, and the uv cache
@@ -22,15 +22,15 @@ use tracing::{debug, instrument, trace};
 
 #[cfg(not(feature = "self-update"))]
 use crate::install_source::InstallSource;
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, EvictionPolicy, Refresh};
 use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    CacheNamespace, Cli, Commands, IndexCommand, IndexNamespace, PipCommand, PipNamespace,
+    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
@@ -53,9 +53,9 @@ use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 use crate::commands::{ExitStatus, ParsedRunCommand, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings, resolve_color,
+    CacheSettings, GlobalSettings, PipAuditSettings, PipCheckSettings, PipCompileSettings,
+    PipDownloadSettings, PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings,
+    PipSyncSettings, PipUninstallSettings, PublishSettings, resolve_color,
 };
 
 pub(crate) mod child;
@@ -113,11 +113,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             module,
             script,
             gui_script,
+            task,
             ..
         }) = **command
     {
         Some(ParsedRunCommand::from_args(
-            command, module, script, gui_script,
+            command, module, script, gui_script, task,
         )?)
     } else {
         None
@@ -355,6 +356,14 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 script: Some(script),
                 ..
             })
+            | ProjectCommand::Why(uv_cli::WhyArgs {
+                script: Some(script),
+                ..
+            })
+            | ProjectCommand::Outdated(uv_cli::OutdatedArgs {
+                script: Some(script),
+                ..
+            })
             | ProjectCommand::Export(uv_cli::ExportArgs {
                 script: Some(script),
                 ..
@@ -557,6 +566,23 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
     let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = if let Some(remote_cache) = cache_settings.remote_cache {
+        if !globals.preview.is_enabled(PreviewFeature::RemoteCache) {
+            warn_user!(
+                "The remote cache is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::RemoteCache
+            );
+        }
+        if cache_settings.no_cache {
+            debug!("Ignoring the remote cache due to `--no-cache`");
+            cache
+        } else {
+            debug!("Using remote cache: {remote_cache}");
+            cache.with_remote(Some(remote_cache))
+        }
+    } else {
+        cache
+    };
     // This check happens after the first (fallible) workspace discovery, which we need to resolve
     // the settings that go into the cache constructor, but the check happens before the first
     // workspace discovery that's used beyond settings discovery.
@@ -1057,6 +1083,95 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Download(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipDownloadSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            let dest = args.dest.unwrap_or_else(|| CWD.to_path_buf());
+
+            Box::pin(commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                args.build_constraints_from_workspace,
+                &dest,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["pip".to_owned(), "download".to_owned()]),
+                args.settings.hash_checking,
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.build_isolation.clone(),
+                &args.settings.extra_build_dependencies,
+                &args.settings.extra_build_variables,
+                args.settings.build_options,
+                args.settings.python_version,
+                args.settings.python_platform,
+                globals.python_downloads,
+                args.settings.install_mirrors,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.link_mode,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                workspace_cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Uninstall(args),
         }) => {
@@ -1193,6 +1308,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.no_dedupe,
                 args.invert,
                 args.outdated,
+                args.output_format,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -1206,6 +1322,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.system,
                 &cache,
                 printer,
+                globals.preview,
             )
             .await
         }
@@ -1229,6 +1346,32 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Audit(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipAuditSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::pip_audit(
+                &args.requirements,
+                args.settings.python.as_deref(),
+                args.settings.system,
+                args.output_format,
+                args.service_format,
+                args.service_url.as_deref(),
+                args.policy,
+                &client_builder.subcommand(vec!["pip".to_owned(), "audit".to_owned()]),
+                &globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Debug(_),
         }) => Err(anyhow!(
@@ -1245,14 +1388,63 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer).await
+            commands::cache_prune(
+                args.ci,
+                args.force,
+                EvictionPolicy {
+                    max_age: args.max_age,
+                    max_size: args.max_size,
+                },
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
-        }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        }) => commands::cache_size(
+            &cache,
+            args.human,
+            args.buckets,
+            args.output_format,
+            printer,
+            globals.preview,
+        ),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Inspect(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheInspectSettings::resolve(args, filesystem);
+            show_settings!(args);
+            commands::cache_inspect(
+                &args.package,
+                args.human,
+                args.output_format,
+                &args.indexes,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Serve(args),
+        }) => {
+            show_settings!(args);
+            commands::index_serve(
+                args.path.as_deref(),
+                args.from_cache,
+                args.host,
+                args.port,
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1285,6 +1477,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.sdist,
                 args.wheel,
                 args.list,
+                args.verify_reproducible,
                 args.build_logs,
                 args.gitignore,
                 args.force_pep517,
@@ -2066,6 +2259,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.paths,
                     args.scripts,
+                    args.tasks,
                     &cache,
                     &workspace_cache,
                     printer,
@@ -2107,6 +2301,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             BuildBackendCommand::PrepareMetadataForBuildEditable { wheel_directory } => {
                 commands::build_backend::prepare_metadata_for_build_editable(&wheel_directory)
             }
+            BuildBackendCommand::BuildHook => commands::build_backend::build_hook(),
         })
         .await
         .expect("tokio threadpool exited unexpectedly"),
@@ -2261,63 +2456,115 @@ async fn run_project(
             // Initialize the cache.
             let cache = cache.init().await?.with_refresh(
                 args.refresh
+                    .clone()
                     .combine(Refresh::from(args.settings.reinstall.clone()))
                     .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
             );
 
-            let mut requirements = Vec::with_capacity(
-                args.with.len() + args.with_editable.len() + args.with_requirements.len(),
-            );
-            for package in args.with {
-                requirements.push(RequirementsSource::from_with_package_argument(&package)?);
-            }
-            for package in args.with_editable {
-                requirements.push(RequirementsSource::from_editable(&package)?);
-            }
-            requirements.extend(
-                args.with_requirements
-                    .into_iter()
-                    .map(RequirementsSource::from_requirements_file)
-                    .collect::<Result<Vec<_>, _>>()?,
-            );
+            // In watch mode, the command is restarted whenever a watched file changes.
+            let watcher = (!args.watch.is_empty())
+                .then(|| commands::Watcher::new(args.watch.clone(), project_dir));
 
-            Box::pin(commands::run(
-                project_dir,
-                script,
-                command,
-                requirements,
-                args.show_resolution || globals.verbose > 0,
-                args.lock_check,
-                args.frozen,
-                args.active,
-                args.no_sync,
-                args.isolated,
-                args.all_packages,
-                args.package,
-                args.no_project,
-                no_config,
-                args.extras,
-                args.groups,
-                args.editable,
-                args.modifications,
-                args.python,
-                args.python_platform,
-                args.install_mirrors,
-                args.settings,
-                client_builder.subcommand(vec!["run".to_owned()]),
-                globals.python_preference,
-                globals.python_downloads,
-                globals.installer_metadata,
-                globals.concurrency,
-                cache,
-                workspace_cache,
-                printer,
-                args.env_file,
-                globals.preview,
-                args.max_recursion_depth,
-                args.malware_settings,
-            ))
-            .await
+            let mut command = command;
+            let mut script = script;
+            let mut restart_workspace_cache = None;
+            loop {
+                let args = args.clone();
+
+                let mut requirements = Vec::with_capacity(
+                    args.with.len() + args.with_editable.len() + args.with_requirements.len(),
+                );
+                for package in args.with {
+                    requirements.push(RequirementsSource::from_with_package_argument(&package)?);
+                }
+                for package in args.with_editable {
+                    requirements.push(RequirementsSource::from_editable(&package)?);
+                }
+                requirements.extend(
+                    args.with_requirements
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_file)
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+
+                // Retain a copy of the command, to restart it once the environment is synced.
+                let restart = command.as_ref().map(RunCommand::try_clone);
+
+                // Record the state of the watched files before syncing, such that changes made
+                // while the environment is syncing aren't missed.
+                if let Some(watcher) = watcher.as_ref() {
+                    watcher.before_sync();
+                }
+
+                let result = Box::pin(commands::run(
+                    project_dir,
+                    script,
+                    command,
+                    requirements,
+                    args.show_resolution || globals.verbose > 0,
+                    args.lock_check,
+                    args.frozen,
+                    args.active,
+                    args.no_sync,
+                    args.isolated,
+                    args.all_packages,
+                    args.fail_fast,
+                    watcher.as_ref(),
+                    args.package,
+                    args.no_project,
+                    no_config,
+                    args.extras,
+                    args.groups,
+                    args.editable,
+                    args.modifications,
+                    args.python,
+                    args.python_platform,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.clone().subcommand(vec!["run".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.installer_metadata,
+                    globals.concurrency.clone(),
+                    cache.clone(),
+                    restart_workspace_cache.as_ref().unwrap_or(workspace_cache),
+                    printer,
+                    args.env_file,
+                    globals.preview,
+                    args.max_recursion_depth,
+                    args.malware_settings,
+                ))
+                .await;
+
+                let Some(watcher) = watcher.as_ref() else {
+                    return result;
+                };
+                // Scripts read from `stdin` or downloaded from a remote URL can't be restarted.
+                let restart = match restart {
+                    Some(None) => return result,
+                    restart => restart.flatten(),
+                };
+
+                // If the environment couldn't be synced, wait for the user to fix it.
+                if let Err(err) = result {
+                    uv_errors::write_error_chain_with_options(
+                        err.as_ref(),
+                        uv_errors::ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                    writeln!(printer.stderr(), "{}", "Waiting for changes...".dimmed())?;
+                    watcher.changed(&mut watcher.snapshot_since_sync()).await;
+                }
+
+                // Re-read the script metadata, which may have changed.
+                script = match &restart {
+                    Some(command) => command.read_pep723_item().await?,
+                    None => None,
+                };
+                command = restart;
+
+                // Discover the workspace from scratch, since its members may have changed.
+                restart_workspace_cache = Some(WorkspaceCache::default());
+            }
         }
         ProjectCommand::Sync(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
@@ -2393,6 +2640,31 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
+            if let Some(merge_driver) = args.merge_driver {
+                if args.script.is_some() {
+                    bail!("`uv lock merge-driver` does not support `--script`");
+                }
+                return Box::pin(commands::lock_merge_driver(
+                    project_dir,
+                    &merge_driver.base,
+                    &merge_driver.current,
+                    &merge_driver.other,
+                    args.python,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.subcommand(vec!["lock".to_owned(), "merge-driver".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    workspace_cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            }
+
             // If the script already exists, use it; otherwise, propagate the file path and we'll
             // initialize it later on.
             let script = script
@@ -2411,6 +2683,8 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.diff,
+                args.diff_format,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2439,9 +2713,27 @@ async fn run_project(
                 .await?
                 .with_refresh(Refresh::from(args.settings.upgrade.clone()));
 
+            let Some(package) = args.package else {
+                return Box::pin(commands::upgrade_interactive(
+                    project_dir,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.subcommand(vec!["upgrade".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    workspace_cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            };
+
             Box::pin(commands::upgrade(
                 project_dir,
-                args.package,
+                package,
                 args.install_mirrors,
                 args.settings,
                 client_builder.subcommand(vec!["upgrade".to_owned()]),
@@ -2539,6 +2831,26 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
             );
 
+            if args.bump_all {
+                return Box::pin(commands::bump_all(
+                    project_dir,
+                    args.active,
+                    args.bounds,
+                    args.python,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.subcommand(vec!["add".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            }
+
             let constraints = args
                 .constraints
                 .into_iter()
@@ -2716,6 +3028,7 @@ async fn run_project(
                 args.invert,
                 args.outdated,
                 args.show_sizes,
+                args.output_format,
                 args.python_version,
                 args.python_platform,
                 args.python,
@@ -2733,6 +3046,80 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Why(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WhySettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv why` does not support stdin"),
+                Pep723Item::Remote(..) => unreachable!("`uv why` does not support remote files"),
+            });
+
+            Box::pin(commands::why(
+                project_dir,
+                args.package,
+                args.output_format,
+                args.lock_check,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.resolver,
+                &client_builder.subcommand(vec!["why".to_owned()]),
+                script,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Outdated(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::OutdatedSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            // Unwrap the script.
+            let script = script.map(|script| match script {
+                Pep723Item::Script(script) => script,
+                Pep723Item::Stdin(..) => unreachable!("`uv outdated` does not support stdin"),
+                Pep723Item::Remote(..) => {
+                    unreachable!("`uv outdated` does not support remote files")
+                }
+            });
+
+            Box::pin(commands::outdated(
+                project_dir,
+                args.output_format,
+                args.direct,
+                args.lock_check,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.resolver,
+                &client_builder.subcommand(vec!["outdated".to_owned()]),
+                script,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Export(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::ExportSettings::resolve(args, filesystem, environment);
@@ -2757,6 +3144,7 @@ async fn run_project(
                 args.hashes,
                 args.install_options,
                 args.output_file,
+                args.python_platforms,
                 args.extras,
                 args.groups,
                 args.editable,
@@ -2896,8 +3284,9 @@ async fn run_project(
                 args.output_format,
                 args.service_format,
                 args.service_url,
-                args.ignore,
-                args.ignore_until_fixed,
+                args.update_db,
+                args.fix,
+                args.policy,
             ))
             .await
         }
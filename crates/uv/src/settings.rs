@@ -10,17 +10,18 @@ use rustc_hash::FxHashSet;
 
 use uv_audit::{VulnerabilityID, VulnerabilityServiceFormat};
 use uv_auth::Service;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, Refresh, RemoteCache};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuditArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs,
-    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
-    VersionFormat,
+    CacheFormat, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, InspectArgs, ListFormat,
+    LockArgs, LockCommand, LockDiffFormat, LockMergeDriverArgs, Maybe, MetadataArgs, OutdatedArgs,
+    OutdatedFormat, PipAuditArgs, PipCheckArgs, PipCompileArgs, PipDownloadArgs, PipFreezeArgs,
+    PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
+    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
+    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
+    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, TreeFormat,
+    UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec, VersionFormat, WhyArgs, WhyFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
@@ -56,8 +57,8 @@ use uv_resolver::{
     ForkStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
-    Combine, EnvironmentOptions, FilesystemOptions, MalwareCheckSettings, Options, PipOptions,
-    PreviewFeaturesOption, PreviewOption, PublishOptions, PythonInstallMirrors,
+    AuditOptions, Combine, EnvironmentOptions, FilesystemOptions, MalwareCheckSettings, Options,
+    PipOptions, PreviewFeaturesOption, PreviewOption, PublishOptions, PythonInstallMirrors,
     ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
 };
 use uv_static::EnvVars;
@@ -68,7 +69,7 @@ use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::{
-    InitKind, InitProjectKind, PythonUpgrade, PythonUpgradeSource, ToolRunCommand,
+    AuditPolicy, InitKind, InitProjectKind, PythonUpgrade, PythonUpgradeSource, ToolRunCommand,
 };
 
 /// The default publish URL.
@@ -127,6 +128,16 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                environment
+                    .concurrency
+                    .download_ranges
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_DOWNLOAD_RANGES),
+                environment
+                    .concurrency
+                    .runs
+                    .map(NonZeroUsize::get)
+                    .unwrap_or_else(Concurrency::threads),
             ),
             show_settings: args.show_settings,
             preview: resolve_preview(args, workspace, environment),
@@ -415,6 +426,7 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) remote_cache: Option<RemoteCache>,
 }
 
 impl CacheSettings {
@@ -428,6 +440,63 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            remote_cache: args
+                .remote_cache
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.remote_cache.clone())),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache inspect` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheInspectSettings {
+    pub(crate) package: PackageName,
+    pub(crate) human: bool,
+    pub(crate) output_format: CacheFormat,
+    /// The configured indexes and `--find-links` locations, used to identify the source of each
+    /// cache entry.
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl CacheInspectSettings {
+    /// Resolve the [`CacheInspectSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: InspectArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let InspectArgs {
+            package,
+            human,
+            output_format,
+        } = args;
+
+        let indexes = filesystem
+            .map(|filesystem| {
+                let Options { top_level, pip, .. } = filesystem.into_options();
+                let pip = pip.unwrap_or_default();
+                top_level
+                    .index
+                    .into_iter()
+                    .flatten()
+                    .chain(
+                        top_level
+                            .extra_index_url
+                            .into_iter()
+                            .flatten()
+                            .map(Index::from),
+                    )
+                    .chain(top_level.index_url.into_iter().map(Index::from))
+                    .chain(top_level.find_links.into_iter().flatten().map(Index::from))
+                    .chain(pip.index.into_iter().flatten())
+                    .chain(pip.extra_index_url.into_iter().flatten().map(Index::from))
+                    .chain(pip.index_url.into_iter().map(Index::from))
+                    .chain(pip.find_links.into_iter().flatten().map(Index::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            package,
+            human,
+            output_format,
+            indexes,
         }
     }
 }
@@ -667,6 +736,26 @@ fn resolve_lock_check(flag: Flag) -> LockCheck {
     }
 }
 
+/// Resolve the [`AuditPolicy`] from the CLI ignore rules and the `[tool.uv.audit]` configuration.
+fn resolve_audit_policy(
+    mut ignore: Vec<String>,
+    mut ignore_until_fixed: Vec<String>,
+    filesystem: AuditOptions,
+) -> AuditPolicy {
+    ignore.extend(filesystem.ignore.unwrap_or_default());
+    ignore_until_fixed.extend(filesystem.ignore_until_fixed.unwrap_or_default());
+    AuditPolicy {
+        ignore: ignore.into_iter().map(VulnerabilityID::new).collect(),
+        ignore_until_fixed: ignore_until_fixed
+            .into_iter()
+            .map(VulnerabilityID::new)
+            .collect(),
+        suppressions: filesystem.suppress.unwrap_or_default(),
+        severity_threshold: filesystem.severity_threshold,
+        fail_on_status: filesystem.fail_on_status.unwrap_or_default(),
+    }
+}
+
 /// The resolved settings to use for a `run` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct RunSettings {
@@ -682,6 +771,8 @@ pub(crate) struct RunSettings {
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
+    pub(crate) fail_fast: bool,
+    pub(crate) watch: Vec<PathBuf>,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
@@ -730,6 +821,8 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            task: _,
+            watch,
             command: _,
             with,
             with_editable,
@@ -744,6 +837,7 @@ impl RunSettings {
             build,
             refresh,
             all_packages,
+            fail_fast,
             package,
             no_project,
             python,
@@ -840,6 +934,8 @@ impl RunSettings {
             isolated,
             show_resolution,
             all_packages,
+            fail_fast,
+            watch,
             package,
             no_project,
             no_sync: no_sync.is_enabled(),
@@ -1993,6 +2089,9 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) diff: Option<String>,
+    pub(crate) diff_format: LockDiffFormat,
+    pub(crate) merge_driver: Option<LockMergeDriverSettings>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -2012,11 +2111,14 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            diff,
+            diff_format,
             script,
             resolver,
             build,
             refresh,
             python,
+            command,
         } = args;
 
         let filesystem_install_mirrors = filesystem
@@ -2024,6 +2126,18 @@ impl LockSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
+        let merge_driver = command.map(|command| match command {
+            LockCommand::MergeDriver(LockMergeDriverArgs {
+                base,
+                current,
+                other,
+            }) => LockMergeDriverSettings {
+                base,
+                current,
+                other,
+            },
+        });
+
         // Resolve flags from CLI and environment variables.
         let locked = resolve_flag(locked, "locked", environment.locked);
         let frozen = resolve_flag(check_exists, "frozen", environment.frozen);
@@ -2041,6 +2155,9 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            diff,
+            diff_format,
+            merge_driver,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -2056,10 +2173,19 @@ impl LockSettings {
     }
 }
 
+/// The lockfiles to merge in a `lock merge-driver` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct LockMergeDriverSettings {
+    pub(crate) base: PathBuf,
+    pub(crate) current: PathBuf,
+    pub(crate) other: PathBuf,
+}
+
 /// The resolved settings to use for an `upgrade` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct UpgradeSettings {
-    pub(crate) package: PackageName,
+    /// The package to upgrade, or `None` to select the packages interactively.
+    pub(crate) package: Option<PackageName>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) settings: ResolverSettings,
 }
@@ -2075,10 +2201,15 @@ impl UpgradeSettings {
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
-        let package = args.package;
+        let UpgradeArgs {
+            package,
+            interactive: _,
+        } = args;
         let mut settings =
             ResolverSettings::combine(ResolverOptions::default(), filesystem, &environment);
-        settings.upgrade = Upgrade::package(package.clone());
+        if let Some(package) = &package {
+            settings.upgrade = Upgrade::package(package.clone());
+        }
 
         Self {
             package,
@@ -2177,6 +2308,7 @@ pub(crate) struct AddSettings {
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw: bool,
     pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) bump_all: bool,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -2221,6 +2353,7 @@ impl AddSettings {
             extra,
             raw,
             bounds,
+            bump_all,
             rev,
             tag,
             branch,
@@ -2413,6 +2546,7 @@ impl AddSettings {
             dependency_type,
             raw,
             bounds,
+            bump_all,
             rev,
             tag,
             branch,
@@ -2656,6 +2790,7 @@ pub(crate) struct TreeSettings {
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
     pub(crate) show_sizes: bool,
+    pub(crate) output_format: TreeFormat,
     #[expect(dead_code)]
     pub(crate) script: Option<PathBuf>,
     pub(crate) python_version: Option<PythonVersion>,
@@ -2739,6 +2874,7 @@ impl TreeSettings {
             invert: tree.invert,
             outdated: tree.outdated,
             show_sizes: tree.show_sizes,
+            output_format: tree.output_format,
             script,
             python_version,
             python_platform,
@@ -2755,6 +2891,132 @@ impl TreeSettings {
     }
 }
 
+/// The resolved settings to use for a `why` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WhySettings {
+    pub(crate) package: PackageName,
+    pub(crate) output_format: WhyFormat,
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    #[expect(dead_code)]
+    pub(crate) script: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl WhySettings {
+    /// Resolve the [`WhySettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(
+        args: WhyArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let WhyArgs {
+            package,
+            output_format,
+            locked,
+            frozen,
+            build,
+            resolver,
+            script,
+            python,
+        } = args;
+
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_flag(locked, "locked", environment.locked);
+        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen);
+
+        Self {
+            package,
+            output_format,
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            script,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        }
+    }
+}
+
+/// The resolved settings to use for an `outdated` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct OutdatedSettings {
+    pub(crate) output_format: OutdatedFormat,
+    pub(crate) direct: bool,
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    #[expect(dead_code)]
+    pub(crate) script: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl OutdatedSettings {
+    /// Resolve the [`OutdatedSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(
+        args: OutdatedArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let OutdatedArgs {
+            output_format,
+            direct,
+            locked,
+            frozen,
+            build,
+            resolver,
+            script,
+            python,
+        } = args;
+
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_flag(locked, "locked", environment.locked);
+        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen);
+
+        Self {
+            output_format,
+            direct,
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            script,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(
+                resolver_options(resolver, build),
+                filesystem,
+                &environment,
+            ),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        }
+    }
+}
+
 /// The resolved settings to use for an `export` invocation.
 #[expect(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -2769,6 +3031,7 @@ pub(crate) struct ExportSettings {
     pub(crate) hashes: bool,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) include_annotations: bool,
@@ -2820,6 +3083,7 @@ impl ExportSettings {
             hashes,
             no_hashes,
             output_file,
+            python_platform,
             no_emit_project,
             only_emit_project,
             no_emit_workspace,
@@ -2909,6 +3173,7 @@ impl ExportSettings {
                 only_emit_package,
             ),
             output_file,
+            python_platforms: python_platform,
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             include_annotations: flag(annotate, no_annotate, "annotate").unwrap_or(true),
@@ -3115,8 +3380,9 @@ pub(crate) struct AuditSettings {
     pub(crate) output_format: AuditOutputFormat,
     pub(crate) service_format: VulnerabilityServiceFormat,
     pub(crate) service_url: Option<String>,
-    pub(crate) ignore: Vec<VulnerabilityID>,
-    pub(crate) ignore_until_fixed: Vec<VulnerabilityID>,
+    pub(crate) update_db: bool,
+    pub(crate) fix: bool,
+    pub(crate) policy: AuditPolicy,
 }
 
 impl AuditSettings {
@@ -3145,6 +3411,8 @@ impl AuditSettings {
             ignore_until_fixed,
             service_format,
             service_url,
+            update_db,
+            fix,
         } = args;
 
         let filesystem_install_mirrors = filesystem
@@ -3205,19 +3473,9 @@ impl AuditSettings {
             output_format,
             service_format,
             service_url,
-            ignore: {
-                let config_ignore = filesystem_audit.ignore.unwrap_or_default();
-                let mut merged = ignore;
-                merged.extend(config_ignore);
-                merged.into_iter().map(VulnerabilityID::new).collect()
-            },
-            ignore_until_fixed: {
-                let config_ignore_until_fixed =
-                    filesystem_audit.ignore_until_fixed.unwrap_or_default();
-                let mut merged = ignore_until_fixed;
-                merged.extend(config_ignore_until_fixed);
-                merged.into_iter().map(VulnerabilityID::new).collect()
-            },
+            update_db,
+            fix,
+            policy: resolve_audit_policy(ignore, ignore_until_fixed, filesystem_audit),
         }
     }
 }
@@ -3751,6 +4009,126 @@ impl PipInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip download` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipDownloadSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Override<Requirement>>,
+    pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) dest: Option<PathBuf>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipDownloadArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipDownloadArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            dest,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
+        } = args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = workspace_overrides(filesystem.as_ref());
+
+        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .build_constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            constraints_from_workspace,
+            overrides_from_workspace,
+            build_constraints_from_workspace,
+            dest,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    no_deps: flag(no_deps, deps, "deps"),
+                    python_version,
+                    python_platform,
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipUninstallSettings {
@@ -3970,6 +4348,7 @@ pub(crate) struct PipTreeSettings {
     pub(crate) no_dedupe: bool,
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
+    pub(crate) output_format: TreeFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -4000,6 +4379,7 @@ impl PipTreeSettings {
             invert: tree.invert,
             package: tree.package,
             outdated: tree.outdated,
+            output_format: tree.output_format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -4051,6 +4431,60 @@ impl PipCheckSettings {
     }
 }
 
+/// The resolved settings to use for a `pip audit` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipAuditSettings {
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) output_format: AuditOutputFormat,
+    pub(crate) service_format: VulnerabilityServiceFormat,
+    pub(crate) service_url: Option<String>,
+    pub(crate) policy: AuditPolicy,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipAuditSettings {
+    /// Resolve the [`PipAuditSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: PipAuditArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipAuditArgs {
+            requirements,
+            python,
+            system,
+            no_system,
+            output_format,
+            ignore,
+            ignore_until_fixed,
+            service_format,
+            service_url,
+        } = args;
+
+        let filesystem_audit = filesystem
+            .as_ref()
+            .and_then(|fs| fs.audit.clone())
+            .unwrap_or_default();
+
+        Self {
+            requirements,
+            output_format,
+            service_format,
+            service_url,
+            policy: resolve_audit_policy(ignore, ignore_until_fixed, filesystem_audit),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    ..PipOptions::default()
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `build` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct BuildSettings {
@@ -4061,6 +4495,7 @@ pub(crate) struct BuildSettings {
     pub(crate) sdist: bool,
     pub(crate) wheel: bool,
     pub(crate) list: bool,
+    pub(crate) verify_reproducible: bool,
     pub(crate) build_logs: bool,
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
@@ -4090,6 +4525,7 @@ impl BuildSettings {
             wheel,
             list,
             force_pep517,
+            verify_reproducible,
             clear,
             build_constraints,
             require_hashes,
@@ -4131,6 +4567,7 @@ impl BuildSettings {
             sdist,
             wheel,
             list,
+            verify_reproducible,
             build_logs: flag(build_logs, no_build_logs, "build-logs").unwrap_or(true),
             force_pep517,
             clear,
@@ -5164,7 +5601,8 @@ mod tests {
         let package = PackageName::from_str("anyio")?;
         let settings = UpgradeSettings::resolve(
             UpgradeArgs {
-                package: package.clone(),
+                package: Some(package.clone()),
+                interactive: false,
             },
             None,
             EnvironmentOptions::new()?,
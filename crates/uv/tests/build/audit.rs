@@ -1,5 +1,6 @@
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use indoc::{formatdoc, indoc};
 use serde_json::json;
@@ -274,6 +275,246 @@ async fn audit_vulnerability_found() {
     ");
 }
 
+/// Audit a project against a local OSV data dump.
+#[tokio::test]
+async fn audit_osv_dump() {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+    write_audit_output_project(&context.temp_dir, &proxy.url("/simple"));
+
+    let database = context.temp_dir.child("osv");
+    database
+        .child("PYSEC-2023-0001.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0001",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "A test vulnerability in iniconfig",
+                "affected": [{
+                    "package": {"name": "iniconfig", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [
+                            {"introduced": "0"},
+                            {"fixed": "2.1.0"}
+                        ]
+                    }]
+                }],
+                "references": [{
+                    "type": "ADVISORY",
+                    "url": "https://example.com/advisory/PYSEC-2023-0001"
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    // A vulnerability that was fixed before the locked version.
+    database
+        .child("PYSEC-2020-0002.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2020-0002",
+                "modified": "2026-01-01T00:00:00Z",
+                "affected": [{
+                    "package": {"name": "iniconfig", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [
+                            {"introduced": "0"},
+                            {"fixed": "1.1.0"}
+                        ]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    iniconfig 2.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0001: A test vulnerability in iniconfig
+
+      Fixed in: 2.1.0
+
+      Advisory information: https://example.com/advisory/PYSEC-2023-0001
+
+
+    ----- stderr -----
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    ");
+
+    // Without a database, ask the user to download it.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No OSV database found at `[CACHE_DIR]/osv-v0/dump/PyPI`, run `uv audit --update-db` to download it
+    ");
+}
+
+/// Write a project depending on `ok` from the local `test/links` index, and an OSV database with
+/// a vulnerability in `ok` fixed in 2.0.0.
+fn write_audit_fix_project(context: &uv_test::TestContext, requirement: &str) -> ChildPath {
+    let links = context.workspace_root.join("test/links");
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["{requirement}"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ['{}']
+    "#, links.display()})
+        .unwrap();
+
+    let database = context.temp_dir.child("osv");
+    database
+        .child("PYSEC-2023-0003.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0003",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "A test vulnerability in ok",
+                "affected": [{
+                    "package": {"name": "ok", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [
+                            {"introduced": "0"},
+                            {"fixed": "2.0.0"}
+                        ]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    database
+}
+
+/// Fix a vulnerability by upgrading the package to the fixed version.
+#[test]
+fn audit_fix() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_fix_project(&context, "ok");
+
+    // Lock the vulnerable version.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("ok<2")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--fix")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Resolved 2 packages in [TIME]
+    Updated ok v1.0.0 -> v2.0.0
+    Found no known vulnerabilities and no adverse project statuses in 1 package
+    ");
+
+    // The vulnerability is fixed in the lockfile.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found no known vulnerabilities and no adverse project statuses in 1 package
+    ");
+}
+
+/// Report a vulnerability that can't be fixed due to the project's requirements.
+#[test]
+fn audit_fix_conflict() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_fix_project(&context, "ok<2");
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--fix")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0003: A test vulnerability in ok
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × No solution found when resolving dependencies:
+      ╰─▶ Because your project depends on ok<2 and ok>=2.0.0, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: `ok` could not be upgraded to >=2.0.0 to fix PYSEC-2023-0003
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    ");
+}
+
 /// Audit a project when OSV returns a malformed vulnerability record.
 #[tokio::test]
 async fn audit_malformed_vulnerability_record() {
@@ -2625,3 +2866,626 @@ async fn audit_sarif_project_artifact_uri() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a project locked to `ok` 1.0.0, with an additional vulnerability in `ok` to be ignored.
+fn write_audit_vex_project(context: &uv_test::TestContext) -> ChildPath {
+    let database = write_audit_fix_project(context, "ok<2");
+    database
+        .child("PYSEC-2023-0004.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0004",
+                "aliases": ["CVE-2023-0004"],
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "An ignored vulnerability in ok",
+                "affected": [{
+                    "package": {"name": "ok", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{"introduced": "0"}]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    context.lock().assert().success();
+    database
+}
+
+/// Emit a CycloneDX VEX document, with ignored vulnerabilities reported as `not_affected`.
+#[test]
+fn audit_cyclonedx() {
+    let context = uv_test::test_context!("3.12").with_cyclonedx_filters();
+    let database = write_audit_vex_project(&context);
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--output-format")
+        .arg("cyclonedx1.5")
+        .arg("--ignore")
+        .arg("CVE-2023-0004")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": [
+          {
+            "vendor": "Astral Software Inc.",
+            "name": "uv",
+            "version": "[VERSION]"
+          }
+        ],
+        "component": {
+          "type": "library",
+          "bom-ref": "project-3",
+          "name": "project",
+          "properties": [
+            {
+              "name": "uv:package:is_synthetic_root",
+              "value": "true"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "ok-2@1.0.0",
+          "name": "ok",
+          "version": "1.0.0",
+          "purl": "pkg:pypi/ok@1.0.0"
+        },
+        {
+          "type": "library",
+          "bom-ref": "project-1@0.1.0",
+          "name": "project",
+          "version": "0.1.0",
+          "properties": [
+            {
+              "name": "uv:package:is_project_root",
+              "value": "true"
+            }
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "ok-2@1.0.0"
+        },
+        {
+          "ref": "project-1@0.1.0",
+          "dependsOn": [
+            "ok-2@1.0.0"
+          ]
+        },
+        {
+          "ref": "project-3",
+          "dependsOn": [
+            "project-1@0.1.0"
+          ]
+        }
+      ],
+      "vulnerabilities": [
+        {
+          "id": "PYSEC-2023-0003",
+          "source": {
+            "name": "OSV",
+            "url": "https://osv.dev/vulnerability/PYSEC-2023-0003"
+          },
+          "description": "A test vulnerability in ok",
+          "recommendation": "Upgrade ok to 2.0.0 or later.",
+          "updated": "2026-01-01T00:00:00Z",
+          "affects": [
+            {
+              "ref": "ok-2@1.0.0",
+              "versions": [
+                {
+                  "version": "1.0.0",
+                  "status": "affected"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "id": "PYSEC-2023-0004",
+          "source": {
+            "name": "OSV",
+            "url": "https://osv.dev/vulnerability/PYSEC-2023-0004"
+          },
+          "references": [
+            {
+              "id": "CVE-2023-0004",
+              "source": {
+                "name": "OSV",
+                "url": "https://osv.dev/vulnerability/CVE-2023-0004"
+              }
+            }
+          ],
+          "description": "An ignored vulnerability in ok",
+          "updated": "2026-01-01T00:00:00Z",
+          "analysis": {
+            "state": "not_affected",
+            "detail": "The vulnerability is ignored in the audit configuration."
+          },
+          "affects": [
+            {
+              "ref": "ok-2@1.0.0",
+              "versions": [
+                {
+                  "version": "1.0.0",
+                  "status": "unaffected"
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+/// Emit an `OpenVEX` document, with ignored vulnerabilities reported as `not_affected`.
+#[test]
+fn audit_openvex() {
+    let context = uv_test::test_context!("3.12").with_cyclonedx_filters();
+    let database = write_audit_vex_project(&context);
+
+    let mut filters = context.filters();
+    filters.push((r#""tooling": "uv [^"]+""#, r#""tooling": "uv [VERSION]""#));
+
+    uv_snapshot!(filters, context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--output-format")
+        .arg("openvex")
+        .arg("--ignore")
+        .arg("CVE-2023-0004")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "@context": "https://openvex.dev/ns/v0.2.0",
+      "@id": "[SERIAL_NUMBER]",
+      "author": "uv",
+      "timestamp": "[TIMESTAMP]",
+      "version": 1,
+      "tooling": "uv [VERSION]",
+      "statements": [
+        {
+          "vulnerability": {
+            "@id": "https://osv.dev/vulnerability/PYSEC-2023-0003",
+            "name": "PYSEC-2023-0003",
+            "description": "A test vulnerability in ok"
+          },
+          "products": [
+            {
+              "@id": "pkg:pypi/ok@1.0.0"
+            }
+          ],
+          "status": "affected",
+          "action_statement": "Upgrade ok to 2.0.0 or later."
+        },
+        {
+          "vulnerability": {
+            "@id": "https://osv.dev/vulnerability/PYSEC-2023-0004",
+            "name": "PYSEC-2023-0004",
+            "description": "An ignored vulnerability in ok",
+            "aliases": [
+              "CVE-2023-0004"
+            ]
+          },
+          "products": [
+            {
+              "@id": "pkg:pypi/ok@1.0.0"
+            }
+          ],
+          "status": "not_affected",
+          "impact_statement": "The vulnerability is ignored in the audit configuration."
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+/// Append a `[tool.uv.audit]` section to the project's `pyproject.toml`.
+fn append_audit_config(context: &uv_test::TestContext, config: &str) {
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let contents = fs_err::read_to_string(&pyproject_toml).unwrap();
+    pyproject_toml
+        .write_str(&format!("{contents}\n[tool.uv.audit]\n{config}"))
+        .unwrap();
+}
+
+/// `[tool.uv.audit]` config supports `suppress`, scoped to a package.
+#[test]
+fn audit_suppress_config() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_vex_project(&context);
+    append_audit_config(
+        &context,
+        indoc! {r#"
+        suppress = [
+            { id = "CVE-2023-0004", reason = "The vulnerable code is never called", package = "ok", expires = "2999-12-31" },
+            { id = "PYSEC-2023-0003", reason = "Only affects other packages", package = "other" },
+        ]
+    "#},
+    );
+
+    // The first suppression matches by alias, while the second is scoped to another package.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0003: A test vulnerability in ok
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+
+    ----- stderr -----
+    warning: Suppressed vulnerability `PYSEC-2023-0003` does not match any vulnerability in `other`
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    ");
+}
+
+/// An expired suppression no longer applies.
+#[test]
+fn audit_suppress_expired() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_vex_project(&context);
+    append_audit_config(
+        &context,
+        indoc! {r#"
+        suppress = [
+            { id = "PYSEC-2023-0004", reason = "Waiting on a fix upstream", expires = "2000-01-01" },
+        ]
+    "#},
+    );
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 2 known vulnerabilities:
+
+    - PYSEC-2023-0003: A test vulnerability in ok
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+    - PYSEC-2023-0004: An ignored vulnerability in ok
+
+      No fix versions available
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0004
+
+
+    ----- stderr -----
+    warning: The suppression of `PYSEC-2023-0004` expired on 2000-01-01, and is no longer applied
+    Found 2 known vulnerabilities and no adverse project statuses in 1 package
+    ");
+}
+
+/// The reason for a suppression is reported in VEX documents.
+#[test]
+fn audit_suppress_openvex() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_vex_project(&context);
+    append_audit_config(
+        &context,
+        indoc! {r#"
+        suppress = [
+            { id = "PYSEC-2023-0004", reason = "The vulnerable code is never called" },
+        ]
+    "#},
+    );
+
+    let output = context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--output-format")
+        .arg("openvex")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path())
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let statement = report["statements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|statement| statement["vulnerability"]["name"] == "PYSEC-2023-0004")
+        .unwrap();
+    assert_eq!(statement["status"], "not_affected");
+    assert_eq!(
+        statement["impact_statement"],
+        "The vulnerable code is never called"
+    );
+}
+
+/// A suppression without a reason is rejected, and the configuration is ignored with a warning.
+#[test]
+fn audit_suppress_missing_reason() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_vex_project(&context);
+    append_audit_config(
+        &context,
+        indoc! {r#"
+        suppress = [{ id = "PYSEC-2023-0004" }]
+    "#},
+    );
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 2 known vulnerabilities:
+
+    - PYSEC-2023-0003: A test vulnerability in ok
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+    - PYSEC-2023-0004: An ignored vulnerability in ok
+
+      No fix versions available
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0004
+
+
+    ----- stderr -----
+    warning: Failed to parse `pyproject.toml` during settings discovery:
+      TOML parse error at line 12, column 13
+         |
+      12 | suppress = [{ id = "PYSEC-2023-0004" }]
+         |             ^^^^^^^^^^^^^^^^^^^^^^^^^^
+      missing field `reason`
+
+    Found 2 known vulnerabilities and no adverse project statuses in 1 package
+    "#);
+}
+
+/// Only vulnerabilities at or above the `severity-threshold` fail the audit.
+#[test]
+fn audit_severity_threshold() {
+    let context = uv_test::test_context!("3.12");
+    let database = write_audit_fix_project(&context, "ok<2");
+    database
+        .child("PYSEC-2023-0003.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0003",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "A critical vulnerability in ok",
+                "severity": [
+                    {"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"}
+                ],
+                "affected": [{
+                    "package": {"name": "ok", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{"introduced": "0"}, {"fixed": "2.0.0"}]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    database
+        .child("PYSEC-2023-0004.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0004",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "A medium vulnerability in ok",
+                "severity": [
+                    {"type": "CVSS_V3", "score": "CVSS:3.1/AV:L/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N"}
+                ],
+                "affected": [{
+                    "package": {"name": "ok", "ecosystem": "PyPI"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{"introduced": "0"}]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    context.lock().assert().success();
+    append_audit_config(&context, r#"severity-threshold = "high""#);
+
+    // The critical vulnerability fails the audit.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 2 known vulnerabilities:
+
+    - PYSEC-2023-0003: A critical vulnerability in ok
+
+      Severity: critical (CVSS 9.8)
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+    - PYSEC-2023-0004: A medium vulnerability in ok
+
+      Severity: medium (CVSS 5.5)
+
+      No fix versions available
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0004
+
+
+    ----- stderr -----
+    Found 2 known vulnerabilities and no adverse project statuses in 1 package
+    ");
+
+    // The medium vulnerability is reported, but doesn't fail the audit.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--ignore")
+        .arg("PYSEC-2023-0003")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0004: A medium vulnerability in ok
+
+      Severity: medium (CVSS 5.5)
+
+      No fix versions available
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0004
+
+
+    ----- stderr -----
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    ");
+}
+
+/// `[tool.uv.audit]` config supports `fail-on-status`.
+#[tokio::test]
+async fn audit_fail_on_status() {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+
+        [tool.uv.audit]
+        fail-on-status = ["quarantined"]
+    "#, proxy.url("/status/quarantined/reason/suspected-malware/simple")})
+        .unwrap();
+
+    context.lock().assert().success();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/querybatch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{"vulns": []}]
+        })))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--service-url")
+        .arg(server.uri()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Adverse statuses:
+
+    - iniconfig is quarantined: suspected-malware
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Found no known vulnerabilities and 1 adverse project status in 1 package
+    ");
+}
@@ -2214,6 +2214,61 @@ fn build_fast_path() -> Result<()> {
     Ok(())
 }
 
+/// Build twice and compare the archives with `--verify-reproducible`.
+#[test]
+fn build_verify_reproducible() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let built_by_uv = current_dir()?.join("../../test/packages/built-by-uv");
+
+    uv_snapshot!(context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output"))
+        .arg("--verify-reproducible")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Verified that built_by_uv-0.1.0.tar.gz is reproducible
+    Verified that built_by_uv-0.1.0-py3-none-any.whl is reproducible
+    Successfully built output/built_by_uv-0.1.0.tar.gz
+    Successfully built output/built_by_uv-0.1.0-py3-none-any.whl
+    ");
+
+    // The archives use the modification time from `SOURCE_DATE_EPOCH`.
+    let wheel = context
+        .temp_dir
+        .child("output")
+        .child("built_by_uv-0.1.0-py3-none-any.whl");
+    let years = block_on(async {
+        let wheel = ZipFileReader::new(fs_err::read(wheel.path())?).await?;
+        let years: Vec<_> = wheel
+            .file()
+            .entries()
+            .iter()
+            .map(|entry| entry.last_modification_date().year())
+            .collect();
+        anyhow::Ok(years)
+    })?;
+    assert!(!years.is_empty());
+    assert!(years.iter().all(|year| *year == 2023), "{years:?}");
+
+    context
+        .temp_dir
+        .child("output")
+        .child("built_by_uv-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
 /// Reject path-shaped script entry point names before writing wheel metadata.
 #[test]
 fn build_unsafe_script_entry_point_name() -> Result<()> {
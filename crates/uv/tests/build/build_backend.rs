@@ -1616,6 +1616,167 @@ fn warn_on_license_classifier() -> Result<()> {
     Ok(())
 }
 
+/// Read a dynamic version from git tags, and from `PKG-INFO` when building the wheel from the
+/// source distribution.
+#[test]
+fn dynamic_version_from_git() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        dynamic = ["version"]
+
+        [tool.uv.build-backend]
+        version = { source = "git" }
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context.temp_dir.child("src/foo/__init__.py").touch()?;
+
+    let git = |args: &[&str]| -> Result<()> {
+        let status = Command::new("git")
+            .args(["-c", "user.name=uv", "-c", "user.email=uv@example.com"])
+            .args(["-c", "commit.gpgsign=false", "-c", "tag.gpgsign=false"])
+            .args(args)
+            .current_dir(context.temp_dir.path())
+            .status()?;
+        assert!(status.success());
+        Ok(())
+    };
+    git(&["init", "--quiet"])?;
+    git(&["add", "pyproject.toml", "src"])?;
+    git(&["commit", "--quiet", "-m", "Initial commit"])?;
+    git(&["tag", "v1.2.3"])?;
+
+    uv_snapshot!(context.filters(), context.build(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built dist/foo-1.2.3.tar.gz
+    Successfully built dist/foo-1.2.3-py3-none-any.whl
+    ");
+
+    let extracted = context.temp_dir.child("extracted");
+    unpack_tar_gz(
+        &context.temp_dir.join("dist").join("foo-1.2.3.tar.gz"),
+        extracted.path(),
+    )?;
+    let pkg_info = fs_err::read_to_string(extracted.join("foo-1.2.3").join("PKG-INFO"))?;
+    assert!(pkg_info.contains("\nVersion: 1.2.3\n"), "{pkg_info}");
+
+    Ok(())
+}
+
+/// Build a wheel with a build hook through the Python shim of the PEP 517 interface.
+///
+/// We don't have a `uv_build` wheel, so we call the shim with the `uv build-backend` command.
+#[test]
+fn build_hook() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+
+        [tool.uv.build-backend]
+        build-hook = { function = "build_extensions:build", requires = ["cython>=3"] }
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    context.temp_dir.child("src/foo/__init__.py").touch()?;
+    // Instead of compiling an extension module, write a file with the config settings.
+    context
+        .temp_dir
+        .child("build_extensions.py")
+        .write_str(indoc! {r#"
+        import os
+
+        def build(output_directory, config_settings):
+            os.makedirs(os.path.join(output_directory, "foo"), exist_ok=True)
+            with open(os.path.join(output_directory, "foo", "_native.py"), "w") as f:
+                f.write(f"CONFIG_SETTINGS = {config_settings!r}\n")
+        "#})?;
+
+    // Without the shim, the build backend can't build the wheel.
+    uv_snapshot!(context.filters(), context.build_backend().arg("build-wheel").arg("dist").current_dir(&context.temp_dir), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The project has a build hook (`tool.uv.build-backend.build-hook`), which is only supported when building through the PEP 517 interface of `uv_build`
+    ");
+
+    let shim =
+        std::path::absolute(Path::new(env!("CARGO_MANIFEST_DIR")).join("../uv-build/python"))?;
+    let path = std::env::join_paths(
+        std::iter::once(uv_test::get_bin!().parent().unwrap().to_path_buf()).chain(
+            std::env::split_paths(&std::env::var_os(EnvVars::PATH).unwrap_or_default()),
+        ),
+    )?;
+    let script = formatdoc! {r#"
+        import os
+        import sys
+        import zipfile
+
+        sys.path.insert(0, {shim:?})
+        import uv_build
+
+        uv_build.USE_UV_EXECUTABLE = True
+
+        print(uv_build.get_requires_for_build_wheel())
+        os.makedirs("dist")
+        wheel = uv_build.build_wheel("dist", {{"key": "value"}})
+        assert wheel == f"foo-0.1.0-{{uv_build.wheel_tag()}}.whl", wheel
+        with zipfile.ZipFile(f"dist/{{wheel}}") as archive:
+            print(archive.read("foo/_native.py").decode().strip())
+            for line in archive.read("foo-0.1.0.dist-info/WHEEL").decode().splitlines():
+                if line.startswith("Root-Is-Purelib"):
+                    print(line)
+
+        editable = uv_build.build_editable("dist")
+        assert editable == wheel, editable
+        with open("src/foo/_native.py") as f:
+            print(f.read().strip())
+        "#,
+        shim = shim.display().to_string(),
+    };
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg(script)
+        .env(EnvVars::PATH, path)
+        .current_dir(&context.temp_dir), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['cython>=3']
+    CONFIG_SETTINGS = {'key': 'value'}
+    Root-Is-Purelib: false
+    CONFIG_SETTINGS = {}
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
 /// Auto-detect TOML 1.1 features in `pyproject.toml` and warn the user.
 #[test]
 fn warn_on_toml_1_1_auto_detected() -> Result<()> {
@@ -0,0 +1,90 @@
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_fs::Simplified;
+use uv_test::uv_snapshot;
+
+/// Inspect the cached versions of a package.
+#[test]
+fn cache_inspect() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_inspect().arg("ok").arg("--preview-features").arg("cache-inspect"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found for ok
+    ");
+
+    for version in ["1.0.0", "2.0.0"] {
+        context
+            .pip_install()
+            .arg(format!("ok=={version}"))
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test/links"))
+            .assert()
+            .success();
+    }
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\([0-9a-f]{16}\)", "([DIGEST])"),
+            (r"\(\d+\)", "([SIZE])"),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.cache_inspect().arg("ok").arg("--preview-features").arg("cache-inspect"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok
+      v1.0.0  index ([DIGEST])  wheels-v6, archive-v0 ([SIZE])
+      v2.0.0  index ([DIGEST])  wheels-v6, archive-v0 ([SIZE])
+
+    ----- stderr -----
+    ");
+}
+
+/// Identify the source of cache entries using the configured `--find-links` locations.
+#[test]
+fn cache_inspect_configured_source() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let links = context.workspace_root.join("test/links");
+    let find_links = links.portable_display().to_string();
+    fs_err::write(
+        context.temp_dir.join("uv.toml"),
+        format!("find-links = [\"{find_links}\"]\nno-index = true\n"),
+    )?;
+
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--config-file")
+        .arg(context.temp_dir.join("uv.toml"))
+        .assert()
+        .success();
+
+    let output = context
+        .cache_inspect()
+        .arg("ok")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--config-file")
+        .arg(context.temp_dir.join("uv.toml"))
+        .output()?;
+    let inspect: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(inspect["versions"][0]["version"], "1.0.0");
+    assert!(
+        inspect["versions"][0]["source"]["url"]
+            .as_str()
+            .is_some_and(|url| url.ends_with("test/links")),
+        "{inspect}"
+    );
+
+    Ok(())
+}
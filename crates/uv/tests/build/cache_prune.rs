@@ -491,3 +491,66 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-age` and `--max-size` should evict the least-recently-used entries.
+#[test]
+fn prune_evict() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Install a requirement from a local directory, to populate the cache.
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((r"Removed \d+ files", "Removed [N] files")))
+        .collect();
+
+    // Every entry was used recently, so nothing is evicted.
+    uv_snapshot!(&filters, context
+        .prune()
+        .arg("--preview-features")
+        .arg("cache-eviction")
+        .arg("--max-age")
+        .arg("30d"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    // With a size limit of zero, every entry is evicted, along with the unzipped wheel.
+    uv_snapshot!(&filters, context
+        .prune()
+        .arg("--preview-features")
+        .arg("cache-eviction")
+        .arg("--max-size")
+        .arg("0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(
+        fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+            .next()
+            .is_none()
+    );
+
+    Ok(())
+}
@@ -57,3 +57,63 @@ fn cache_size_with_packages_human() {
     ----- stderr -----
     ");
 }
+
+/// Test that `cache size --buckets` breaks down the size by bucket and package.
+#[test]
+fn cache_size_buckets() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .assert()
+        .success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"(?m)  \d+$", "  [SIZE]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.cache_size().arg("--preview").arg("--buckets"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    wheels-v6        [SIZE]
+      ok             [SIZE]
+    interpreter-v4   [SIZE]
+    archive-v0       [SIZE]
+      ok             [SIZE]
+    other            [SIZE]
+    total            [SIZE]
+
+    ----- stderr -----
+    ");
+
+    // Remove the wheel, leaving its unzipped archive unreferenced.
+    fs_err::remove_dir_all(context.cache_dir.join("wheels-v6")).unwrap();
+
+    let output = context
+        .cache_size()
+        .arg("--preview")
+        .arg("--output-format")
+        .arg("json")
+        .output()
+        .unwrap();
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let archive = stats["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| bucket["name"] == "archive-v0")
+        .unwrap();
+    assert_eq!(archive["packages"][0]["name"], "ok");
+    assert_eq!(stats["unreferenced_archives"]["count"], 1);
+    assert_eq!(
+        stats["unreferenced_archives"]["size"],
+        archive["packages"][0]["size"]
+    );
+}
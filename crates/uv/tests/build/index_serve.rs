@@ -0,0 +1,228 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+
+use uv_static::EnvVars;
+use uv_test::{TestContext, uv_snapshot};
+
+/// A running `uv index serve` process, which is killed on drop.
+struct Server {
+    child: Child,
+    url: String,
+}
+
+impl Server {
+    /// Spawn the server, waiting until it reports the URL on which it's listening.
+    fn spawn(mut command: Command) -> Result<Self> {
+        let mut child = command
+            .arg("--preview-features")
+            .arg("index-serve")
+            .arg("--port")
+            .arg("0")
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let mut lines = BufReader::new(stderr).lines();
+        let url = loop {
+            let Some(line) = lines.next().transpose()? else {
+                anyhow::bail!("`uv index serve` exited before listening");
+            };
+            if let Some((_, url)) = line.split_once(" at ") {
+                break url.trim().to_string();
+            }
+        };
+
+        Ok(Self { child, url })
+    }
+
+    /// Fetch the JSON project page for the given package.
+    fn project(&self, name: &str) -> Result<serde_json::Value> {
+        let response = reqwest::blocking::Client::new()
+            .get(format!("{}{name}/", self.url))
+            .header("Accept", "application/vnd.pypi.simple.v1+json")
+            .send()?
+            .error_for_status()?;
+        Ok(response.json()?)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Return the filenames listed on a JSON project page.
+fn filenames(project: &serde_json::Value) -> Vec<&str> {
+    project["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| file["filename"].as_str().unwrap())
+        .collect()
+}
+
+/// Copy the distributions for the given package from `test/links` into `links`.
+fn copy_links(context: &TestContext, package: &str) -> Result<()> {
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    for entry in fs_err::read_dir(context.workspace_root.join("test/links"))? {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(&format!("{package}-")))
+        {
+            fs_err::copy(
+                entry.path(),
+                context.temp_dir.join("links").join(entry.file_name()),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Serve a directory of distributions, then install from it.
+#[test]
+fn index_serve_directory() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    copy_links(&context, "ok")?;
+    copy_links(&context, "tqdm")?;
+
+    let mut command = context.index_serve();
+    command.arg(context.temp_dir.child("links").path());
+    let server = Server::spawn(command)?;
+
+    // Wheels expose their core metadata; source distributions do not.
+    let project = server.project("tqdm")?;
+    assert_eq!(
+        filenames(&project),
+        [
+            "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl",
+            "tqdm-999.0.0.tar.gz",
+            "tqdm-1000.0.0-py3-none-any.whl",
+        ]
+    );
+    assert_eq!(
+        project["versions"],
+        serde_json::json!(["4.66.1", "999.0.0", "1000.0.0"])
+    );
+    assert!(project["files"][0]["core-metadata"]["sha256"].is_string());
+    assert_eq!(project["files"][1]["core-metadata"], false);
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(&server.url)
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Serve the wheels in the cache.
+#[test]
+fn index_serve_from_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    copy_links(&context, "ok")?;
+
+    // Populate the cache.
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("links").path())
+        .assert()
+        .success();
+
+    let mut command = context.index_serve();
+    command.arg("--from-cache");
+    let server = Server::spawn(command)?;
+
+    // The wheels are repacked on demand, so their hashes and sizes aren't known up front, but
+    // their core metadata is.
+    let project = server.project("ok")?;
+    assert_eq!(filenames(&project), ["ok-1.0.0-py3-none-any.whl"]);
+    assert_eq!(project["meta"]["api-version"], "1.0");
+    assert_eq!(project["files"][0]["hashes"], serde_json::json!({}));
+    assert!(project["files"][0]["size"].is_null());
+    assert!(project["files"][0]["core-metadata"]["sha256"].is_string());
+
+    // Install into a fresh environment, bypassing the cache.
+    context.venv().arg("--clear").assert().success();
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("ok")
+        .arg("--no-cache")
+        .arg("--index-url")
+        .arg(&server.url)
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Serve a distribution with a local version, whether or not the `+` is percent-encoded.
+#[test]
+fn index_serve_local_version() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        context
+            .temp_dir
+            .join("links")
+            .join("ok-1.0.0+local-py3-none-any.whl"),
+    )?;
+
+    let mut command = context.index_serve();
+    command.arg(context.temp_dir.child("links").path());
+    let server = Server::spawn(command)?;
+
+    let project = server.project("ok")?;
+    assert_eq!(filenames(&project), ["ok-1.0.0+local-py3-none-any.whl"]);
+    assert_eq!(
+        project["files"][0]["url"],
+        "/files/ok-1.0.0%2Blocal-py3-none-any.whl"
+    );
+
+    let base = reqwest::Url::parse(&server.url)?;
+    for path in [
+        "/files/ok-1.0.0+local-py3-none-any.whl",
+        "/files/ok-1.0.0%2Blocal-py3-none-any.whl",
+        "/files/ok-1.0.0%2blocal-py3-none-any.whl.metadata",
+    ] {
+        let response = reqwest::blocking::get(base.join(path)?)?;
+        assert_eq!(response.status(), reqwest::StatusCode::OK, "{path}");
+    }
+
+    Ok(())
+}
@@ -18,6 +18,9 @@ mod cache;
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_clean;
 
+#[cfg(feature = "test-python")]
+mod cache_inspect;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_prune;
 
@@ -25,3 +28,9 @@ mod cache_prune;
 mod cache_size;
 
 mod extract;
+
+#[cfg(feature = "test-python")]
+mod index_serve;
+
+#[cfg(feature = "test-python")]
+mod remote_cache;
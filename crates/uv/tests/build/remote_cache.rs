@@ -0,0 +1,111 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv_static::EnvVars;
+use uv_test::uv_snapshot;
+
+/// Share a wheel built from a source distribution through a directory-backed remote cache.
+#[test]
+fn remote_cache_directory() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Build a source distribution for a package that uses the uv build backend, such that
+    // building a wheel from it doesn't require network access.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    let links = context.temp_dir.child("links");
+    context
+        .build()
+        .arg("--sdist")
+        .arg("--out-dir")
+        .arg(links.path())
+        .arg(project.path())
+        .assert()
+        .success();
+
+    let remote = context.temp_dir.child("remote");
+    // On a miss, the wheel is built locally and uploaded to the remote cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("project")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--remote-cache")
+        .arg(remote.path())
+        .arg("--preview-features")
+        .arg("remote-cache")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0
+    ");
+
+    // Pure Python wheels are recorded in a manifest that's shared across platforms.
+    let manifests = walkdir::WalkDir::new(remote.path())
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name() == "py3-none-any.json")
+        .collect::<Vec<_>>();
+    assert_eq!(manifests.len(), 1);
+    let manifest: serde_json::Value =
+        serde_json::from_str(&fs_err::read_to_string(manifests[0].path())?)?;
+    assert_eq!(manifest["filename"], "project-0.1.0-py3-none-any.whl");
+
+    // The wheel is stored by its digest, and no temporary files are left behind.
+    let sha256 = manifest["sha256"].as_str().unwrap();
+    let mut files = fs_err::read_dir(manifests[0].path().parent().unwrap())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    assert_eq!(
+        files,
+        [format!("{sha256}.whl"), "py3-none-any.json".to_string()]
+    );
+
+    // With an empty local cache, the wheel is fetched from the remote cache instead of being
+    // rebuilt.
+    context.clean().assert().success();
+
+    let output = context
+        .pip_install()
+        .arg("project")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--remote-cache")
+        .arg(remote.path())
+        .arg("--preview-features")
+        .arg("remote-cache")
+        .arg("--verbose")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Fetched built wheel from remote cache for: project==0.1.0"));
+    assert!(!stderr.contains("Building: project==0.1.0"));
+
+    Ok(())
+}
@@ -26,6 +26,8 @@ fn help() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Explain why a package is included in the project's lockfile
+      outdated                   Display the outdated packages in the project's lockfile
       format                     Format Python code in the project
       check                      Run checks on the project
       audit                      Audit the project's dependencies
@@ -35,6 +37,7 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Serve a local package index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
@@ -110,6 +113,8 @@ fn help_flag() {
       lock       Update the project's lockfile
       export     Export the project's lockfile to an alternate format
       tree       Display the project's dependency tree
+      why        Explain why a package is included in the project's lockfile
+      outdated   Display the outdated packages in the project's lockfile
       format     Format Python code in the project
       check      Run checks on the project
       audit      Audit the project's dependencies
@@ -119,6 +124,7 @@ fn help_flag() {
       venv       Create a virtual environment
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
+      index      Serve a local package index
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
       self       Manage the uv executable
@@ -193,6 +199,8 @@ fn help_short_flag() {
       lock       Update the project's lockfile
       export     Export the project's lockfile to an alternate format
       tree       Display the project's dependency tree
+      why        Explain why a package is included in the project's lockfile
+      outdated   Display the outdated packages in the project's lockfile
       format     Format Python code in the project
       check      Run checks on the project
       audit      Audit the project's dependencies
@@ -202,6 +210,7 @@ fn help_short_flag() {
       venv       Create a virtual environment
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
+      index      Serve a local package index
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
       self       Manage the uv executable
@@ -977,6 +986,8 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        why
+        outdated
         format
         check
         audit
@@ -986,6 +997,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
         workspace
         cache
         self
@@ -1009,6 +1021,8 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        why
+        outdated
         format
         check
         audit
@@ -1018,6 +1032,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
         workspace
         cache
         self
@@ -1070,6 +1085,8 @@ fn help_with_global_option() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Explain why a package is included in the project's lockfile
+      outdated                   Display the outdated packages in the project's lockfile
       format                     Format Python code in the project
       check                      Run checks on the project
       audit                      Audit the project's dependencies
@@ -1079,6 +1096,7 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Serve a local package index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
@@ -1195,6 +1213,8 @@ fn help_with_no_pager() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Explain why a package is included in the project's lockfile
+      outdated                   Display the outdated packages in the project's lockfile
       format                     Format Python code in the project
       check                      Run checks on the project
       audit                      Audit the project's dependencies
@@ -1204,6 +1224,7 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Serve a local package index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
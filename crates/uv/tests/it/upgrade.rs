@@ -65,10 +65,13 @@ fn upgrade_help() {
     ----- stdout -----
     Upgrade a dependency in the project
 
-    Usage: uv upgrade [OPTIONS] <PACKAGE>
+    Usage: uv upgrade [OPTIONS] [PACKAGE]
 
     Arguments:
-      <PACKAGE>  The package to upgrade
+      [PACKAGE]  The package to upgrade
+
+    Options:
+      -i, --interactive  Select the dependencies to upgrade interactively
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -113,6 +116,47 @@ fn upgrade_help() {
     );
 }
 
+#[test]
+fn upgrade_interactive_requires_terminal() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let pyproject_toml = r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3"]
+    "#;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject_toml)?;
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.upgrade().arg("--interactive"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv upgrade --interactive` requires an interactive terminal
+    ");
+
+    uv_snapshot!(context.filters(), context.upgrade().arg("--interactive").arg("anyio"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--interactive' cannot be used with '[PACKAGE]'
+
+    Usage: uv upgrade --cache-dir [CACHE_DIR] --interactive [PACKAGE]
+
+    For more information, try '--help'.
+    ");
+
+    assert_project_unchanged(&context, pyproject_toml)
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn upgrade_selects_normalized_production_dependency() -> Result<()> {
@@ -25949,6 +25949,340 @@ fn lock_dry_run_noop() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn lock_diff() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Save the current lockfile to compare against.
+    fs_err::create_dir(context.temp_dir.child("base"))?;
+    fs_err::copy(
+        context.temp_dir.child("uv.lock"),
+        context.temp_dir.child("base").child("uv.lock"),
+    )?;
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff").arg("base").arg("--preview-features").arg("lock-diff"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Upgraded anyio v3.7.0 -> v4.3.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v3.7.0 -> v4.3.0
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff").arg("base/uv.lock").arg("--diff-format").arg("markdown").arg("--preview-features").arg("lock-diff"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    | Package | Change | Old | New |
+    |---------|--------|-----|-----|
+    | `anyio` | upgraded | `v3.7.0` | `v4.3.0` |
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Comparing the lockfile against itself reports no changes.
+    uv_snapshot!(context.filters(), context.lock().arg("--diff").arg("uv.lock").arg("--preview-features").arg("lock-diff"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No lockfile changes detected
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_merge_driver() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_dependencies = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+            "#,
+        })
+    };
+
+    // Lock the common ancestor, and each side of the merge.
+    write_dependencies(r#""anyio==3.7.0""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("base.lock"),
+    )?;
+
+    write_dependencies(r#""anyio==3.7.0", "iniconfig""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("current.lock"),
+    )?;
+
+    write_dependencies(r#""anyio==3.7.0", "typing-extensions""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("other.lock"),
+    )?;
+
+    // Merge the requirements, then the lockfiles.
+    write_dependencies(r#""anyio==3.7.0", "iniconfig", "typing-extensions""#)?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("merge-driver")
+        .arg("base.lock")
+        .arg("current.lock")
+        .arg("other.lock")
+        .arg("--preview-features")
+        .arg("lock-merge-driver"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    // The merged lockfile is consistent with the merged requirements.
+    fs_err::copy(
+        context.temp_dir.join("current.lock"),
+        context.temp_dir.join("uv.lock"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Merge lockfiles in which only the other side added a dependency, while the `pyproject.toml` in
+/// the working tree still reflects the current side.
+#[test]
+fn lock_merge_driver_other_side_added() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let links = context.workspace_root.join("test/links");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_dependencies = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+
+            [tool.uv]
+            no-index = true
+            find-links = ['{}']
+            "#,
+            links.display(),
+        })
+    };
+
+    // Lock the common ancestor, and each side of the merge. Only the other side adds
+    // `validation`.
+    write_dependencies(r#""ok<2""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("base.lock"),
+    )?;
+
+    write_dependencies(r#""ok<2", "validation<2""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("other.lock"),
+    )?;
+
+    write_dependencies(r#""ok""#)?;
+    context.lock().arg("--upgrade").assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("current.lock"),
+    )?;
+
+    // A `pyproject.toml` that can't be parsed is reported as a conflict.
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        <<<<<<< ours
+        dependencies = ["ok"]
+        =======
+        dependencies = ["ok<2", "validation<2"]
+        >>>>>>> theirs
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("merge-driver")
+        .arg("base.lock")
+        .arg("current.lock")
+        .arg("other.lock")
+        .arg("--preview-features")
+        .arg("lock-merge-driver"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse `pyproject.toml` during settings discovery:
+      TOML parse error at line 5, column 9
+        |
+      5 | <<<<<<< ours
+        |         ^
+      key with no value, expected `=`
+
+    Failed to read the workspace; resolve any conflicts in `pyproject.toml`, then run `uv lock`: Failed to parse: `pyproject.toml`
+    ");
+
+    // During the merge, the working tree contains the current side of `pyproject.toml`. The
+    // lockfiles merge cleanly nonetheless, retaining the upgrade from the current side and the
+    // dependency added on the other side.
+    write_dependencies(r#""ok""#)?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("merge-driver")
+        .arg("base.lock")
+        .arg("current.lock")
+        .arg("other.lock")
+        .arg("--preview-features")
+        .arg("lock-merge-driver"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("current.lock");
+    assert!(lock.contains("name = \"ok\"\nversion = \"2.0.0\""));
+    assert!(lock.contains("name = \"validation\"\nversion = \"1.0.0\""));
+    assert!(lock.contains(r#"{ name = "validation", specifier = "<2" }"#));
+
+    // Once `pyproject.toml` is merged, the merged lockfile is up-to-date.
+    write_dependencies(r#""ok", "validation<2""#)?;
+    fs_err::copy(
+        context.temp_dir.join("current.lock"),
+        context.temp_dir.join("uv.lock"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Reject a merge in which both sides changed the requirement on the same package.
+#[test]
+fn lock_merge_driver_requirement_conflict() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let links = context.workspace_root.join("test/links");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_dependencies = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+
+            [tool.uv]
+            no-index = true
+            find-links = ['{}']
+            "#,
+            links.display(),
+        })
+    };
+
+    write_dependencies(r#""validation<2""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("base.lock"),
+    )?;
+
+    write_dependencies(r#""validation<2", "ok<2""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("other.lock"),
+    )?;
+
+    write_dependencies(r#""validation<2", "ok""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("current.lock"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("merge-driver")
+        .arg("base.lock")
+        .arg("current.lock")
+        .arg("other.lock")
+        .arg("--preview-features")
+        .arg("lock-merge-driver"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    The requirement on `ok` was changed on both sides of the merge; resolve the conflict in `pyproject.toml`, then run `uv lock`
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_group_include() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -1,5 +1,8 @@
 //! Integration tests for uv pip commands.
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_audit;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_check;
 
@@ -7,6 +10,9 @@ mod pip_compile_scenarios;
 
 mod pip_debug;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod pip_download;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_exclude_newer_relative;
 
@@ -0,0 +1,330 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{ChildPath, FileWriteStr, PathChild};
+use indoc::indoc;
+use serde_json::json;
+
+use uv_test::uv_snapshot;
+
+/// Write an OSV database with vulnerabilities in `iniconfig` and `ok`.
+fn write_osv_database(context: &uv_test::TestContext) -> Result<ChildPath> {
+    let database = context.temp_dir.child("osv");
+    database.child("PYSEC-2023-0001.json").write_str(
+        &json!({
+            "id": "PYSEC-2023-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "summary": "A test vulnerability in iniconfig",
+            "affected": [{
+                "package": {"name": "iniconfig", "ecosystem": "PyPI"},
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [{"introduced": "0"}, {"fixed": "2.1.0"}]
+                }]
+            }]
+        })
+        .to_string(),
+    )?;
+    database.child("PYSEC-2023-0003.json").write_str(
+        &json!({
+            "id": "PYSEC-2023-0003",
+            "modified": "2026-01-01T00:00:00Z",
+            "summary": "A test vulnerability in ok",
+            "affected": [{
+                "package": {"name": "ok", "ecosystem": "PyPI"},
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [{"introduced": "0"}, {"fixed": "2.0.0"}]
+                }]
+            }]
+        })
+        .to_string(),
+    )?;
+    Ok(database)
+}
+
+/// Audit the packages installed in an environment.
+#[test]
+fn pip_audit_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let database = write_osv_database(&context)?;
+
+    context
+        .pip_install()
+        .arg(
+            context
+                .workspace_root
+                .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        )
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context
+        .pip_audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    ok 1.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0003: A test vulnerability in ok
+
+      Fixed in: 2.0.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0003
+
+
+    ----- stderr -----
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    ");
+
+    Ok(())
+}
+
+/// Audit the packages pinned in a `requirements.txt` file.
+#[test]
+fn pip_audit_requirements_txt() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let database = write_osv_database(&context)?;
+
+    context
+        .temp_dir
+        .child("requirements.txt")
+        .write_str(indoc! {r"
+        iniconfig==2.0.0
+        ok==2.0.0
+        anyio>=4
+    "})?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    iniconfig 2.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0001: A test vulnerability in iniconfig
+
+      Fixed in: 2.1.0
+
+      Advisory information: https://osv.dev/vulnerability/PYSEC-2023-0001
+
+
+    ----- stderr -----
+    warning: Skipping `anyio>=4` in `requirements.txt`, which is not pinned to an exact version
+    Found 1 known vulnerability and no adverse project statuses in 2 packages
+    ");
+
+    Ok(())
+}
+
+/// Audit the packages in a `pylock.toml` file, with JSON output.
+#[test]
+fn pip_audit_pylock_toml() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let database = write_osv_database(&context)?;
+
+    context
+        .temp_dir
+        .child("pylock.toml")
+        .write_str(indoc! {r#"
+        lock-version = "1.0"
+        created-by = "uv"
+        requires-python = ">=3.12"
+
+        [[packages]]
+        name = "iniconfig"
+        version = "2.0.0"
+        index = "https://pypi.org/simple"
+        wheels = [{ url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hashes = { sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" } }]
+
+        [[packages]]
+        name = "project"
+        directory = { path = ".", editable = true }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_audit()
+        .arg("--preview-features")
+        .arg("audit,json-output")
+        .arg("-r")
+        .arg("pylock.toml")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "summary": {
+        "audited_packages": 1,
+        "vulnerabilities": 1,
+        "adverse_statuses": 0
+      },
+      "vulnerabilities": [
+        {
+          "dependency": {
+            "name": "iniconfig",
+            "version": "2.0.0"
+          },
+          "id": "PYSEC-2023-0001",
+          "display_id": "PYSEC-2023-0001",
+          "aliases": [],
+          "summary": "A test vulnerability in iniconfig",
+          "description": null,
+          "link": "https://osv.dev/vulnerability/PYSEC-2023-0001",
+          "fix_versions": [
+            "2.1.0"
+          ],
+          "published": null,
+          "modified": "2026-01-01T00:00:00Z"
+        }
+      ],
+      "adverse_statuses": []
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+/// Reject unsupported requirements files.
+#[test]
+fn pip_audit_unsupported_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("-r")
+        .arg("pyproject.toml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv pip audit` only supports `requirements.txt` and `pylock.toml` files, but received: `pyproject.toml`
+    ");
+
+    Ok(())
+}
+
+/// Emit a CycloneDX VEX document for the packages in an environment, which has no lockfile
+/// components to reference.
+#[test]
+fn pip_audit_cyclonedx() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_cyclonedx_filters();
+    let database = write_osv_database(&context)?;
+
+    context
+        .pip_install()
+        .arg(
+            context
+                .workspace_root
+                .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        )
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context
+        .pip_audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--output-format")
+        .arg("cyclonedx1.5")
+        .arg("--service-format")
+        .arg("osv-dump")
+        .arg("--service-url")
+        .arg(database.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": [
+          {
+            "vendor": "Astral Software Inc.",
+            "name": "uv",
+            "version": "[VERSION]"
+          }
+        ]
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/ok@1.0.0",
+          "name": "ok",
+          "version": "1.0.0",
+          "purl": "pkg:pypi/ok@1.0.0"
+        }
+      ],
+      "vulnerabilities": [
+        {
+          "id": "PYSEC-2023-0003",
+          "source": {
+            "name": "OSV",
+            "url": "https://osv.dev/vulnerability/PYSEC-2023-0003"
+          },
+          "description": "A test vulnerability in ok",
+          "recommendation": "Upgrade ok to 2.0.0 or later.",
+          "updated": "2026-01-01T00:00:00Z",
+          "affects": [
+            {
+              "ref": "pkg:pypi/ok@1.0.0",
+              "versions": [
+                {
+                  "version": "1.0.0",
+                  "status": "affected"
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use itertools::Itertools;
+
+use uv_test::uv_snapshot;
+
+/// List the files in a directory, sorted by name.
+fn list_files(dir: &std::path::Path) -> Result<String> {
+    Ok(fs_err::read_dir(dir)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .sorted()
+        .join("\n"))
+}
+
+/// Download a package and its dependencies, then install from the resulting directory without
+/// network access.
+#[test]
+fn download_find_links() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_download()
+        .arg("--preview-features")
+        .arg("pip-download")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages to wheelhouse in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(list_files(&context.temp_dir.join("wheelhouse"))?, @"
+    anyio-4.3.0-py3-none-any.whl
+    idna-3.6-py3-none-any.whl
+    sniffio-1.3.1-py3-none-any.whl
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("anyio")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("wheelhouse")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    Ok(())
+}
+
+/// Download wheels for a platform and Python version other than the current interpreter.
+#[test]
+fn download_python_platform() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context
+        .pip_download()
+        .arg("--preview-features")
+        .arg("pip-download")
+        .arg("markupsafe==2.1.5")
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-version")
+        .arg("3.10")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to wheelhouse in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(list_files(&context.temp_dir.join("wheelhouse"))?, @"MarkupSafe-2.1.5-cp310-cp310-manylinux_2_17_x86_64.manylinux2014_x86_64.whl");
+
+    Ok(())
+}
+
+/// Download a source distribution when binaries are disabled.
+#[test]
+fn download_no_binary() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context
+        .pip_download()
+        .arg("--preview-features")
+        .arg("pip-download")
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to wheelhouse in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(list_files(&context.temp_dir.join("wheelhouse"))?, @"iniconfig-2.0.0.tar.gz");
+
+    Ok(())
+}
+
+/// Download a source distribution that's requested directly when builds are disabled.
+#[test]
+fn download_no_build_direct_source_dist() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let sdist = context
+        .workspace_root
+        .join("test/links/basic_package-0.1.0.tar.gz");
+
+    uv_snapshot!(context.filters(), context
+        .pip_download()
+        .arg("--preview-features")
+        .arg("pip-download")
+        .arg(format!("basic-package @ {}", sdist.display()))
+        .arg("--no-build")
+        .arg("-d")
+        .arg("wheelhouse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to wheelhouse in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(list_files(&context.temp_dir.join("wheelhouse"))?, @"basic_package-0.1.0.tar.gz");
+
+    Ok(())
+}
@@ -14933,6 +14933,124 @@ fn add_bounds_requirement_over_bounds_kind() -> Result<()> {
     Ok(())
 }
 
+/// Rewrite the bounds of existing dependencies across the workspace to match the lockfile.
+#[test]
+fn add_bump_all() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "ok>=1",
+            "child",
+        ]
+
+        [dependency-groups]
+        dev = ["validation<3"]
+
+        [tool.uv]
+        find-links = ["{}"]
+        no-index = true
+
+        [tool.uv.sources]
+        child = {{ workspace = true }}
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+        context.workspace_root.join("test/links").portable_display()
+    })?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["validation"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--bump-all").arg("--bounds").arg("major"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated `validation` -> `validation>=1.0.0,<2.0.0` in `child/pyproject.toml`
+    Updated `ok>=1` -> `ok>=2.0.0,<3.0.0` in `pyproject.toml`
+    Updated `validation<3` -> `validation>=1.0.0,<2.0.0` in `pyproject.toml`
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "ok>=2.0.0,<3.0.0",
+            "child",
+        ]
+
+        [dependency-groups]
+        dev = ["validation>=1.0.0,<2.0.0"]
+
+        [tool.uv]
+        find-links = ["[WORKSPACE]/test/links"]
+        no-index = true
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#);
+    });
+
+    let child_pyproject_toml = context.read("child/pyproject.toml");
+    assert_snapshot!(child_pyproject_toml, @r#"
+    [project]
+    name = "child"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["validation>=1.0.0,<2.0.0"]
+
+    [build-system]
+    requires = ["uv_build>=0.7,<10000"]
+    build-backend = "uv_build"
+    "#);
+
+    // Running again is a no-op.
+    uv_snapshot!(context.filters(), context.add().arg("--bump-all").arg("--bounds").arg("major"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    All dependency bounds already match the lockfile
+    ");
+
+    Ok(())
+}
+
 /// Add a path dependency with `--workspace` flag to add it to workspace members. The root already
 /// contains a workspace definition, so the package should be added to the workspace members.
 #[test]
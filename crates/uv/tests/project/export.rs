@@ -9832,3 +9832,165 @@ fn requirements_txt_emit_indexes() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn bundle() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // A bundle requires an output directory.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("bundle").arg("--preview-features").arg("export-bundle"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: `--format bundle` requires an output directory; provide one with `--output-file`
+    ");
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("bundle").arg("--output-file").arg("bundle").arg("--preview-features").arg("export-bundle"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Bundled 6 files to bundle in [TIME]
+    ");
+
+    let mut files = fs_err::read_dir(context.temp_dir.join("bundle"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    assert_snapshot!(files.join("\n"), @"
+    anyio-3.7.0-py3-none-any.whl
+    anyio-3.7.0.tar.gz
+    idna-3.6-py3-none-any.whl
+    idna-3.6.tar.gz
+    pylock.toml
+    sniffio-1.3.1-py3-none-any.whl
+    sniffio-1.3.1.tar.gz
+    ");
+
+    let pylock = context.read("bundle/pylock.toml");
+    assert_snapshot!(pylock, @r#"
+    lock-version = "1.0"
+    created-by = "uv"
+    requires-python = ">=3.12"
+
+    [[packages]]
+    name = "anyio"
+    version = "3.7.0"
+    index = "https://pypi.org/simple"
+    sdist = { path = "anyio-3.7.0.tar.gz", upload-time = 2023-05-27T11:12:46Z, size = 142737, hashes = { sha256 = "275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce" } }
+    wheels = [{ path = "anyio-3.7.0-py3-none-any.whl", upload-time = 2023-05-27T11:12:44Z, size = 80873, hashes = { sha256 = "eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0" } }]
+
+    [[packages]]
+    name = "idna"
+    version = "3.6"
+    index = "https://pypi.org/simple"
+    sdist = { path = "idna-3.6.tar.gz", upload-time = 2023-11-25T15:40:54Z, size = 175426, hashes = { sha256 = "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca" } }
+    wheels = [{ path = "idna-3.6-py3-none-any.whl", upload-time = 2023-11-25T15:40:52Z, size = 61567, hashes = { sha256 = "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f" } }]
+
+    [[packages]]
+    name = "project"
+    directory = { path = "..", editable = true }
+
+    [[packages]]
+    name = "sniffio"
+    version = "1.3.1"
+    index = "https://pypi.org/simple"
+    sdist = { path = "sniffio-1.3.1.tar.gz", upload-time = 2024-02-25T23:20:04Z, size = 20372, hashes = { sha256 = "f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc" } }
+    wheels = [{ path = "sniffio-1.3.1-py3-none-any.whl", upload-time = 2024-02-25T23:20:01Z, size = 10235, hashes = { sha256 = "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2" } }]
+    "#);
+
+    // The bundle can be used as a flat index, without network access.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--offline")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("bundle")
+        .arg("--frozen")
+        .arg("--no-install-project"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
+/// Bundle archives from a local find-links directory.
+#[test]
+fn bundle_find_links() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let links = context.workspace_root.join("test/links");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ['{}']
+        "#,
+        links.display(),
+    })?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("bundle").arg("--output-file").arg("bundle").arg("--preview-features").arg("export-bundle"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Bundled 1 file to bundle in [TIME]
+    ");
+
+    let mut files = fs_err::read_dir(context.temp_dir.join("bundle"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    assert_snapshot!(files.join("\n"), @"
+    ok-1.0.0-py3-none-any.whl
+    pylock.toml
+    ");
+
+    // The bundled archive matches the original.
+    assert_eq!(
+        fs_err::read(context.temp_dir.join("bundle/ok-1.0.0-py3-none-any.whl"))?,
+        fs_err::read(links.join("ok-1.0.0-py3-none-any.whl"))?,
+    );
+
+    Ok(())
+}
@@ -18,11 +18,17 @@ mod format;
 #[cfg(all(feature = "test-python", feature = "test-pypi", feature = "test-git"))]
 mod init;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod outdated;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod run;
 
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod tree;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod why;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod workflow;
@@ -0,0 +1,163 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+use url::Url;
+
+use uv_static::EnvVars;
+use uv_test::uv_snapshot;
+
+/// Report the outdated packages from a local index.
+#[test]
+fn outdated() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Create a local index with two versions of `ok`.
+    let root = context.temp_dir.child("simple-html");
+    let package = root.child("ok");
+    fs_err::create_dir_all(&package)?;
+    let mut links = String::new();
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        let wheel = package.child(filename);
+        fs_err::copy(
+            context.workspace_root.join("test/links").join(filename),
+            &wheel,
+        )?;
+        writeln!(
+            links,
+            "<a href=\"{}\">{filename}</a>",
+            Url::from_file_path(&wheel).unwrap()
+        )?;
+    }
+    package.child("index.html").write_str(&formatdoc! {r"
+        <!DOCTYPE html>
+        <html>
+          <body>
+            <h1>Links for ok</h1>
+            {links}
+          </body>
+        </html>
+    "})?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok>=1"]
+
+        [tool.uv]
+        extra-index-url = ["{}"]
+        "#,
+        Url::from_file_path(&root).unwrap().as_str()
+    })?;
+
+    // Lock to the oldest version of `ok`.
+    context
+        .lock()
+        .arg("--resolution")
+        .arg("lowest-direct")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.outdated().arg("--frozen").arg("--preview-features").arg("outdated-command").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Compatible Latest Update Dependency
+    ------- ------- ---------- ------ ------ ----------
+    ok      1.0.0   2.0.0      2.0.0  major  direct
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.outdated().arg("--frozen").arg("--output-format").arg("json").arg("--preview-features").arg("outdated-command,json-output").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "packages": [
+        {
+          "name": "ok",
+          "version": "1.0.0",
+          "compatible": "2.0.0",
+          "latest": "2.0.0",
+          "update": "major",
+          "direct": true
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+/// Report every locked package, including transitive dependencies and packages that are up to
+/// date.
+#[test]
+fn outdated_transitive() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.1"]
+    "#})?;
+
+    // The specifiers on `idna` and `sniffio` are read from the metadata of `anyio`.
+    uv_snapshot!(context.filters(), context.outdated().arg("--preview-features").arg("outdated-command"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Compatible Latest Update Dependency
+    ------- ------- ---------- ------ ------ ----------
+    anyio   3.7.1   3.7.1      4.3.0  major  direct
+    idna    3.6     3.6        3.6           transitive
+    sniffio 1.3.1   1.3.1      1.3.1         transitive
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Report that the lockfile doesn't contain any packages from a registry.
+#[test]
+fn outdated_no_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.outdated(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv outdated` is experimental and may change without warning. Pass `--preview-features outdated-command` to disable this warning.
+    Resolved 1 package in [TIME]
+    No packages from a registry found in the lockfile
+    ");
+
+    Ok(())
+}
@@ -3,7 +3,7 @@
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{fixture::ChildPath, prelude::*};
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::{prelude::predicate, str::contains};
 use serde_json::json;
@@ -209,6 +209,139 @@ fn run_args() -> Result<()> {
     Ok(())
 }
 
+/// Run a task defined in `tool.uv.tasks`.
+#[test]
+fn run_task() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.tasks]
+        setup = "python -c 'print(\"setup\")'"
+        greet = { cmd = "python -c 'import os, sys; print(os.environ[\"GREETING\"], *sys.argv[1:])'", env = { GREETING = "hello" }, depends-on = ["setup"] }
+        fail = { cmd = "python -c 'raise SystemExit(3)'" }
+        after-fail = { cmd = "python -c 'print(\"unreachable\")'", depends-on = ["fail"] }
+        all = { depends-on = ["setup", "greet"] }
+        lint = { cmd = "python -c 'pass'", groups = ["lint"] }
+        cycle = { cmd = "python -c 'pass'", depends-on = ["cycle"] }
+        "#
+    })?;
+
+    // Dependencies run first, and additional arguments are forwarded to the requested task.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("greet")
+        .arg("world"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    setup
+    hello world
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // Each task runs at most once.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("all"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    setup
+    hello
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // Execution stops at the first failing task.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("after-fail"), @"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // Dependency groups required by the task must be defined.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("lint"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Group `lint` is not defined in the project's `dependency-groups` table
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("cycle"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Detected a cycle in `tool.uv.tasks`: cycle -> cycle
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("tasks")
+        .arg("--task")
+        .arg("missing"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `missing` is not defined in `tool.uv.tasks`
+    ");
+
+    // Without the preview feature, a warning is shown.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--task")
+        .arg("setup"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    setup
+
+    ----- stderr -----
+    warning: `uv run --task` is experimental and may change without warning. Pass `--preview-features tasks` to disable this warning.
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Run without specifying any arguments.
 ///
 /// This should list the available scripts.
@@ -2143,6 +2276,161 @@ fn run_in_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Run a command in each workspace member with `--all-packages`.
+#[test]
+fn run_all_packages_each_member() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+        })?;
+    for name in ["alpha", "beta", "gamma"] {
+        context
+            .temp_dir
+            .child("packages")
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! { r#"
+                [project]
+                name = "{name}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = []
+                "#
+            })?;
+    }
+
+    // The command runs in each member's directory.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("run-all-packages")
+        .arg("--all-packages")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.path.basename(os.getcwd()))")
+        .env(EnvVars::UV_CONCURRENT_RUNS, "1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    alpha | alpha
+    beta  | beta
+    gamma | gamma
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Checked in [TIME]
+
+    Summary:
+      alpha  exit code 0
+      beta   exit code 0
+      gamma  exit code 0
+    ");
+
+    // The command fails in `beta`, so the remaining members are skipped with `--fail-fast`.
+    let script = "import os, sys; sys.exit(3 if os.path.basename(os.getcwd()) == 'beta' else 0)";
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("run-all-packages")
+        .arg("--all-packages")
+        .arg("--fail-fast")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg(script)
+        .env(EnvVars::UV_CONCURRENT_RUNS, "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Checked in [TIME]
+
+    Summary:
+      alpha  exit code 0
+      beta   exit code 3
+      gamma  skipped
+    ");
+
+    // Without `--fail-fast`, the command runs in every member.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("run-all-packages")
+        .arg("--all-packages")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg(script)
+        .env(EnvVars::UV_CONCURRENT_RUNS, "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Checked in [TIME]
+
+    Summary:
+      alpha  exit code 0
+      beta   exit code 3
+      gamma  exit code 0
+    ");
+
+    // Without the preview feature, the command runs once.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--all-packages")
+        .arg("--")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.path.basename(os.getcwd()))"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    temp
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Scripts provided via stdin can't be restarted with `--watch`.
+#[test]
+fn run_watch_stdin() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let src = context.temp_dir.child("src");
+    src.create_dir_all()?;
+    let main = context.temp_dir.child("main.py");
+    main.write_str("print('hello')")?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--preview-features")
+        .arg("run-watch")
+        .arg("--no-project")
+        .arg("--watch")
+        .arg(src.path())
+        .arg("-")
+        .stdin(std::fs::File::open(&main)?), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--watch` cannot be used with scripts provided via stdin or a remote URL
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_with_editable() -> Result<()> {
     let context = uv_test::test_context!("3.12");
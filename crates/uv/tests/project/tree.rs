@@ -2998,3 +2998,218 @@ fn workspace_circular_dependencies() -> Result<()> {
 
     Ok(())
 }
+
+/// Display the dependency graph in machine-readable formats.
+#[test]
+fn output_format() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child-a"]
+
+        [project.optional-dependencies]
+        feature = ["child-b"]
+
+        [dependency-groups]
+        dev = ["child-c ; sys_platform == 'linux'"]
+
+        [tool.uv.sources]
+        child-a = { path = "child-a" }
+        child-b = { path = "child-b" }
+        child-c = { path = "child-c" }
+    "#})?;
+    for name in ["child-a", "child-b", "child-c"] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+                [project]
+                name = "{name}"
+                version = "1.0.0"
+            "#})?;
+    }
+
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--universal")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "roots": [
+        3
+      ],
+      "nodes": [
+        {
+          "name": "child-a",
+          "version": "1.0.0",
+          "source": "directory+child-a",
+          "latest": null,
+          "size": null
+        },
+        {
+          "name": "child-b",
+          "version": "1.0.0",
+          "source": "directory+child-b",
+          "latest": null,
+          "size": null
+        },
+        {
+          "name": "child-c",
+          "version": "1.0.0",
+          "source": "directory+child-c",
+          "latest": null,
+          "size": null
+        },
+        {
+          "name": "project",
+          "version": "0.1.0",
+          "source": "virtual+.",
+          "latest": null,
+          "size": null
+        }
+      ],
+      "edges": [
+        {
+          "from": null,
+          "to": 3,
+          "extras": [],
+          "extra": null,
+          "group": null,
+          "specifier": null,
+          "marker": null
+        },
+        {
+          "from": 3,
+          "to": 0,
+          "extras": [],
+          "extra": null,
+          "group": null,
+          "specifier": "file://[TEMP_DIR]/child-a",
+          "marker": null
+        },
+        {
+          "from": 3,
+          "to": 1,
+          "extras": [],
+          "extra": "feature",
+          "group": null,
+          "specifier": "file://[TEMP_DIR]/child-b",
+          "marker": null
+        },
+        {
+          "from": 3,
+          "to": 2,
+          "extras": [],
+          "extra": null,
+          "group": "dev",
+          "specifier": "file://[TEMP_DIR]/child-c",
+          "marker": "sys_platform == 'linux'"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--universal")
+        .arg("--output-format")
+        .arg("dot"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        n0 [label="child-a v1.0.0"];
+        n1 [label="child-b v1.0.0"];
+        n2 [label="child-c v1.0.0"];
+        n3 [label="project v0.1.0"];
+        n3 -> n0 [label="file://[TEMP_DIR]/child-a"];
+        n3 -> n1 [label="file://[TEMP_DIR]/child-b extra: feature"];
+        n3 -> n2 [label="file://[TEMP_DIR]/child-c group: dev ; sys_platform == 'linux'"];
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--universal")
+        .arg("--invert")
+        .arg("--output-format")
+        .arg("mermaid"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flowchart TD
+        n0["child-a v1.0.0"]
+        n1["child-b v1.0.0"]
+        n2["child-c v1.0.0"]
+        n3["project v0.1.0"]
+        n0 -->|"file://[TEMP_DIR]/child-a"| n3
+        n1 -->|"file://[TEMP_DIR]/child-b extra: feature"| n3
+        n2 -->|"file://[TEMP_DIR]/child-c group: dev ; sys_platform == 'linux'"| n3
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
+/// Label the edges in machine-readable formats with the specifiers recorded in the lockfile.
+#[test]
+fn output_format_specifier() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+    "#})?;
+
+    // The lockfile doesn't record the requirements of registry packages, so the edges from
+    // `anyio` have no specifier.
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--universal")
+        .arg("--output-format")
+        .arg("dot"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        n0 [label="anyio v4.3.0"];
+        n1 [label="idna v3.6"];
+        n2 [label="project v0.1.0"];
+        n3 [label="sniffio v1.3.1"];
+        n0 -> n1;
+        n0 -> n3;
+        n2 -> n0 [label="==4.3.0"];
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv_test::uv_snapshot;
+
+#[test]
+fn why() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["scikit-learn==1.4.1.post1"]
+
+        [dependency-groups]
+        dev = ["scipy>=1.12"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.why().arg("numpy").arg("--preview-features").arg("why-command"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 -> scikit-learn v1.4.1.post1 (specifier: ==1.4.1.post1) -> numpy v1.26.4 (specifier: >=1.19.5, <2.0)
+    project v0.1.0 -> scikit-learn v1.4.1.post1 (specifier: ==1.4.1.post1) -> scipy v1.12.0 (specifier: >=1.6.0) -> numpy v1.26.4 (specifier: >=1.22.4, <1.29.0)
+    project v0.1.0 -> scipy v1.12.0 (group: dev, specifier: >=1.12) -> numpy v1.26.4 (specifier: >=1.22.4, <1.29.0)
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.why().arg("requests").arg("--preview-features").arg("why-command"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    error: Package `requests` was not found in the lockfile
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn why_forks() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==4.2.0 ; sys_platform == 'linux'",
+            "anyio==4.3.0 ; sys_platform != 'linux'",
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.why().arg("idna").arg("--preview-features").arg("why-command"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    sys_platform == 'linux':
+        project v0.1.0 -> anyio v4.2.0 (specifier: ==4.2.0) -> idna v3.6 (specifier: >=2.8)
+    sys_platform != 'linux':
+        project v0.1.0 -> anyio v4.3.0 (specifier: ==4.3.0) -> idna v3.6 (specifier: >=2.8)
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn why_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.why()
+        .arg("sniffio")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("why-command,json-output"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "package": "sniffio",
+      "versions": [
+        "1.3.1"
+      ],
+      "forks": [
+        {
+          "marker": null,
+          "paths": [
+            {
+              "root": {
+                "name": "project",
+                "version": "0.1.0"
+              },
+              "steps": [
+                {
+                  "name": "anyio",
+                  "version": "4.3.0",
+                  "extras": [],
+                  "extra": null,
+                  "group": null,
+                  "specifier": "==4.3.0",
+                  "marker": null
+                },
+                {
+                  "name": "sniffio",
+                  "version": "1.3.1",
+                  "extras": [],
+                  "extra": null,
+                  "group": null,
+                  "specifier": ">=1.1",
+                  "marker": null
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
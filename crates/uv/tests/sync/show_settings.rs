@@ -15,6 +15,7 @@ fn add_shared_args(mut command: Command) -> Command {
         .env(EnvVars::UV_CONCURRENT_DOWNLOADS, "50")
         .env(EnvVars::UV_CONCURRENT_BUILDS, "16")
         .env(EnvVars::UV_CONCURRENT_INSTALLS, "8")
+        .env(EnvVars::UV_CONCURRENT_RUNS, "4")
         .env_remove(EnvVars::UV_EXCLUDE_NEWER)
         .env_remove(EnvVars::UV_PYTHON_DOWNLOADS);
 
@@ -60,6 +61,8 @@ fn pip_compile_baseline() {
             downloads: 50,
             builds: 16,
             installs: 8,
+            download_ranges: 1,
+            runs: 4,
         },
         show_settings: true,
         preview: Preview {
@@ -75,6 +78,7 @@ fn pip_compile_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -247,6 +251,8 @@ fn pip_install_baseline() {
             downloads: 50,
             builds: 16,
             installs: 8,
+            download_ranges: 1,
+            runs: 4,
         },
         show_settings: true,
         preview: Preview {
@@ -262,6 +268,7 @@ fn pip_install_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -430,6 +437,8 @@ fn lock_baseline() {
             downloads: 50,
             builds: 16,
             installs: 8,
+            download_ranges: 1,
+            runs: 4,
         },
         show_settings: true,
         preview: Preview {
@@ -445,11 +454,15 @@ fn lock_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        diff_format: Text,
+        merge_driver: None,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -552,6 +565,8 @@ fn version_baseline() {
             downloads: 50,
             builds: 16,
             installs: 8,
+            download_ranges: 1,
+            runs: 4,
         },
         show_settings: true,
         preview: Preview {
@@ -567,6 +582,7 @@ fn version_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache: None,
     }
     VersionSettings {
         value: None,
@@ -689,6 +705,8 @@ fn tool_install_baseline() {
             downloads: 50,
             builds: 16,
             installs: 8,
+            download_ranges: 1,
+            runs: 4,
         },
         show_settings: true,
         preview: Preview {
@@ -704,6 +722,7 @@ fn tool_install_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote_cache: None,
     }
     ToolInstallSettings {
         package: "anyio",
@@ -2493,7 +2512,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `remote-cache`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `tasks`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -3056,6 +3075,20 @@ fn preview_features() {
     +            CentralizedProjectEnvs,
     +            ToolInstallLocks,
     +            WorkspaceListScripts,
+    +            Why,
+    +            LockDiff,
+    +            LockMergeDriver,
+    +            PipDownload,
+    +            ExportBundle,
+    +            IndexServe,
+    +            CacheEviction,
+    +            CacheInspect,
+    +            RemoteCache,
+    +            ResumableDownloads,
+    +            Tasks,
+    +            RunAllPackages,
+    +            RunWatch,
+    +            Outdated,
     +        ],
          },
          python_preference: Managed,
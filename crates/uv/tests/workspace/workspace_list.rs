@@ -342,3 +342,69 @@ fn workspace_list_scripts() -> Result<()> {
 
     Ok(())
 }
+
+/// List the tasks defined by each workspace member.
+#[test]
+fn workspace_list_tasks() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(
+        r#"
+[tool.uv.workspace]
+members = ["packages/*"]
+
+[tool.uv.tasks]
+check = { depends-on = ["lint"] }
+lint = "ruff check"
+"#,
+    )?;
+    workspace.child("packages/foo/pyproject.toml").write_str(
+        r#"
+[project]
+name = "foo"
+version = "0.1.0"
+
+[tool.uv.tasks]
+test = { cmd = "pytest", description = "Run the test suite" }
+"#,
+    )?;
+    workspace.child("packages/bar/pyproject.toml").write_str(
+        r#"
+[project]
+name = "bar"
+version = "0.1.0"
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.workspace_list()
+        .arg("--tasks")
+        .current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    check
+    lint
+    foo:test  Run the test suite
+
+    ----- stderr -----
+    warning: The `--tasks` option is experimental and may change without warning. Pass `--preview-features tasks` to disable this warning.
+    ");
+
+    uv_snapshot!(context.filters(), context.workspace_list()
+        .arg("--tasks")
+        .arg("--preview-features")
+        .arg("tasks")
+        .current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    check
+    lint
+    foo:test  Run the test suite
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}